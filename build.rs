@@ -5,9 +5,26 @@ use std::env;
 
 fn main() {
     let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let config = Config::from_file("cbindgen.toml").unwrap();
 
-    cbindgen::generate_with_config(crate_dir, config)
+    let config = Config::from_file("cbindgen.toml").unwrap();
+    cbindgen::generate_with_config(&crate_dir, config)
         .unwrap()
         .write_to_file("include/crypto_msg_parser_ffi.h");
+
+    // Versioned SONAME, e.g., libcrypto_msg_parser_ffi.so.0.1 for 0.1.x,
+    // `make` and `make install` create the symlinks named after it
+    if env::var("CARGO_CFG_TARGET_OS").unwrap() == "linux" {
+        let major = env::var("CARGO_PKG_VERSION_MAJOR").unwrap();
+        let minor = env::var("CARGO_PKG_VERSION_MINOR").unwrap();
+        let soversion = if major == "0" {
+            format!("0.{minor}")
+        } else {
+            major
+        };
+        let soname = format!("libcrypto_msg_parser_ffi.so.{soversion}");
+        println!("cargo:rustc-cdylib-link-arg=-Wl,-soname,{soname}");
+    }
+
+    #[cfg(feature = "node")]
+    napi_build::setup();
 }
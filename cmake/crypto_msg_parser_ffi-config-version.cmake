@@ -0,0 +1,31 @@
+# Versions with the same major version, or the same minor version before
+# 1.0, are compatible.
+
+# Replaced by `make install`, read from Cargo.toml in the source tree
+set(PACKAGE_VERSION "@VERSION@")
+if(EXISTS "${CMAKE_CURRENT_LIST_DIR}/../Cargo.toml")
+  file(STRINGS "${CMAKE_CURRENT_LIST_DIR}/../Cargo.toml" _version_line
+    REGEX "^version = \"[^\"]+\"" LIMIT_COUNT 1)
+  string(REGEX REPLACE "^version = \"([^\"]+)\".*" "\\1" PACKAGE_VERSION "${_version_line}")
+  unset(_version_line)
+endif()
+
+if(NOT PACKAGE_FIND_VERSION)
+  set(PACKAGE_VERSION_COMPATIBLE TRUE)
+elseif(PACKAGE_FIND_VERSION VERSION_GREATER PACKAGE_VERSION)
+  set(PACKAGE_VERSION_COMPATIBLE FALSE)
+else()
+  string(REGEX MATCH "^0\\.[0-9]+|^[1-9][0-9]*" _version_prefix "${PACKAGE_VERSION}")
+  string(REGEX MATCH "^0\\.[0-9]+|^[1-9][0-9]*" _find_version_prefix "${PACKAGE_FIND_VERSION}")
+  if(_version_prefix STREQUAL _find_version_prefix)
+    set(PACKAGE_VERSION_COMPATIBLE TRUE)
+  else()
+    set(PACKAGE_VERSION_COMPATIBLE FALSE)
+  endif()
+  unset(_version_prefix)
+  unset(_find_version_prefix)
+endif()
+
+if(PACKAGE_FIND_VERSION STREQUAL PACKAGE_VERSION)
+  set(PACKAGE_VERSION_EXACT TRUE)
+endif()
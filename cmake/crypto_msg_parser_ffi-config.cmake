@@ -0,0 +1,57 @@
+# CMake package config of crypto-msg-parser-ffi.
+#
+# Installed by `make install` into <libdir>/cmake/crypto_msg_parser_ffi, with
+# the LIBDIR and INCLUDEDIR of the installation:
+#
+#   find_package(crypto_msg_parser_ffi REQUIRED)
+#
+# or used from the source tree with the library built by `make`:
+#
+#   find_package(crypto_msg_parser_ffi REQUIRED PATHS /path/to/crypto-msg-parser-ffi/cmake)
+#
+# Targets:
+#
+# * crypto_msg_parser_ffi::crypto_msg_parser_ffi, the C library
+# * crypto_msg_parser_ffi::cpp, the header-only C++17 wrapper
+#
+# Set CRYPTO_MSG_PARSER_FFI_LIBRARY_DIR to use another cargo profile or target
+# in the source tree.
+
+if(EXISTS "${CMAKE_CURRENT_LIST_DIR}/../Cargo.toml")
+  get_filename_component(_crypto_msg_parser_ffi_root "${CMAKE_CURRENT_LIST_DIR}/.." ABSOLUTE)
+  set(_crypto_msg_parser_ffi_include_dir "${_crypto_msg_parser_ffi_root}/include")
+  if(NOT CRYPTO_MSG_PARSER_FFI_LIBRARY_DIR)
+    set(CRYPTO_MSG_PARSER_FFI_LIBRARY_DIR "${_crypto_msg_parser_ffi_root}/target/release")
+  endif()
+  unset(_crypto_msg_parser_ffi_root)
+else()
+  # substituted by `make install`, same as in crypto_msg_parser_ffi.pc
+  set(CRYPTO_MSG_PARSER_FFI_LIBRARY_DIR "@LIBDIR@")
+  set(_crypto_msg_parser_ffi_include_dir "@INCLUDEDIR@")
+endif()
+
+find_library(CRYPTO_MSG_PARSER_FFI_LIBRARY
+  NAMES crypto_msg_parser_ffi
+  PATHS "${CRYPTO_MSG_PARSER_FFI_LIBRARY_DIR}"
+  NO_DEFAULT_PATH)
+
+if(NOT CRYPTO_MSG_PARSER_FFI_LIBRARY)
+  set(crypto_msg_parser_ffi_FOUND FALSE)
+  set(crypto_msg_parser_ffi_NOT_FOUND_MESSAGE
+    "libcrypto_msg_parser_ffi not found in ${CRYPTO_MSG_PARSER_FFI_LIBRARY_DIR}, run `make` first")
+  return()
+endif()
+
+if(NOT TARGET crypto_msg_parser_ffi::crypto_msg_parser_ffi)
+  add_library(crypto_msg_parser_ffi::crypto_msg_parser_ffi UNKNOWN IMPORTED)
+  set_target_properties(crypto_msg_parser_ffi::crypto_msg_parser_ffi PROPERTIES
+    IMPORTED_LOCATION "${CRYPTO_MSG_PARSER_FFI_LIBRARY}"
+    INTERFACE_INCLUDE_DIRECTORIES "${_crypto_msg_parser_ffi_include_dir}")
+
+  add_library(crypto_msg_parser_ffi::cpp INTERFACE IMPORTED)
+  set_target_properties(crypto_msg_parser_ffi::cpp PROPERTIES
+    INTERFACE_LINK_LIBRARIES crypto_msg_parser_ffi::crypto_msg_parser_ffi
+    INTERFACE_COMPILE_FEATURES cxx_std_17)
+endif()
+
+unset(_crypto_msg_parser_ffi_include_dir)
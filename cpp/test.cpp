@@ -0,0 +1,114 @@
+// Tests of crypto_msg_parser.hpp, build and run them with:
+//
+//   cargo build --release
+//   cmake -S cpp -B cpp/build && cmake --build cpp/build && ctest --test-dir cpp/build
+
+#include <cstdlib>
+#include <iostream>
+#include <string>
+#include <string_view>
+#include <utility>
+
+#include "crypto_msg_parser.hpp"
+
+namespace cmp = crypto_msg_parser;
+
+static int failures = 0;
+
+#define CHECK(condition)                                             \
+  do {                                                               \
+    if (!(condition)) {                                              \
+      std::cerr << __FILE__ << ":" << __LINE__ << ": " #condition "\n"; \
+      ++failures;                                                    \
+    }                                                                \
+  } while (0)
+
+static bool contains(std::string_view haystack, std::string_view needle) {
+  return haystack.find(needle) != std::string_view::npos;
+}
+
+static const char *TRADE_MSG =
+    R"({"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}})";
+static const char *L2_MSG =
+    R"({"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}})";
+
+static void test_parse_trade() {
+  auto trades = cmp::parse_trade("binance", MarketType::InverseSwap, TRADE_MSG);
+  CHECK(trades.has_value());
+  CHECK(contains(*trades, R"("market_type":"inverse_swap")"));
+  CHECK(contains(*trades, R"("price":58570.1)"));
+  CHECK(contains(*trades, R"("side":"sell")"));
+  CHECK(cmp::get_msg_type("binance", TRADE_MSG) == MessageType::Trade);
+
+  cmp::Options csv_options;
+  csv_options.format = OutputFormat_Csv;
+  auto csv = cmp::parse_trade("binance", MarketType::InverseSwap, TRADE_MSG, csv_options);
+  CHECK(csv.has_value() && contains(csv->view(), "58570.1"));
+  CHECK(cmp::csv_header(MessageType::Trade).has_value());
+}
+
+static void test_parse_l2() {
+  auto orderbooks = cmp::parse_l2("binance", MarketType::InverseSwap, L2_MSG);
+  CHECK(orderbooks.has_value());
+  CHECK(contains(*orderbooks, R"("msg_type":"l2_event")"));
+  CHECK(contains(*orderbooks, R"("timestamp":1622370862564)"));
+}
+
+static void test_options() {
+  cmp::Options options;
+  options.number_format = NumberFormat_DecimalString;
+  options.fields = "timestamp,price,side";
+  auto bytes = cmp::parse_trade("binance", MarketType::InverseSwap, TRADE_MSG, options);
+  CHECK(bytes.has_value());
+  CHECK(bytes->view() == R"([{"price":"58570.1","side":"sell","timestamp":1616201883458}])");
+
+  // moving transfers the ownership
+  cmp::Bytes moved = std::move(*bytes);
+  CHECK(bytes->data() == nullptr);
+  CHECK(!moved.empty());
+}
+
+static void test_extract() {
+  auto symbol = cmp::extract_symbol("binance", MarketType::InverseSwap, L2_MSG);
+  CHECK(symbol.has_value() && *symbol == "BTCUSD_PERP");
+  auto timestamp = cmp::extract_timestamp("binance", MarketType::InverseSwap, L2_MSG);
+  CHECK(timestamp.has_value() && **timestamp == 1622370862564);
+}
+
+static void test_errors() {
+  auto trades = cmp::parse_trade("binance", MarketType::InverseSwap, "not json");
+  CHECK(!trades);
+  CHECK(!trades.error().message.empty());
+
+  auto bytes = cmp::parse_trade("binance", MarketType::InverseSwap, "not json", cmp::Options{});
+  CHECK(!bytes);
+  CHECK(!bytes.error().message.empty());
+
+  auto reader = cmp::RawFileReader::open("/nonexistent/file.txt");
+  CHECK(!reader);
+}
+
+static void test_enums() {
+  static_assert(cmp::to_string(MarketType::InverseSwap) == "inverse_swap");
+  static_assert(cmp::to_string(MessageType::L2TopK) == "l2_topk");
+  CHECK(cmp::market_type_from_string("linear_swap") == MarketType::LinearSwap);
+  CHECK(cmp::msg_type_from_string("funding_rate") == MessageType::FundingRate);
+  CHECK(cmp::msg_type_from_string("taker_volume") == MessageType::TakerVolume);
+  CHECK(!cmp::market_type_from_string("nonexistent").has_value());
+}
+
+int main() {
+  CHECK(cmp::abi_compatible());
+  test_parse_trade();
+  test_parse_l2();
+  test_options();
+  test_extract();
+  test_errors();
+  test_enums();
+  if (failures > 0) {
+    std::cerr << failures << " checks failed\n";
+    return EXIT_FAILURE;
+  }
+  std::cout << "All tests passed\n";
+  return EXIT_SUCCESS;
+}
@@ -0,0 +1,93 @@
+using CryptoMsgParser;
+using Xunit;
+
+namespace CryptoMsgParser.Tests
+{
+    public class ParserTest
+    {
+        private const string TradeMsg = @"{""stream"":""btcusd_perp@aggTrade"",""data"":{""e"":""aggTrade"",""E"":1616201883458,""a"":41045788,""s"":""BTCUSD_PERP"",""p"":""58570.1"",""q"":""58"",""f"":91864326,""l"":91864327,""T"":1616201883304,""m"":true}}";
+        private const string L2Msg = @"{""stream"":""btcusd_perp@depth@100ms"",""data"":{""e"":""depthUpdate"",""E"":1622370862564,""T"":1622370862553,""s"":""BTCUSD_PERP"",""ps"":""BTCUSD"",""U"":127559587191,""u"":127559588177,""pu"":127559587113,""b"":[[""35365.9"",""1400""],[""35425.8"",""561""]],""a"":[[""35817.8"",""7885""],[""35818.7"",""307""]]}}";
+        private const string BboMsg = @"{""stream"":""ethusdt@bookTicker"",""data"":{""e"":""bookTicker"",""u"":1553413152520,""s"":""ETHUSDT"",""b"":""1778.54"",""B"":""15.164"",""a"":""1778.55"",""A"":""7.289"",""T"":1653817855284,""E"":1653817855289}}";
+        private const string FundingRateMsg = @"{""stream"":""btcusd_perp@markPrice"",""data"":{""e"":""markPriceUpdate"",""E"":1617309477000,""s"":""BTCUSD_PERP"",""p"":""59012.56007222"",""P"":""58896.00503145"",""r"":""0.00073689"",""T"":1617321600000}}";
+        private const string CandlestickMsg = @"{""stream"":""btcusdt@kline_1M"",""data"":{""e"":""kline"",""E"":1653819041520,""s"":""BTCUSDT"",""k"":{""t"":1651363200000,""T"":1654041599999,""s"":""BTCUSDT"",""i"":""1M"",""f"":2172726276,""L"":2301806561,""o"":""37614.40"",""c"":""29075.50"",""h"":""40071.70"",""l"":""26631.00"",""v"":""13431981.671"",""n"":129025447,""x"":false,""q"":""423075730671.12853"",""V"":""6700065.176"",""Q"":""211000435586.65000"",""B"":""0""}}}";
+
+        [Fact]
+        public void AbiCompatible()
+        {
+            Assert.True(Parser.AbiCompatible);
+        }
+
+        [Fact]
+        public void ParseTrade()
+        {
+            var trades = Parser.ParseTrade("binance", MarketType.InverseSwap, TradeMsg);
+            var trade = Assert.Single(trades);
+            Assert.Equal("binance", trade.Exchange);
+            Assert.Equal(MarketType.InverseSwap, trade.MarketType);
+            Assert.Equal(MessageType.Trade, trade.MsgType);
+            Assert.Equal(58570.1, trade.Price);
+            Assert.Equal(5800.0 / 58570.1, trade.QuantityBase, 9);
+            Assert.Equal(5800.0, trade.QuantityQuote);
+            Assert.Equal(58.0, trade.QuantityContract);
+            Assert.Equal(TradeSide.Sell, trade.Side);
+            Assert.Equal(MessageType.Trade, Parser.GetMsgType("binance", TradeMsg));
+        }
+
+        [Fact]
+        public void ParseL2()
+        {
+            var orderbook = Assert.Single(Parser.ParseL2("binance", MarketType.InverseSwap, L2Msg));
+            Assert.Equal(MessageType.L2Event, orderbook.MsgType);
+            Assert.Equal(1622370862564, orderbook.Timestamp);
+            Assert.False(orderbook.Snapshot);
+            Assert.Equal(2, orderbook.Asks.Count);
+            Assert.Equal(35365.9, orderbook.Bids[0].Price);
+            Assert.Equal(1400.0, orderbook.Bids[0].QuantityContract);
+            Assert.Equal(MessageType.L2Event, Parser.GetMsgType("binance", L2Msg));
+        }
+
+        [Fact]
+        public void ParseBbo()
+        {
+            var bbo = Assert.Single(Parser.ParseBbo("binance", MarketType.LinearSwap, BboMsg));
+            Assert.Equal("ETHUSDT", bbo.Symbol);
+            Assert.Equal(1653817855289, bbo.Timestamp);
+            Assert.Equal(1553413152520UL, bbo.Id);
+            Assert.Equal(1778.55, bbo.AskPrice);
+            Assert.Equal(15.164, bbo.BidQuantityBase);
+        }
+
+        [Fact]
+        public void ParseFundingRate()
+        {
+            var rate = Assert.Single(Parser.ParseFundingRate("binance", MarketType.InverseSwap, FundingRateMsg));
+            Assert.Equal("BTC/USD", rate.Pair);
+            Assert.Equal(0.00073689, rate.FundingRate);
+            Assert.Equal(1617321600000, rate.FundingTime);
+        }
+
+        [Fact]
+        public void ParseCandlestick()
+        {
+            var candlestick = Assert.Single(Parser.ParseCandlestick("binance", MarketType.LinearSwap, CandlestickMsg));
+            Assert.Equal("1M", candlestick.Period);
+            Assert.Equal(1651363200, candlestick.BeginTime);
+            Assert.Equal(37614.4, candlestick.Open);
+            Assert.Equal(423075730671.12853, candlestick.QuoteVolume);
+        }
+
+        [Fact]
+        public void Extract()
+        {
+            Assert.Equal("BTCUSD_PERP", Parser.ExtractSymbol("binance", MarketType.InverseSwap, L2Msg));
+            Assert.Equal(1622370862564, Parser.ExtractTimestamp("binance", MarketType.InverseSwap, L2Msg));
+        }
+
+        [Fact]
+        public void ParseError()
+        {
+            var err = Assert.Throws<ParseException>(() => Parser.ParseTrade("binance", MarketType.InverseSwap, "not json"));
+            Assert.NotEmpty(err.Message);
+        }
+    }
+}
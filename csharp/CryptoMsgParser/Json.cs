@@ -0,0 +1,44 @@
+using System;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace CryptoMsgParser
+{
+    /// <summary>
+    /// snake_case names of the JSON output, e.g., <c>inverse_swap</c>.
+    /// </summary>
+    internal sealed class EnumNamingPolicy : JsonNamingPolicy
+    {
+        public override string ConvertName(string name) =>
+            name == nameof(MessageType.L2TopK) ? "l2_topk" : SnakeCaseLower.ConvertName(name);
+    }
+
+    /// <summary>
+    /// Orders are serialized as <c>[price, quantity_base, quantity_quote, quantity_contract?]</c>.
+    /// </summary>
+    internal sealed class OrderConverter : JsonConverter<Order>
+    {
+        public override Order Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var values = JsonSerializer.Deserialize<double[]>(ref reader, options);
+            if (values is null || values.Length < 3 || values.Length > 4)
+            {
+                throw new JsonException("An order should have 3 or 4 numbers");
+            }
+            return new Order(values[0], values[1], values[2], values.Length == 4 ? values[3] : null);
+        }
+
+        public override void Write(Utf8JsonWriter writer, Order value, JsonSerializerOptions options)
+        {
+            writer.WriteStartArray();
+            writer.WriteNumberValue(value.Price);
+            writer.WriteNumberValue(value.QuantityBase);
+            writer.WriteNumberValue(value.QuantityQuote);
+            if (value.QuantityContract is double quantityContract)
+            {
+                writer.WriteNumberValue(quantityContract);
+            }
+            writer.WriteEndArray();
+        }
+    }
+}
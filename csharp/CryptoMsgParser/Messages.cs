@@ -0,0 +1,143 @@
+using System.Collections.Generic;
+
+namespace CryptoMsgParser
+{
+    /// <summary>
+    /// Which side is taker.
+    /// </summary>
+    public enum TradeSide
+    {
+        Buy,
+        Sell,
+    }
+
+    /// <summary>
+    /// Tick-by-tick trade.
+    /// </summary>
+    public sealed record TradeMsg
+    {
+        public string Exchange { get; init; } = string.Empty;
+        public MarketType MarketType { get; init; }
+        public MessageType MsgType { get; init; }
+        /// <summary>Unified pair, base/quote, e.g., BTC/USDT.</summary>
+        public string Pair { get; init; } = string.Empty;
+        /// <summary>Exchange-specific trading symbol or id.</summary>
+        public string Symbol { get; init; } = string.Empty;
+        /// <summary>Unix timestamp, in milliseconds.</summary>
+        public long Timestamp { get; init; }
+        public TradeSide Side { get; init; }
+        public double Price { get; init; }
+        public double QuantityBase { get; init; }
+        public double QuantityQuote { get; init; }
+        /// <summary>Number of contracts, null in spot market.</summary>
+        public double? QuantityContract { get; init; }
+        public string TradeId { get; init; } = string.Empty;
+        /// <summary>The original JSON message.</summary>
+        public string Json { get; init; } = string.Empty;
+    }
+
+    /// <summary>
+    /// A price level of an orderbook.
+    /// </summary>
+    public sealed record Order(
+        double Price,
+        double QuantityBase,
+        double QuantityQuote,
+        double? QuantityContract);
+
+    /// <summary>
+    /// Level2 orderbook snapshot or incremental update.
+    /// </summary>
+    public sealed record OrderBookMsg
+    {
+        public string Exchange { get; init; } = string.Empty;
+        public MarketType MarketType { get; init; }
+        public string Symbol { get; init; } = string.Empty;
+        public string Pair { get; init; } = string.Empty;
+        public MessageType MsgType { get; init; }
+        /// <summary>Unix timestamp, in milliseconds.</summary>
+        public long Timestamp { get; init; }
+        public bool Snapshot { get; init; }
+        /// <summary>Sorted in ascending order by price.</summary>
+        public IReadOnlyList<Order> Asks { get; init; } = new List<Order>();
+        /// <summary>Sorted in descending order by price.</summary>
+        public IReadOnlyList<Order> Bids { get; init; } = new List<Order>();
+        public ulong? SeqId { get; init; }
+        public ulong? PrevSeqId { get; init; }
+        /// <summary>The original JSON message.</summary>
+        public string Json { get; init; } = string.Empty;
+    }
+
+    /// <summary>
+    /// Best bid and offer.
+    /// </summary>
+    public sealed record BboMsg
+    {
+        public string Exchange { get; init; } = string.Empty;
+        public MarketType MarketType { get; init; }
+        public string Symbol { get; init; } = string.Empty;
+        public string Pair { get; init; } = string.Empty;
+        public MessageType MsgType { get; init; }
+        /// <summary>Unix timestamp, in milliseconds.</summary>
+        public long Timestamp { get; init; }
+        public double BidPrice { get; init; }
+        public double BidQuantityBase { get; init; }
+        public double BidQuantityQuote { get; init; }
+        public double? BidQuantityContract { get; init; }
+        public double AskPrice { get; init; }
+        public double AskQuantityBase { get; init; }
+        public double AskQuantityQuote { get; init; }
+        public double? AskQuantityContract { get; init; }
+        public ulong? Id { get; init; }
+        /// <summary>The original JSON message.</summary>
+        public string Json { get; init; } = string.Empty;
+    }
+
+    /// <summary>
+    /// Funding rate of a perpetual swap.
+    /// </summary>
+    public sealed record FundingRateMsg
+    {
+        public string Exchange { get; init; } = string.Empty;
+        public MarketType MarketType { get; init; }
+        public string Symbol { get; init; } = string.Empty;
+        public string Pair { get; init; } = string.Empty;
+        public MessageType MsgType { get; init; }
+        /// <summary>Unix timestamp, in milliseconds.</summary>
+        public long Timestamp { get; init; }
+        public double FundingRate { get; init; }
+        /// <summary>Unix timestamp of the funding, in milliseconds.</summary>
+        public long FundingTime { get; init; }
+        public double? EstimatedRate { get; init; }
+        /// <summary>The original JSON message.</summary>
+        public string Json { get; init; } = string.Empty;
+    }
+
+    /// <summary>
+    /// OHLCV candlestick.
+    /// </summary>
+    public sealed record CandlestickMsg
+    {
+        public string Exchange { get; init; } = string.Empty;
+        public MarketType MarketType { get; init; }
+        public string Symbol { get; init; } = string.Empty;
+        public string Pair { get; init; } = string.Empty;
+        public MessageType MsgType { get; init; }
+        /// <summary>Unix timestamp, in milliseconds.</summary>
+        public long Timestamp { get; init; }
+        /// <summary>Begin time of the candle cycle, in seconds.</summary>
+        public long BeginTime { get; init; }
+        public double Open { get; init; }
+        public double High { get; init; }
+        public double Low { get; init; }
+        public double Close { get; init; }
+        /// <summary>Base volume.</summary>
+        public double Volume { get; init; }
+        /// <summary>m, minute; H, hour; D, day; W, week; M, month; Y, year.</summary>
+        public string Period { get; init; } = string.Empty;
+        /// <summary>Quote volume.</summary>
+        public double? QuoteVolume { get; init; }
+        /// <summary>The original JSON message.</summary>
+        public string Json { get; init; } = string.Empty;
+    }
+}
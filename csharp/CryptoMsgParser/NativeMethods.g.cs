@@ -0,0 +1,481 @@
+// <auto-generated>
+// Generated by csharp/generate.py from the C headers, don't modify it manually.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace CryptoMsgParser
+{
+    /// <summary>
+    /// Market type.
+    ///
+    /// * In spot market, cryptocurrencies are traded for immediate delivery, see https://en.wikipedia.org/wiki/Spot_market.
+    /// * In futures market, delivery is set at a specified time in the future, see https://en.wikipedia.org/wiki/Futures_exchange.
+    /// * Swap market is a variant of futures market with no expiry date.
+    ///
+    /// ## Margin
+    ///
+    /// A market can have margin enabled or disabled.
+    ///
+    /// * All contract markets are margin enabled, including future, swap and
+    ///   option.
+    /// * Most spot markets don't have margin enabled, only a few exchanges have
+    ///   spot market with margin enabled.
+    ///
+    /// ## Linear VS. Inverse
+    ///
+    /// A market can be inverse or linear.
+    /// * Linear means USDT-margined, i.e., you can use USDT as collateral
+    /// * Inverse means coin-margined, i.e., you can use BTC as collateral.
+    /// * Spot market is always linear.
+    ///
+    /// **Margin and Inverse are orthogonal.**
+    /// </summary>
+    public enum MarketType
+    {
+        Unknown = 0,
+        Spot = 1,
+        LinearFuture = 2,
+        InverseFuture = 3,
+        LinearSwap = 4,
+        InverseSwap = 5,
+        AmericanOption = 6,
+        EuropeanOption = 7,
+        QuantoFuture = 8,
+        QuantoSwap = 9,
+        Move = 10,
+        BVOL = 11,
+    }
+
+    /// <summary>
+    /// Crypto message types.
+    ///
+    /// L2Snapshot and L2TopK are very similar, the former is from RESTful API,
+    /// the latter is from websocket.
+    /// </summary>
+    public enum MessageType
+    {
+        /// <summary>
+        /// All other messages
+        /// </summary>
+        Other = 0,
+        /// <summary>
+        /// tick-by-tick trade messages
+        /// </summary>
+        Trade = 1,
+        /// <summary>
+        /// Incremental level2 orderbook updates
+        /// </summary>
+        L2Event = 2,
+        /// <summary>
+        /// Level2 snapshot from RESTful API
+        /// </summary>
+        L2Snapshot = 3,
+        /// <summary>
+        /// Level2 top K snapshots from websocket
+        /// </summary>
+        L2TopK = 4,
+        /// <summary>
+        /// Incremental level3 orderbook updates
+        /// </summary>
+        L3Event = 5,
+        /// <summary>
+        /// Level3 snapshot from RESTful API
+        /// </summary>
+        L3Snapshot = 6,
+        /// <summary>
+        /// Best bid and ask
+        /// </summary>
+        BBO = 7,
+        /// <summary>
+        /// 24hr rolling window ticker
+        /// </summary>
+        Ticker = 8,
+        /// <summary>
+        /// OHLCV candlestick
+        /// </summary>
+        Candlestick = 9,
+        /// <summary>
+        /// Funding rate
+        /// </summary>
+        FundingRate = 10,
+        /// <summary>
+        /// Open interest
+        /// </summary>
+        OpenInterest = 11,
+        /// <summary>
+        /// Long/short ratio
+        /// </summary>
+        LongShortRatio = 12,
+        /// <summary>
+        /// Taker buy/sell volume
+        /// </summary>
+        TakerVolume = 13,
+    }
+
+    /// <summary>
+    /// Compression of a raw message.
+    /// </summary>
+    public enum Compression
+    {
+        /// <summary>
+        /// Uncompressed text
+        /// </summary>
+        Plain = 0,
+        Gzip = 1,
+        /// <summary>
+        /// Raw deflate stream without header, RFC 1951
+        /// </summary>
+        Deflate = 2,
+        /// <summary>
+        /// Deflate stream with a zlib header, RFC 1950
+        /// </summary>
+        Zlib = 3,
+        /// <summary>
+        /// Detect gzip and zlib by their headers, otherwise the frame is plain
+        /// text if it starts with `{` or `[`, or a raw deflate stream
+        /// </summary>
+        Auto = 4,
+    }
+
+    /// <summary>
+    /// How prices and quantities are represented in the output.
+    /// </summary>
+    public enum NumberFormat
+    {
+        /// <summary>
+        /// Floating point numbers, same as the `parse_*` functions
+        /// </summary>
+        Float = 0,
+        /// <summary>
+        /// The decimal strings of the exchange, e.g., `"0.00073689"`, null for
+        /// values not in the raw message
+        /// </summary>
+        DecimalString = 1,
+        /// <summary>
+        /// Integer mantissas of the decimal strings of the exchange, each message
+        /// has a decimal exponent per column, e.g., `"price": 585701` with
+        /// `"price_exponent": -1`. Values not in the raw message are null, a
+        /// mantissa overflowing `i64` is an error
+        /// </summary>
+        FixedPoint = 2,
+    }
+
+    /// <summary>
+    /// Serialization format of normalized messages.
+    ///
+    /// All formats keep the field names of the JSON output.
+    /// </summary>
+    public enum OutputFormat
+    {
+        Json = 0,
+        MessagePack = 1,
+        Cbor = 2,
+        /// <summary>
+        /// A list message defined in `proto/crypto_msg_parser.proto`, e.g., `TradeList`
+        /// </summary>
+        Protobuf = 3,
+        /// <summary>
+        /// A `Messages` root table defined in `fbs/crypto_msg_parser.fbs`, only
+        /// trade, orderbook and BBO messages are supported
+        /// </summary>
+        FlatBuffers = 4,
+        /// <summary>
+        /// CSV rows without the header row, see `csv_header()` and the `csv` module
+        /// </summary>
+        Csv = 5,
+    }
+
+    /// <summary>
+    /// Encoding of a raw message.
+    /// </summary>
+    public enum PayloadEncoding
+    {
+        /// <summary>
+        /// UTF-8 JSON text, parsed by `crypto-msg-parser`
+        /// </summary>
+        Json = 0,
+        /// <summary>
+        /// Binance spot SBE market data streams, see the `sbe` module
+        /// </summary>
+        BinanceSbe = 1,
+    }
+
+    /// <summary>
+    /// A byte buffer allocated by this library.
+    ///
+    /// `data` is NULL if an error happens, call `deallocate_bytes()` to free it.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public struct ByteBuffer
+    {
+        public IntPtr Data;
+        public nuint Len;
+    }
+
+    /// <summary>
+    /// Options of the `parse_*_with_options()` functions.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public struct ParseOptions
+    {
+        public OutputFormat Format;
+        /// <summary>
+        /// Only JSON, MessagePack and CBOR support number formats other than `Float`
+        /// </summary>
+        public NumberFormat NumberFormat;
+        /// <summary>
+        /// Comma separated names of fields to keep, e.g., `"timestamp,price,quantity_base,side"`,
+        /// NULL means all fields.
+        ///
+        /// Only JSON, MessagePack and CBOR support it. Exponent fields of the
+        /// `FixedPoint` number format are always kept.
+        /// </summary>
+        public IntPtr Fields;
+        /// <summary>
+        /// Replace the original message in the `json` field with an empty string,
+        /// which roughly halves the output size.
+        /// </summary>
+        [MarshalAs(UnmanagedType.U1)]
+        public bool OmitJson;
+    }
+
+    internal static partial class NativeMethods
+    {
+        internal const string LibraryName = "crypto_msg_parser_ffi";
+
+        /// <summary>
+        /// ABI version of the header a program is compiled against.
+        /// </summary>
+        internal const uint CRYPTO_MSG_PARSER_FFI_ABI_VERSION = 1;
+
+        /// <summary>
+        /// Extract the symbol from the message.
+        ///
+        /// * If the message contains multiple symbols, `ALL` is returned;
+        /// * If the message has no symbol, `NONE` is returned.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString extract_symbol([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg);
+
+        /// <summary>
+        /// Extract the timestamp from the message.
+        ///
+        /// Returns 0 if the message doesn't have a timestamp, -1 if an error happens.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern long extract_timestamp([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg);
+
+        /// <summary>
+        /// Infer the message type from the message.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern MessageType get_msg_type([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg);
+
+        /// <summary>
+        /// Parse a raw trade message into a Vec&lt;TradeMsg&gt; and then convert to a JSON string.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString parse_trade([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg);
+
+        /// <summary>
+        /// Parse a raw level2 orderbook message into a Vec&lt;OrderBookMsg&gt; and then convert to a JSON string.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString parse_l2([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at);
+
+        /// <summary>
+        /// Parse a level2 topk orderbook message into a Vec&lt;OrderBookMsg&gt; and then convert to a JSON string.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString parse_l2_topk([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at);
+
+        /// <summary>
+        /// Parse a BBO(best bid&amp;offer) message into a Vec&lt;BboMsg&gt; and then convert to a JSON string.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString parse_bbo([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at);
+
+        /// <summary>
+        /// Parse a raw funding rate message into a Vec&lt;FundingRateMsg&gt; and then convert to a JSON string.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString parse_funding_rate([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at);
+
+        /// <summary>
+        /// Parse a raw candlestick message into a Vec&lt;CandlestickMsg&gt; and then convert to a JSON string.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString parse_candlestick([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at);
+
+        /// <summary>
+        /// Get the CSV header row of a message type, returns NULL if the message
+        /// type has no CSV representation.
+        ///
+        /// See the `csv` module for the columns of each message type.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString csv_header(MessageType msg_type);
+
+        /// <summary>
+        /// Parse a raw trade message into a Vec&lt;TradeMsg&gt; and then serialize it in the given format.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_trade_with_format([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, OutputFormat format);
+
+        /// <summary>
+        /// Parse a raw level2 orderbook message into a Vec&lt;OrderBookMsg&gt; and then serialize it in the given format.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_l2_with_format([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, OutputFormat format);
+
+        /// <summary>
+        /// Parse a level2 topk orderbook message into a Vec&lt;OrderBookMsg&gt; and then serialize it in the given format.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_l2_topk_with_format([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, OutputFormat format);
+
+        /// <summary>
+        /// Parse a BBO(best bid&amp;offer) message into a Vec&lt;BboMsg&gt; and then serialize it in the given format.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_bbo_with_format([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, OutputFormat format);
+
+        /// <summary>
+        /// Parse a raw funding rate message into a Vec&lt;FundingRateMsg&gt; and then serialize it in the given format.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_funding_rate_with_format([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, OutputFormat format);
+
+        /// <summary>
+        /// Parse a raw candlestick message into a Vec&lt;CandlestickMsg&gt; and then serialize it in the given format.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_candlestick_with_format([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, OutputFormat format);
+
+        /// <summary>
+        /// Parse a raw trade message into a Vec&lt;TradeMsg&gt; and then serialize it with the given options.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_trade_with_options([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, ParseOptions options);
+
+        /// <summary>
+        /// Parse a raw level2 orderbook message into a Vec&lt;OrderBookMsg&gt; and then serialize it with the given options.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_l2_with_options([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, ParseOptions options);
+
+        /// <summary>
+        /// Parse a level2 topk orderbook message into a Vec&lt;OrderBookMsg&gt; and then serialize it with the given options.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_l2_topk_with_options([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, ParseOptions options);
+
+        /// <summary>
+        /// Parse a BBO(best bid&amp;offer) message into a Vec&lt;BboMsg&gt; and then serialize it with the given options.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_bbo_with_options([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, ParseOptions options);
+
+        /// <summary>
+        /// Parse a raw funding rate message into a Vec&lt;FundingRateMsg&gt; and then serialize it with the given options.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_funding_rate_with_options([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, ParseOptions options);
+
+        /// <summary>
+        /// Parse a raw candlestick message into a Vec&lt;CandlestickMsg&gt; and then serialize it with the given options.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_candlestick_with_options([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, [MarshalAs(UnmanagedType.LPUTF8Str)] string msg, long received_at, ParseOptions options);
+
+        /// <summary>
+        /// Parse a possibly compressed raw message of `len` bytes with the `parse_*`
+        /// function matching `msg_type`, then serialize the messages with the given options.
+        ///
+        /// The message is decompressed according to `compression` before parsing,
+        /// `received_at` is ignored by trade messages.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_compressed([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, MessageType msg_type, byte[] data, nuint len, Compression compression, long received_at, ParseOptions options);
+
+        /// <summary>
+        /// Parse a raw message of `len` bytes in the given encoding, then serialize
+        /// the messages with the given options.
+        ///
+        /// Binary payloads such as `BinanceSbe` produce the same normalized messages
+        /// as JSON ones, with an empty `json` field.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ByteBuffer parse_payload_bytes([MarshalAs(UnmanagedType.LPUTF8Str)] string exchange, MarketType market_type, MessageType msg_type, byte[] data, nuint len, PayloadEncoding encoding, long received_at, ParseOptions options);
+
+        /// <summary>
+        /// Deallocate a string.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern void deallocate_string(IntPtr pointer);
+
+        /// <summary>
+        /// Get the ABI version of the loaded library.
+        ///
+        /// A program should check at startup that it equals the
+        /// `CRYPTO_MSG_PARSER_FFI_ABI_VERSION` it was compiled with.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern uint abi_version();
+
+        /// <summary>
+        /// Get the error message of the last call on the current thread, returns
+        /// NULL if it succeeded.
+        ///
+        /// Errors are recorded by the functions returning a `ByteBuffer`
+        /// (`parse_*_with_format()`, `parse_*_with_options()`, `parse_compressed()`
+        /// and `parse_payload_bytes()`), `raw_file_reader_open()`,
+        /// `export_arrow_schema()`, `parse_batch_arrow()` and the `parquet_writer_*`
+        /// functions. Each of them clears the error when called, so the result
+        /// always belongs to the last of them. Functions returning a string, such as
+        /// `parse_trade()` or `extract_symbol()`, only return NULL on failure and
+        /// leave the error untouched.
+        ///
+        /// The string is owned by this library and valid until the next call of a
+        /// function that records errors on the same thread, don't free it.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern IntPtr last_error();
+
+        /// <summary>
+        /// Get the default options, i.e., JSON output with floating point numbers.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern ParseOptions default_parse_options();
+
+        /// <summary>
+        /// Deallocate a byte buffer.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern void deallocate_bytes(ByteBuffer buffer);
+
+        /// <summary>
+        /// Open a crypto-crawler archive, returns NULL if the file can't be opened.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern IntPtr raw_file_reader_open([MarshalAs(UnmanagedType.LPUTF8Str)] string path);
+
+        /// <summary>
+        /// Parse the next line and convert the result to a JSON string.
+        ///
+        /// The JSON object has a `line_number` field, and either a `messages` array
+        /// or an `error` string. Returns NULL at the end of the file, and after an
+        /// I/O error, which is returned as the `error` of the last line.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern NativeString raw_file_reader_next(IntPtr reader);
+
+        /// <summary>
+        /// Close a reader returned by `raw_file_reader_open()`.
+        /// </summary>
+        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern void raw_file_reader_close(IntPtr reader);
+    }
+}
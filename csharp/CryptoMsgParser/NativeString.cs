@@ -0,0 +1,31 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace CryptoMsgParser
+{
+    /// <summary>
+    /// A string returned by the native library, freed by <c>deallocate_string()</c>
+    /// when it is disposed or finalized.
+    /// </summary>
+    public sealed class NativeString : SafeHandle
+    {
+        public NativeString()
+            : base(IntPtr.Zero, ownsHandle: true)
+        {
+        }
+
+        public override bool IsInvalid => handle == IntPtr.Zero;
+
+        protected override bool ReleaseHandle()
+        {
+            NativeMethods.deallocate_string(handle);
+            return true;
+        }
+
+        /// <summary>
+        /// Copy the UTF-8 string into a managed string, empty if the handle is NULL.
+        /// </summary>
+        public override string ToString() =>
+            IsInvalid ? string.Empty : Marshal.PtrToStringUTF8(handle) ?? string.Empty;
+    }
+}
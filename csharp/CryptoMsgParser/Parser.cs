@@ -0,0 +1,129 @@
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace CryptoMsgParser
+{
+    /// <summary>
+    /// A raw message can't be parsed.
+    /// </summary>
+    public sealed class ParseException : Exception
+    {
+        public ParseException(string message)
+            : base(message)
+        {
+        }
+    }
+
+    /// <summary>
+    /// Parse raw messages of cryptocurrency exchanges into normalized messages.
+    /// </summary>
+    /// <remarks>
+    /// <c>receivedAt</c> is used by message types whose raw messages may lack a
+    /// timestamp, a non-positive value means it is unknown.
+    /// </remarks>
+    public static class Parser
+    {
+        private static readonly JsonSerializerOptions JsonOptions = new()
+        {
+            PropertyNamingPolicy = JsonNamingPolicy.SnakeCaseLower,
+            Converters =
+            {
+                new JsonStringEnumConverter(new EnumNamingPolicy()),
+                new OrderConverter(),
+            },
+        };
+
+        /// <summary>
+        /// Whether the loaded native library has the ABI version this binding is generated from.
+        /// </summary>
+        public static bool AbiCompatible =>
+            NativeMethods.abi_version() == NativeMethods.CRYPTO_MSG_PARSER_FFI_ABI_VERSION;
+
+        /// <summary>
+        /// Extract the symbol, <c>ALL</c> for multiple symbols, <c>NONE</c> for no symbol.
+        /// </summary>
+        public static string ExtractSymbol(string exchange, MarketType marketType, string msg)
+        {
+            using var symbol = NativeMethods.extract_symbol(exchange, marketType, msg);
+            if (symbol.IsInvalid)
+            {
+                throw new ParseException($"Failed to extract the symbol from {msg}");
+            }
+            return JsonSerializer.Deserialize<string>(symbol.ToString(), JsonOptions)!;
+        }
+
+        /// <summary>
+        /// Extract the timestamp in milliseconds, null if the message has none.
+        /// </summary>
+        public static long? ExtractTimestamp(string exchange, MarketType marketType, string msg)
+        {
+            var timestamp = NativeMethods.extract_timestamp(exchange, marketType, msg);
+            if (timestamp < 0)
+            {
+                throw new ParseException($"Failed to extract the timestamp from {msg}");
+            }
+            return timestamp == 0 ? null : timestamp;
+        }
+
+        /// <summary>
+        /// Infer the message type from the message.
+        /// </summary>
+        public static MessageType GetMsgType(string exchange, string msg) =>
+            NativeMethods.get_msg_type(exchange, msg);
+
+        /// <summary>
+        /// Parse a raw trade message.
+        /// </summary>
+        public static IReadOnlyList<TradeMsg> ParseTrade(string exchange, MarketType marketType, string msg) =>
+            Deserialize<TradeMsg>(NativeMethods.parse_trade(exchange, marketType, msg), msg);
+
+        /// <summary>
+        /// Parse a raw level2 orderbook message.
+        /// </summary>
+        public static IReadOnlyList<OrderBookMsg> ParseL2(
+            string exchange, MarketType marketType, string msg, long receivedAt = 0) =>
+            Deserialize<OrderBookMsg>(NativeMethods.parse_l2(exchange, marketType, msg, receivedAt), msg);
+
+        /// <summary>
+        /// Parse a level2 topk orderbook message.
+        /// </summary>
+        public static IReadOnlyList<OrderBookMsg> ParseL2Topk(
+            string exchange, MarketType marketType, string msg, long receivedAt = 0) =>
+            Deserialize<OrderBookMsg>(NativeMethods.parse_l2_topk(exchange, marketType, msg, receivedAt), msg);
+
+        /// <summary>
+        /// Parse a BBO(best bid&amp;offer) message.
+        /// </summary>
+        public static IReadOnlyList<BboMsg> ParseBbo(
+            string exchange, MarketType marketType, string msg, long receivedAt = 0) =>
+            Deserialize<BboMsg>(NativeMethods.parse_bbo(exchange, marketType, msg, receivedAt), msg);
+
+        /// <summary>
+        /// Parse a raw funding rate message.
+        /// </summary>
+        public static IReadOnlyList<FundingRateMsg> ParseFundingRate(
+            string exchange, MarketType marketType, string msg, long receivedAt = 0) =>
+            Deserialize<FundingRateMsg>(NativeMethods.parse_funding_rate(exchange, marketType, msg, receivedAt), msg);
+
+        /// <summary>
+        /// Parse a raw candlestick message.
+        /// </summary>
+        public static IReadOnlyList<CandlestickMsg> ParseCandlestick(
+            string exchange, MarketType marketType, string msg, long receivedAt = 0) =>
+            Deserialize<CandlestickMsg>(NativeMethods.parse_candlestick(exchange, marketType, msg, receivedAt), msg);
+
+        private static IReadOnlyList<T> Deserialize<T>(NativeString json, string msg)
+        {
+            using (json)
+            {
+                if (json.IsInvalid)
+                {
+                    throw new ParseException($"Failed to parse {msg}");
+                }
+                return JsonSerializer.Deserialize<List<T>>(json.ToString(), JsonOptions)!;
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+#!/usr/bin/env python3
+"""Generate CryptoMsgParser/NativeMethods.g.cs from the C headers.
+
+Integer constants, enums, structs and functions are read from the cbindgen
+output, those behind cargo features are skipped. Run it after the headers change:
+
+    python3 csharp/generate.py
+"""
+
+import re
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+HEADERS = [
+    ROOT / "include" / "crypto_market_type.h",
+    ROOT / "include" / "crypto_msg_type.h",
+    ROOT / "include" / "crypto_msg_parser_ffi.h",
+]
+OUTPUT = Path(__file__).resolve().parent / "CryptoMsgParser" / "NativeMethods.g.cs"
+
+# Preprocessor blocks to skip, i.e., C++ only code, the Arrow C Data Interface
+# and functions behind cargo features
+SKIPPED_BLOCKS = re.compile(
+    r"#ifdef __cplusplus|#ifndef ARROW_C_DATA_INTERFACE|#if defined\(CRYPTO_MSG_PARSER_FFI_\w+\)"
+)
+# Returned strings which are owned by the library and must not be freed
+BORROWED_STRINGS = {"last_error"}
+# Functions which free a string returned by the library
+STRING_DEALLOCATORS = {"deallocate_string"}
+CSHARP_KEYWORDS = {"base", "checked", "event", "fixed", "lock", "object", "out", "params", "ref", "string"}
+
+INT_TYPES = {
+    "int8_t": "sbyte",
+    "uint8_t": "byte",
+    "int16_t": "short",
+    "uint16_t": "ushort",
+    "int32_t": "int",
+    "uint32_t": "uint",
+    "int64_t": "long",
+    "uint64_t": "ulong",
+    "size_t": "nuint",
+}
+UTF8_STRING = "[MarshalAs(UnmanagedType.LPUTF8Str)] string"
+BOOL = "[MarshalAs(UnmanagedType.U1)] bool"
+
+
+def pascal_case(name):
+    return "".join(part[:1].upper() + part[1:] for part in name.split("_"))
+
+
+def identifier(name):
+    return "@" + name if name in CSHARP_KEYWORDS else name
+
+
+def doc_lines(comment, indent):
+    """Convert the text of a /** */ comment into an XML doc comment."""
+    if not comment:
+        return []
+    text = [
+        line.replace("&", "&amp;").replace("<", "&lt;").replace(">", "&gt;")
+        for line in comment
+    ]
+    while text and not text[-1]:
+        text.pop()
+    lines = [f"{indent}/// <summary>"]
+    lines += [f"{indent}/// {line}".rstrip() for line in text]
+    lines.append(f"{indent}/// </summary>")
+    return lines
+
+
+def parse_headers():
+    """Returns constants, enums, structs and functions in the order of declaration."""
+    constants, enums, structs, functions = [], [], [], []
+    for header in HEADERS:
+        lines = header.read_text().splitlines()
+        # True for each open preprocessor block which is skipped
+        blocks = []
+        comment, in_comment = [], False
+        body, kind = None, None
+        prototype = None
+        for line in lines:
+            stripped = line.strip()
+            if stripped.startswith("#if"):
+                blocks.append(bool(SKIPPED_BLOCKS.match(stripped)))
+                continue
+            if stripped.startswith("#endif"):
+                blocks.pop()
+                continue
+            if any(blocks):
+                continue
+            if stripped.startswith("#"):
+                match = re.match(r"#define (\w+) (\d+)$", stripped)
+                if match:
+                    constants.append((match.group(1), comment, match.group(2)))
+                comment = []
+                continue
+
+            if in_comment:
+                if stripped.endswith("*/"):
+                    in_comment = False
+                else:
+                    comment.append(re.sub(r"^\* ?", "", stripped))
+                continue
+            if stripped.startswith("/**"):
+                in_comment = not stripped.endswith("*/")
+                comment = [] if in_comment else [stripped[3:-2].strip()]
+                continue
+            if not stripped or stripped.startswith("/*") or stripped.startswith("//"):
+                continue
+
+            if body is not None:
+                match = re.match(r"}\s*(\w+);", stripped)
+                if match:
+                    name = match.group(1)
+                    (enums if kind == "enum" else structs).append((name, body_doc, body))
+                    body = None
+                else:
+                    body.append((stripped.rstrip(",;"), comment))
+                comment = []
+                continue
+            match = re.match(r"typedef (enum|struct) {", stripped)
+            if match:
+                kind, body, body_doc, comment = match.group(1), [], comment, []
+                continue
+            if stripped.startswith("typedef"):
+                comment = []
+                continue
+
+            prototype = stripped if prototype is None else f"{prototype} {stripped}"
+            if prototype.endswith(";"):
+                match = re.match(r"(.+?)\s*\b(\w+)\((.*)\);", prototype)
+                ret, name, params = match.groups()
+                params = [] if params in ("", "void") else [p.strip() for p in params.split(",")]
+                functions.append((name, comment, ret, params))
+                prototype, comment = None, []
+    return constants, enums, structs, functions
+
+
+def split_declaration(declaration):
+    match = re.match(r"(.+?)\s*\b(\w+)$", declaration)
+    return match.group(1).replace(" *", "*").strip(), match.group(2)
+
+
+def csharp_type(c_type, names, position, function=None):
+    c_type = c_type.replace("struct ", "")
+    if c_type == "const char*":
+        if position == "return":
+            return "IntPtr" if function in BORROWED_STRINGS else "NativeString"
+        if position == "param" and function not in STRING_DEALLOCATORS:
+            return UTF8_STRING
+        return "IntPtr"
+    if c_type == "const uint8_t*" and position == "param":
+        return "byte[]"
+    if c_type.endswith("*"):
+        return "IntPtr"
+    if c_type == "bool":
+        return "bool" if position == "return" else BOOL
+    if c_type == "void":
+        return "void"
+    if c_type in INT_TYPES:
+        return INT_TYPES[c_type]
+    if c_type in names:
+        return c_type
+    raise ValueError(f"Unsupported C type {c_type}")
+
+
+def generate():
+    constants, enums, structs, functions = parse_headers()
+    names = {name for name, _, _ in enums} | {name for name, _, _ in structs}
+    out = [
+        "// <auto-generated>",
+        "// Generated by csharp/generate.py from the C headers, don't modify it manually.",
+        "// </auto-generated>",
+        "",
+        "using System;",
+        "using System.Runtime.InteropServices;",
+        "",
+        "namespace CryptoMsgParser",
+        "{",
+    ]
+    for name, doc, members in enums:
+        out += doc_lines(doc, "    ")
+        out += [f"    public enum {name}", "    {"]
+        for member, member_doc in members:
+            out += doc_lines(member_doc, "        ")
+            out.append(f"        {member.removeprefix(name + '_')},")
+        out += ["    }", ""]
+    for name, doc, fields in structs:
+        out += doc_lines(doc, "    ")
+        out += ["    [StructLayout(LayoutKind.Sequential)]", f"    public struct {name}", "    {"]
+        for field, field_doc in fields:
+            c_type, field_name = split_declaration(field)
+            out += doc_lines(field_doc, "        ")
+            field_type = csharp_type(c_type, names, "field")
+            if field_type.startswith("["):
+                attribute, field_type = field_type.split("] ")
+                out.append(f"        {attribute}]")
+            out.append(f"        public {field_type} {pascal_case(field_name)};")
+        out += ["    }", ""]
+
+    out += [
+        "    internal static partial class NativeMethods",
+        "    {",
+        '        internal const string LibraryName = "crypto_msg_parser_ffi";',
+    ]
+    for name, doc, value in constants:
+        out.append("")
+        out += doc_lines(doc, "        ")
+        out.append(f"        internal const uint {name} = {value};")
+    for name, doc, ret, params in functions:
+        ret = csharp_type(ret.replace(" *", "*"), names, "return", name)
+        args = []
+        for param in params:
+            c_type, param_name = split_declaration(param)
+            args.append(f"{csharp_type(c_type, names, 'param', name)} {identifier(param_name)}")
+        out.append("")
+        out += doc_lines(doc, "        ")
+        out.append("        [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]")
+        if ret == "bool":
+            out.append("        [return: MarshalAs(UnmanagedType.U1)]")
+        out.append(f"        internal static extern {ret} {name}({', '.join(args)});")
+    out += ["    }", "}", ""]
+    OUTPUT.write_text("\n".join(out))
+
+
+if __name__ == "__main__":
+    generate()
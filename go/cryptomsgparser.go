@@ -0,0 +1,158 @@
+// Package cryptomsgparser parses raw messages of cryptocurrency exchanges into
+// normalized messages by calling the crypto-msg-parser-ffi library via cgo.
+//
+// Build the library first with `make` in the parent directory, the package
+// links to target/release/libcrypto_msg_parser_ffi.
+package cryptomsgparser
+
+/*
+#cgo CFLAGS: -I${SRCDIR}/../include
+#cgo LDFLAGS: -L${SRCDIR}/../target/release -lcrypto_msg_parser_ffi
+#cgo linux darwin LDFLAGS: -Wl,-rpath,${SRCDIR}/../target/release
+#include <stdlib.h>
+#include "crypto_msg_parser_ffi.h"
+*/
+import "C"
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"unsafe"
+)
+
+// ErrParse is returned, wrapped, when a raw message can't be parsed.
+var ErrParse = errors.New("cryptomsgparser: failed to parse the message")
+
+// call passes exchange and msg as C strings to f, then decodes the JSON string
+// returned by f into v and frees it.
+func call(exchange, msg string, f func(exchange, msg *C.char) *C.char, v interface{}) bool {
+	cExchange := C.CString(exchange)
+	defer C.free(unsafe.Pointer(cExchange))
+	cMsg := C.CString(msg)
+	defer C.free(unsafe.Pointer(cMsg))
+
+	ptr := f(cExchange, cMsg)
+	if ptr == nil {
+		return false
+	}
+	defer C.deallocate_string(ptr)
+	return json.Unmarshal([]byte(C.GoString(ptr)), v) == nil
+}
+
+func parseError(exchange string, marketType MarketType, msgType MessageType, msg string) error {
+	return fmt.Errorf("%w: %s %s %s %s", ErrParse, exchange, marketType, msgType, msg)
+}
+
+// ExtractSymbol extracts the symbol from the message, ALL if the message
+// contains multiple symbols, NONE if the message has no symbol.
+func ExtractSymbol(exchange string, marketType MarketType, msg string) (string, error) {
+	var symbol string
+	ok := call(exchange, msg, func(exchange, msg *C.char) *C.char {
+		return C.extract_symbol(exchange, C.MarketType(marketType), msg)
+	}, &symbol)
+	if !ok {
+		return "", fmt.Errorf("cryptomsgparser: failed to extract the symbol from %s", msg)
+	}
+	return symbol, nil
+}
+
+// ExtractTimestamp extracts the timestamp in milliseconds from the message,
+// 0 if the message has no timestamp.
+func ExtractTimestamp(exchange string, marketType MarketType, msg string) (int64, error) {
+	cExchange := C.CString(exchange)
+	defer C.free(unsafe.Pointer(cExchange))
+	cMsg := C.CString(msg)
+	defer C.free(unsafe.Pointer(cMsg))
+
+	timestamp := int64(C.extract_timestamp(cExchange, C.MarketType(marketType), cMsg))
+	if timestamp < 0 {
+		return 0, fmt.Errorf("cryptomsgparser: failed to extract the timestamp from %s", msg)
+	}
+	return timestamp, nil
+}
+
+// GetMsgType infers the message type from the message.
+func GetMsgType(exchange string, msg string) MessageType {
+	cExchange := C.CString(exchange)
+	defer C.free(unsafe.Pointer(cExchange))
+	cMsg := C.CString(msg)
+	defer C.free(unsafe.Pointer(cMsg))
+
+	return MessageType(C.get_msg_type(cExchange, cMsg))
+}
+
+// ParseTrade parses a raw trade message.
+func ParseTrade(exchange string, marketType MarketType, msg string) ([]TradeMsg, error) {
+	var trades []TradeMsg
+	ok := call(exchange, msg, func(exchange, msg *C.char) *C.char {
+		return C.parse_trade(exchange, C.MarketType(marketType), msg)
+	}, &trades)
+	if !ok {
+		return nil, parseError(exchange, marketType, Trade, msg)
+	}
+	return trades, nil
+}
+
+// ParseL2 parses a raw level2 orderbook message.
+//
+// receivedAt is the time when the message was received, in milliseconds,
+// used if the message has no timestamp, a non-positive value means unknown.
+func ParseL2(exchange string, marketType MarketType, msg string, receivedAt int64) ([]OrderBookMsg, error) {
+	var orderbooks []OrderBookMsg
+	ok := call(exchange, msg, func(exchange, msg *C.char) *C.char {
+		return C.parse_l2(exchange, C.MarketType(marketType), msg, C.int64_t(receivedAt))
+	}, &orderbooks)
+	if !ok {
+		return nil, parseError(exchange, marketType, L2Event, msg)
+	}
+	return orderbooks, nil
+}
+
+// ParseL2TopK parses a level2 topk orderbook message.
+func ParseL2TopK(exchange string, marketType MarketType, msg string, receivedAt int64) ([]OrderBookMsg, error) {
+	var orderbooks []OrderBookMsg
+	ok := call(exchange, msg, func(exchange, msg *C.char) *C.char {
+		return C.parse_l2_topk(exchange, C.MarketType(marketType), msg, C.int64_t(receivedAt))
+	}, &orderbooks)
+	if !ok {
+		return nil, parseError(exchange, marketType, L2TopK, msg)
+	}
+	return orderbooks, nil
+}
+
+// ParseBbo parses a BBO(best bid&offer) message.
+func ParseBbo(exchange string, marketType MarketType, msg string, receivedAt int64) ([]BboMsg, error) {
+	var bbos []BboMsg
+	ok := call(exchange, msg, func(exchange, msg *C.char) *C.char {
+		return C.parse_bbo(exchange, C.MarketType(marketType), msg, C.int64_t(receivedAt))
+	}, &bbos)
+	if !ok {
+		return nil, parseError(exchange, marketType, BBO, msg)
+	}
+	return bbos, nil
+}
+
+// ParseFundingRate parses a raw funding rate message.
+func ParseFundingRate(exchange string, marketType MarketType, msg string, receivedAt int64) ([]FundingRateMsg, error) {
+	var rates []FundingRateMsg
+	ok := call(exchange, msg, func(exchange, msg *C.char) *C.char {
+		return C.parse_funding_rate(exchange, C.MarketType(marketType), msg, C.int64_t(receivedAt))
+	}, &rates)
+	if !ok {
+		return nil, parseError(exchange, marketType, FundingRate, msg)
+	}
+	return rates, nil
+}
+
+// ParseCandlestick parses a raw candlestick message.
+func ParseCandlestick(exchange string, marketType MarketType, msg string, receivedAt int64) ([]CandlestickMsg, error) {
+	var candlesticks []CandlestickMsg
+	ok := call(exchange, msg, func(exchange, msg *C.char) *C.char {
+		return C.parse_candlestick(exchange, C.MarketType(marketType), msg, C.int64_t(receivedAt))
+	}, &candlesticks)
+	if !ok {
+		return nil, parseError(exchange, marketType, Candlestick, msg)
+	}
+	return candlesticks, nil
+}
@@ -0,0 +1,161 @@
+package cryptomsgparser
+
+import (
+	"errors"
+	"math"
+	"testing"
+)
+
+func assertEqual[T comparable](t *testing.T, expected, actual T) {
+	t.Helper()
+	if expected != actual {
+		t.Errorf("expected %v, got %v", expected, actual)
+	}
+}
+
+func assertSome(t *testing.T, expected float64, actual *float64) {
+	t.Helper()
+	if actual == nil {
+		t.Errorf("expected %v, got nil", expected)
+	} else if expected != *actual {
+		t.Errorf("expected %v, got %v", expected, *actual)
+	}
+}
+
+func TestParseTrade(t *testing.T) {
+	rawMsg := `{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}`
+	trades, err := ParseTrade("binance", InverseSwap, rawMsg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assertEqual(t, 1, len(trades))
+	trade := trades[0]
+
+	assertEqual(t, "binance", trade.Exchange)
+	assertEqual(t, InverseSwap, trade.MarketType)
+	assertEqual(t, Trade, trade.MsgType)
+	assertEqual(t, Trade, GetMsgType("binance", rawMsg))
+	assertEqual(t, 58570.1, trade.Price)
+	if math.Abs(trade.QuantityBase-5800.0/58570.1) > 0.00000000000000002 {
+		t.Errorf("unexpected quantity_base %v", trade.QuantityBase)
+	}
+	assertEqual(t, 5800.0, trade.QuantityQuote)
+	assertSome(t, 58.0, trade.QuantityContract)
+	assertEqual(t, Sell, trade.Side)
+}
+
+func TestParseL2(t *testing.T) {
+	rawMsg := `{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}`
+	orderbooks, err := ParseL2("binance", InverseSwap, rawMsg, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assertEqual(t, 1, len(orderbooks))
+	orderbook := orderbooks[0]
+
+	assertEqual(t, "binance", orderbook.Exchange)
+	assertEqual(t, InverseSwap, orderbook.MarketType)
+	assertEqual(t, L2Event, orderbook.MsgType)
+	assertEqual(t, L2Event, GetMsgType("binance", rawMsg))
+	assertEqual(t, 2, len(orderbook.Asks))
+	assertEqual(t, 2, len(orderbook.Bids))
+	assertEqual(t, false, orderbook.Snapshot)
+	assertEqual(t, int64(1622370862564), orderbook.Timestamp)
+
+	assertEqual(t, 35365.9, orderbook.Bids[0].Price)
+	assertSome(t, 1400.0, orderbook.Bids[0].QuantityContract)
+	assertEqual(t, 35817.8, orderbook.Asks[0].Price)
+	assertSome(t, 7885.0, orderbook.Asks[0].QuantityContract)
+}
+
+func TestParseBbo(t *testing.T) {
+	rawMsg := `{"stream":"ethusdt@bookTicker","data":{"e":"bookTicker","u":1553413152520,"s":"ETHUSDT","b":"1778.54","B":"15.164","a":"1778.55","A":"7.289","T":1653817855284,"E":1653817855289}}`
+	bbos, err := ParseBbo("binance", LinearSwap, rawMsg, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assertEqual(t, 1, len(bbos))
+	bbo := bbos[0]
+
+	assertEqual(t, BBO, bbo.MsgType)
+	assertEqual(t, "ETHUSDT", bbo.Symbol)
+	assertEqual(t, int64(1653817855289), bbo.Timestamp)
+	if bbo.ID == nil || *bbo.ID != 1553413152520 {
+		t.Errorf("unexpected id %v", bbo.ID)
+	}
+
+	// variables rather than constants, so that products are rounded to float64
+	askPrice, askQuantity := 1778.55, 7.289
+	assertEqual(t, askPrice, bbo.AskPrice)
+	assertEqual(t, askQuantity, bbo.AskQuantityBase)
+	assertEqual(t, askPrice*askQuantity, bbo.AskQuantityQuote)
+	assertSome(t, askQuantity, bbo.AskQuantityContract)
+
+	bidPrice, bidQuantity := 1778.54, 15.164
+	assertEqual(t, bidPrice, bbo.BidPrice)
+	assertEqual(t, bidQuantity, bbo.BidQuantityBase)
+	assertEqual(t, bidPrice*bidQuantity, bbo.BidQuantityQuote)
+	assertSome(t, bidQuantity, bbo.BidQuantityContract)
+}
+
+func TestParseFundingRate(t *testing.T) {
+	rawMsg := `{"stream":"btcusd_perp@markPrice","data":{"e":"markPriceUpdate","E":1617309477000,"s":"BTCUSD_PERP","p":"59012.56007222","P":"58896.00503145","r":"0.00073689","T":1617321600000}}`
+	rates, err := ParseFundingRate("binance", InverseSwap, rawMsg, -1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assertEqual(t, 1, len(rates))
+	rate := rates[0]
+
+	assertEqual(t, "binance", rate.Exchange)
+	assertEqual(t, InverseSwap, rate.MarketType)
+	assertEqual(t, FundingRate, rate.MsgType)
+	assertEqual(t, FundingRate, GetMsgType("binance", rawMsg))
+	assertEqual(t, "BTC/USD", rate.Pair)
+	assertEqual(t, 0.00073689, rate.FundingRate)
+	assertEqual(t, int64(1617321600000), rate.FundingTime)
+}
+
+func TestExtractTimestamp(t *testing.T) {
+	rawMsg := `{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}`
+	timestamp, err := ExtractTimestamp("binance", InverseSwap, rawMsg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assertEqual(t, int64(1622370862564), timestamp)
+
+	symbol, err := ExtractSymbol("binance", InverseSwap, rawMsg)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assertEqual(t, "BTCUSD_PERP", symbol)
+}
+
+func TestParseCandlestick(t *testing.T) {
+	rawMsg := `{"stream":"btcusdt@kline_1M","data":{"e":"kline","E":1653819041520,"s":"BTCUSDT","k":{"t":1651363200000,"T":1654041599999,"s":"BTCUSDT","i":"1M","f":2172726276,"L":2301806561,"o":"37614.40","c":"29075.50","h":"40071.70","l":"26631.00","v":"13431981.671","n":129025447,"x":false,"q":"423075730671.12853","V":"6700065.176","Q":"211000435586.65000","B":"0"}}}`
+	candlesticks, err := ParseCandlestick("binance", LinearSwap, rawMsg, -1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	assertEqual(t, 1, len(candlesticks))
+	candlestick := candlesticks[0]
+
+	assertEqual(t, "BTCUSDT", candlestick.Symbol)
+	assertEqual(t, int64(1653819041520), candlestick.Timestamp)
+	assertEqual(t, "1M", candlestick.Period)
+	assertEqual(t, int64(1651363200), candlestick.BeginTime)
+
+	assertEqual(t, 37614.40, candlestick.Open)
+	assertEqual(t, 40071.70, candlestick.High)
+	assertEqual(t, 26631.0, candlestick.Low)
+	assertEqual(t, 29075.5, candlestick.Close)
+	assertEqual(t, 13431981.671, candlestick.Volume)
+	assertSome(t, 423075730671.12853, candlestick.QuoteVolume)
+}
+
+func TestParseError(t *testing.T) {
+	_, err := ParseTrade("binance", InverseSwap, "not json")
+	if !errors.Is(err, ErrParse) {
+		t.Errorf("expected ErrParse, got %v", err)
+	}
+}
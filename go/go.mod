@@ -0,0 +1,3 @@
+module cryptomsgparser
+
+go 1.18
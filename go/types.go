@@ -0,0 +1,250 @@
+package cryptomsgparser
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+// MarketType has the same values as MarketType in crypto_market_type.h.
+type MarketType int
+
+const (
+	Unknown MarketType = iota
+	Spot
+	LinearFuture
+	InverseFuture
+	LinearSwap
+	InverseSwap
+	AmericanOption
+	EuropeanOption
+	QuantoFuture
+	QuantoSwap
+	Move
+	BVOL
+)
+
+var marketTypeNames = []string{
+	"unknown",
+	"spot",
+	"linear_future",
+	"inverse_future",
+	"linear_swap",
+	"inverse_swap",
+	"american_option",
+	"european_option",
+	"quanto_future",
+	"quanto_swap",
+	"move",
+	"bvol",
+}
+
+func (t MarketType) String() string {
+	if t < 0 || int(t) >= len(marketTypeNames) {
+		return fmt.Sprintf("MarketType(%d)", int(t))
+	}
+	return marketTypeNames[t]
+}
+
+// UnmarshalText parses a snake_case name such as inverse_swap.
+func (t *MarketType) UnmarshalText(text []byte) error {
+	for i, name := range marketTypeNames {
+		if name == string(text) {
+			*t = MarketType(i)
+			return nil
+		}
+	}
+	return fmt.Errorf("cryptomsgparser: unknown market type %q", text)
+}
+
+// MessageType has the same values as MessageType in crypto_msg_type.h.
+type MessageType int
+
+const (
+	Other MessageType = iota
+	Trade
+	L2Event
+	L2Snapshot
+	L2TopK
+	L3Event
+	L3Snapshot
+	BBO
+	Ticker
+	Candlestick
+	FundingRate
+	OpenInterest
+	LongShortRatio
+	TakerVolume
+)
+
+var messageTypeNames = []string{
+	"other",
+	"trade",
+	"l2_event",
+	"l2_snapshot",
+	"l2_topk",
+	"l3_event",
+	"l3_snapshot",
+	"bbo",
+	"ticker",
+	"candlestick",
+	"funding_rate",
+	"open_interest",
+	"long_short_ratio",
+	"taker_volume",
+}
+
+func (t MessageType) String() string {
+	if t < 0 || int(t) >= len(messageTypeNames) {
+		return fmt.Sprintf("MessageType(%d)", int(t))
+	}
+	return messageTypeNames[t]
+}
+
+// UnmarshalText parses a snake_case name such as l2_event.
+func (t *MessageType) UnmarshalText(text []byte) error {
+	for i, name := range messageTypeNames {
+		if name == string(text) {
+			*t = MessageType(i)
+			return nil
+		}
+	}
+	return fmt.Errorf("cryptomsgparser: unknown message type %q", text)
+}
+
+// TradeSide is which side is taker, buy or sell.
+type TradeSide string
+
+const (
+	Buy  TradeSide = "buy"
+	Sell TradeSide = "sell"
+)
+
+// TradeMsg is a tick-by-tick trade.
+type TradeMsg struct {
+	Exchange   string      `json:"exchange"`
+	MarketType MarketType  `json:"market_type"`
+	MsgType    MessageType `json:"msg_type"`
+	Pair       string      `json:"pair"`
+	Symbol     string      `json:"symbol"`
+	// Unix timestamp, in milliseconds
+	Timestamp     int64     `json:"timestamp"`
+	Side          TradeSide `json:"side"`
+	Price         float64   `json:"price"`
+	QuantityBase  float64   `json:"quantity_base"`
+	QuantityQuote float64   `json:"quantity_quote"`
+	// Number of contracts, nil in spot market
+	QuantityContract *float64 `json:"quantity_contract"`
+	TradeID          string   `json:"trade_id"`
+	// The original JSON message
+	JSON string `json:"json"`
+}
+
+// Order is a price level of an orderbook, serialized as
+// [price, quantity_base, quantity_quote, quantity_contract?].
+type Order struct {
+	Price         float64
+	QuantityBase  float64
+	QuantityQuote float64
+	// Number of contracts, nil in spot market
+	QuantityContract *float64
+}
+
+// UnmarshalJSON decodes an array of 3 or 4 numbers.
+func (o *Order) UnmarshalJSON(data []byte) error {
+	var values []float64
+	if err := json.Unmarshal(data, &values); err != nil {
+		return err
+	}
+	if len(values) < 3 || len(values) > 4 {
+		return fmt.Errorf("cryptomsgparser: invalid order %s", data)
+	}
+	o.Price, o.QuantityBase, o.QuantityQuote = values[0], values[1], values[2]
+	o.QuantityContract = nil
+	if len(values) == 4 {
+		o.QuantityContract = &values[3]
+	}
+	return nil
+}
+
+// OrderBookMsg is a level2 orderbook snapshot or incremental update.
+type OrderBookMsg struct {
+	Exchange   string      `json:"exchange"`
+	MarketType MarketType  `json:"market_type"`
+	Symbol     string      `json:"symbol"`
+	Pair       string      `json:"pair"`
+	MsgType    MessageType `json:"msg_type"`
+	// Unix timestamp, in milliseconds
+	Timestamp int64 `json:"timestamp"`
+	Snapshot  bool  `json:"snapshot"`
+	// Sorted in ascending order by price
+	Asks []Order `json:"asks"`
+	// Sorted in descending order by price
+	Bids      []Order `json:"bids"`
+	SeqID     *uint64 `json:"seq_id"`
+	PrevSeqID *uint64 `json:"prev_seq_id"`
+	// The original JSON message
+	JSON string `json:"json"`
+}
+
+// BboMsg is the best bid and offer.
+type BboMsg struct {
+	Exchange   string      `json:"exchange"`
+	MarketType MarketType  `json:"market_type"`
+	Symbol     string      `json:"symbol"`
+	Pair       string      `json:"pair"`
+	MsgType    MessageType `json:"msg_type"`
+	// Unix timestamp, in milliseconds
+	Timestamp           int64    `json:"timestamp"`
+	BidPrice            float64  `json:"bid_price"`
+	BidQuantityBase     float64  `json:"bid_quantity_base"`
+	BidQuantityQuote    float64  `json:"bid_quantity_quote"`
+	BidQuantityContract *float64 `json:"bid_quantity_contract"`
+	AskPrice            float64  `json:"ask_price"`
+	AskQuantityBase     float64  `json:"ask_quantity_base"`
+	AskQuantityQuote    float64  `json:"ask_quantity_quote"`
+	AskQuantityContract *float64 `json:"ask_quantity_contract"`
+	ID                  *uint64  `json:"id"`
+	// The original JSON message
+	JSON string `json:"json"`
+}
+
+// FundingRateMsg is the funding rate of a perpetual swap.
+type FundingRateMsg struct {
+	Exchange   string      `json:"exchange"`
+	MarketType MarketType  `json:"market_type"`
+	Symbol     string      `json:"symbol"`
+	Pair       string      `json:"pair"`
+	MsgType    MessageType `json:"msg_type"`
+	// Unix timestamp, in milliseconds
+	Timestamp   int64   `json:"timestamp"`
+	FundingRate float64 `json:"funding_rate"`
+	// Unix timestamp of the funding, in milliseconds
+	FundingTime   int64    `json:"funding_time"`
+	EstimatedRate *float64 `json:"estimated_rate"`
+	// The original JSON message
+	JSON string `json:"json"`
+}
+
+// CandlestickMsg is an OHLCV candlestick.
+type CandlestickMsg struct {
+	Exchange   string      `json:"exchange"`
+	MarketType MarketType  `json:"market_type"`
+	Symbol     string      `json:"symbol"`
+	Pair       string      `json:"pair"`
+	MsgType    MessageType `json:"msg_type"`
+	// Unix timestamp, in milliseconds
+	Timestamp int64 `json:"timestamp"`
+	// Begin time of the candle cycle, in seconds
+	BeginTime int64   `json:"begin_time"`
+	Open      float64 `json:"open"`
+	High      float64 `json:"high"`
+	Low       float64 `json:"low"`
+	Close     float64 `json:"close"`
+	// Base volume
+	Volume float64 `json:"volume"`
+	// m, minute; H, hour; D, day; W, week; M, month; Y, year
+	Period      string   `json:"period"`
+	QuoteVolume *float64 `json:"quote_volume"`
+	// The original JSON message
+	JSON string `json:"json"`
+}
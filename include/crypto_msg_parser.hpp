@@ -0,0 +1,607 @@
+/* Licensed under Apache-2.0 */
+
+#ifndef CRYPTO_MSG_PARSER_HPP_
+#define CRYPTO_MSG_PARSER_HPP_
+
+/*
+ * Header-only C++17 wrapper of crypto_msg_parser_ffi.h.
+ *
+ * Strings and byte buffers returned by the library are owned by RAII types
+ * and freed automatically, failures are returned as a `Result` holding an
+ * `Error` instead of NULL pointers and -1.
+ *
+ * Inputs are `std::string_view`s, which are copied to add the NUL terminator
+ * required by the C API.
+ */
+
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <optional>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <variant>
+
+#include "crypto_msg_parser_ffi.h"
+
+namespace crypto_msg_parser {
+
+/**
+ * Frees a string returned by the library.
+ */
+struct StringDeleter {
+  void operator()(const char *pointer) const noexcept {
+    ::deallocate_string(pointer);
+  }
+};
+
+/**
+ * Owner of a string returned by the library.
+ */
+using UniqueString = std::unique_ptr<const char, StringDeleter>;
+
+/**
+ * Owner of a byte buffer returned by the library.
+ */
+class Bytes {
+ public:
+  explicit Bytes(ByteBuffer buffer) noexcept : buffer_(buffer) {}
+  Bytes(Bytes &&other) noexcept : buffer_(other.release()) {}
+  Bytes &operator=(Bytes &&other) noexcept {
+    if (this != &other) {
+      reset();
+      buffer_ = other.release();
+    }
+    return *this;
+  }
+  Bytes(const Bytes &) = delete;
+  Bytes &operator=(const Bytes &) = delete;
+  ~Bytes() { reset(); }
+
+  const uint8_t *data() const noexcept { return buffer_.data; }
+  size_t size() const noexcept { return buffer_.len; }
+  bool empty() const noexcept { return buffer_.len == 0; }
+
+  /**
+   * View the bytes as text, e.g., the JSON output.
+   */
+  std::string_view view() const noexcept {
+    return {reinterpret_cast<const char *>(buffer_.data), buffer_.len};
+  }
+
+ private:
+  ByteBuffer release() noexcept {
+    return std::exchange(buffer_, ByteBuffer{nullptr, 0});
+  }
+  void reset() noexcept {
+    if (buffer_.data != nullptr) {
+      ::deallocate_bytes(release());
+    }
+  }
+
+  ByteBuffer buffer_;
+};
+
+/**
+ * Error of a failed call.
+ */
+struct Error {
+  std::string message;
+};
+
+/**
+ * Either a value or an `Error`, similar to C++23 `std::expected`.
+ */
+template <typename T>
+class Result {
+ public:
+  Result(T value) : inner_(std::in_place_index<0>, std::move(value)) {}
+  Result(Error error) : inner_(std::in_place_index<1>, std::move(error)) {}
+
+  bool has_value() const noexcept { return inner_.index() == 0; }
+  explicit operator bool() const noexcept { return has_value(); }
+
+  /**
+   * Throws `std::bad_variant_access` if the call failed.
+   */
+  T &value() & { return std::get<0>(inner_); }
+  const T &value() const & { return std::get<0>(inner_); }
+  T &&value() && { return std::get<0>(std::move(inner_)); }
+
+  T &operator*() & { return value(); }
+  const T &operator*() const & { return value(); }
+  T &&operator*() && { return std::move(*this).value(); }
+  T *operator->() { return &value(); }
+  const T *operator->() const { return &value(); }
+
+  /**
+   * Throws `std::bad_variant_access` if the call succeeded.
+   */
+  const Error &error() const { return std::get<1>(inner_); }
+
+ private:
+  std::variant<T, Error> inner_;
+};
+
+/**
+ * Options of the parsers returning `Bytes`, see `ParseOptions`.
+ */
+struct Options {
+  OutputFormat format = OutputFormat_Json;
+  NumberFormat number_format = NumberFormat_Float;
+  /**
+   * Comma separated names of fields to keep, std::nullopt means all fields.
+   */
+  std::optional<std::string> fields;
+  bool omit_json = false;
+};
+
+namespace detail {
+
+inline Error last_error(const char *fallback) {
+  const char *message = ::last_error();
+  return Error{message != nullptr ? message : fallback};
+}
+
+// The string-returning functions don't record errors
+inline Result<std::string> take_string(const char *pointer,
+                                       const char *error) {
+  UniqueString owned(pointer);
+  if (!owned) {
+    return Error{error};
+  }
+  return std::string(owned.get());
+}
+
+// extract_symbol() returns a JSON string, symbols don't need unicode escapes
+inline Result<std::string> unquote(Result<std::string> json) {
+  if (!json || json->size() < 2 || json->front() != '"' ||
+      json->back() != '"') {
+    return json;
+  }
+  std::string text;
+  for (size_t i = 1; i + 1 < json->size(); ++i) {
+    if ((*json)[i] == '\\' && i + 2 < json->size()) {
+      ++i;
+    }
+    text.push_back((*json)[i]);
+  }
+  return text;
+}
+
+inline Result<Bytes> take_bytes(ByteBuffer buffer) {
+  if (buffer.data == nullptr) {
+    return last_error("Failed to parse the message");
+  }
+  return Bytes(buffer);
+}
+
+// Valid as long as `options` is alive
+inline ParseOptions to_c(const Options &options) {
+  return ParseOptions{options.format, options.number_format,
+                      options.fields ? options.fields->c_str() : nullptr,
+                      options.omit_json};
+}
+
+inline constexpr std::pair<MarketType, std::string_view> kMarketTypes[] = {
+    {MarketType::Unknown, "unknown"},
+    {MarketType::Spot, "spot"},
+    {MarketType::LinearFuture, "linear_future"},
+    {MarketType::InverseFuture, "inverse_future"},
+    {MarketType::LinearSwap, "linear_swap"},
+    {MarketType::InverseSwap, "inverse_swap"},
+    {MarketType::AmericanOption, "american_option"},
+    {MarketType::EuropeanOption, "european_option"},
+    {MarketType::QuantoFuture, "quanto_future"},
+    {MarketType::QuantoSwap, "quanto_swap"},
+    {MarketType::Move, "move"},
+    {MarketType::BVOL, "bvol"},
+};
+
+inline constexpr std::pair<MessageType, std::string_view> kMessageTypes[] = {
+    {MessageType::Other, "other"},
+    {MessageType::Trade, "trade"},
+    {MessageType::L2Event, "l2_event"},
+    {MessageType::L2Snapshot, "l2_snapshot"},
+    {MessageType::L2TopK, "l2_topk"},
+    {MessageType::L3Event, "l3_event"},
+    {MessageType::L3Snapshot, "l3_snapshot"},
+    {MessageType::BBO, "bbo"},
+    {MessageType::Ticker, "ticker"},
+    {MessageType::Candlestick, "candlestick"},
+    {MessageType::FundingRate, "funding_rate"},
+    {MessageType::OpenInterest, "open_interest"},
+    {MessageType::LongShortRatio, "long_short_ratio"},
+    {MessageType::TakerVolume, "taker_volume"},
+};
+
+}  // namespace detail
+
+/**
+ * The snake_case name of a market type, e.g., `inverse_swap`, empty if unknown.
+ */
+constexpr std::string_view to_string(MarketType market_type) noexcept {
+  for (const auto &[value, name] : detail::kMarketTypes) {
+    if (value == market_type) {
+      return name;
+    }
+  }
+  return {};
+}
+
+/**
+ * The snake_case name of a message type, e.g., `l2_event`, empty if unknown.
+ */
+constexpr std::string_view to_string(MessageType msg_type) noexcept {
+  for (const auto &[value, name] : detail::kMessageTypes) {
+    if (value == msg_type) {
+      return name;
+    }
+  }
+  return {};
+}
+
+/**
+ * Parse the snake_case name of a market type.
+ */
+constexpr std::optional<MarketType> market_type_from_string(
+    std::string_view name) noexcept {
+  for (const auto &[value, value_name] : detail::kMarketTypes) {
+    if (value_name == name) {
+      return value;
+    }
+  }
+  return std::nullopt;
+}
+
+/**
+ * Parse the snake_case name of a message type.
+ */
+constexpr std::optional<MessageType> msg_type_from_string(
+    std::string_view name) noexcept {
+  for (const auto &[value, value_name] : detail::kMessageTypes) {
+    if (value_name == name) {
+      return value;
+    }
+  }
+  return std::nullopt;
+}
+
+/**
+ * Whether the loaded library has the ABI version of the header this program
+ * is compiled against.
+ */
+inline bool abi_compatible() noexcept {
+  return ::abi_version() == CRYPTO_MSG_PARSER_FFI_ABI_VERSION;
+}
+
+/**
+ * Extract the symbol, `ALL` for multiple symbols, `NONE` for no symbol.
+ */
+inline Result<std::string> extract_symbol(std::string_view exchange,
+                                          MarketType market_type,
+                                          std::string_view msg) {
+  return detail::unquote(detail::take_string(
+      ::extract_symbol(std::string(exchange).c_str(), market_type,
+                       std::string(msg).c_str()),
+      "Failed to extract the symbol"));
+}
+
+/**
+ * Extract the timestamp in milliseconds, std::nullopt if the message has none.
+ */
+inline Result<std::optional<int64_t>> extract_timestamp(
+    std::string_view exchange, MarketType market_type, std::string_view msg) {
+  int64_t timestamp = ::extract_timestamp(std::string(exchange).c_str(),
+                                          market_type, std::string(msg).c_str());
+  if (timestamp < 0) {
+    return Error{"Failed to extract the timestamp"};
+  }
+  return timestamp == 0 ? std::nullopt : std::optional<int64_t>(timestamp);
+}
+
+/**
+ * Infer the message type from the message.
+ */
+inline MessageType get_msg_type(std::string_view exchange,
+                                std::string_view msg) {
+  return ::get_msg_type(std::string(exchange).c_str(),
+                        std::string(msg).c_str());
+}
+
+/**
+ * Get the CSV header row of a message type, std::nullopt if the message type
+ * has no CSV representation.
+ */
+inline std::optional<std::string> csv_header(MessageType msg_type) {
+  UniqueString header(::csv_header(msg_type));
+  if (!header) {
+    return std::nullopt;
+  }
+  return std::string(header.get());
+}
+
+/**
+ * Parse a raw trade message into a JSON array of `TradeMsg`.
+ */
+inline Result<std::string> parse_trade(std::string_view exchange,
+                                       MarketType market_type,
+                                       std::string_view msg) {
+  return detail::take_string(::parse_trade(std::string(exchange).c_str(),
+                                           market_type,
+                                           std::string(msg).c_str()),
+                             "Failed to parse the trade message");
+}
+
+/**
+ * Parse a raw level2 orderbook message into a JSON array of `OrderBookMsg`.
+ */
+inline Result<std::string> parse_l2(std::string_view exchange,
+                                    MarketType market_type,
+                                    std::string_view msg,
+                                    int64_t received_at = 0) {
+  return detail::take_string(
+      ::parse_l2(std::string(exchange).c_str(), market_type,
+                 std::string(msg).c_str(), received_at),
+      "Failed to parse the level2 orderbook message");
+}
+
+/**
+ * Parse a level2 topk orderbook message into a JSON array of `OrderBookMsg`.
+ */
+inline Result<std::string> parse_l2_topk(std::string_view exchange,
+                                         MarketType market_type,
+                                         std::string_view msg,
+                                         int64_t received_at = 0) {
+  return detail::take_string(
+      ::parse_l2_topk(std::string(exchange).c_str(), market_type,
+                      std::string(msg).c_str(), received_at),
+      "Failed to parse the level2 topk orderbook message");
+}
+
+/**
+ * Parse a BBO(best bid&offer) message into a JSON array of `BboMsg`.
+ */
+inline Result<std::string> parse_bbo(std::string_view exchange,
+                                     MarketType market_type,
+                                     std::string_view msg,
+                                     int64_t received_at = 0) {
+  return detail::take_string(
+      ::parse_bbo(std::string(exchange).c_str(), market_type,
+                  std::string(msg).c_str(), received_at),
+      "Failed to parse the BBO message");
+}
+
+/**
+ * Parse a raw funding rate message into a JSON array of `FundingRateMsg`.
+ */
+inline Result<std::string> parse_funding_rate(std::string_view exchange,
+                                              MarketType market_type,
+                                              std::string_view msg,
+                                              int64_t received_at = 0) {
+  return detail::take_string(
+      ::parse_funding_rate(std::string(exchange).c_str(), market_type,
+                           std::string(msg).c_str(), received_at),
+      "Failed to parse the funding rate message");
+}
+
+/**
+ * Parse a raw candlestick message into a JSON array of `CandlestickMsg`.
+ */
+inline Result<std::string> parse_candlestick(std::string_view exchange,
+                                             MarketType market_type,
+                                             std::string_view msg,
+                                             int64_t received_at = 0) {
+  return detail::take_string(
+      ::parse_candlestick(std::string(exchange).c_str(), market_type,
+                          std::string(msg).c_str(), received_at),
+      "Failed to parse the candlestick message");
+}
+
+/**
+ * Parse a raw trade message and serialize it with the given options.
+ */
+inline Result<Bytes> parse_trade(std::string_view exchange,
+                                 MarketType market_type, std::string_view msg,
+                                 const Options &options) {
+  return detail::take_bytes(::parse_trade_with_options(
+      std::string(exchange).c_str(), market_type, std::string(msg).c_str(),
+      detail::to_c(options)));
+}
+
+/**
+ * Parse a raw level2 orderbook message and serialize it with the given options.
+ */
+inline Result<Bytes> parse_l2(std::string_view exchange,
+                              MarketType market_type, std::string_view msg,
+                              int64_t received_at, const Options &options) {
+  return detail::take_bytes(::parse_l2_with_options(
+      std::string(exchange).c_str(), market_type, std::string(msg).c_str(),
+      received_at, detail::to_c(options)));
+}
+
+/**
+ * Parse a level2 topk orderbook message and serialize it with the given options.
+ */
+inline Result<Bytes> parse_l2_topk(std::string_view exchange,
+                                   MarketType market_type,
+                                   std::string_view msg, int64_t received_at,
+                                   const Options &options) {
+  return detail::take_bytes(::parse_l2_topk_with_options(
+      std::string(exchange).c_str(), market_type, std::string(msg).c_str(),
+      received_at, detail::to_c(options)));
+}
+
+/**
+ * Parse a BBO(best bid&offer) message and serialize it with the given options.
+ */
+inline Result<Bytes> parse_bbo(std::string_view exchange,
+                               MarketType market_type, std::string_view msg,
+                               int64_t received_at, const Options &options) {
+  return detail::take_bytes(::parse_bbo_with_options(
+      std::string(exchange).c_str(), market_type, std::string(msg).c_str(),
+      received_at, detail::to_c(options)));
+}
+
+/**
+ * Parse a raw funding rate message and serialize it with the given options.
+ */
+inline Result<Bytes> parse_funding_rate(std::string_view exchange,
+                                        MarketType market_type,
+                                        std::string_view msg,
+                                        int64_t received_at,
+                                        const Options &options) {
+  return detail::take_bytes(::parse_funding_rate_with_options(
+      std::string(exchange).c_str(), market_type, std::string(msg).c_str(),
+      received_at, detail::to_c(options)));
+}
+
+/**
+ * Parse a raw candlestick message and serialize it with the given options.
+ */
+inline Result<Bytes> parse_candlestick(std::string_view exchange,
+                                       MarketType market_type,
+                                       std::string_view msg,
+                                       int64_t received_at,
+                                       const Options &options) {
+  return detail::take_bytes(::parse_candlestick_with_options(
+      std::string(exchange).c_str(), market_type, std::string(msg).c_str(),
+      received_at, detail::to_c(options)));
+}
+
+/**
+ * Decompress a raw message and parse it with the parser matching `msg_type`,
+ * see `parse_compressed()` in the C API.
+ */
+inline Result<Bytes> parse_compressed(std::string_view exchange,
+                                      MarketType market_type,
+                                      MessageType msg_type,
+                                      std::string_view data,
+                                      Compression compression,
+                                      int64_t received_at = 0,
+                                      const Options &options = {}) {
+  return detail::take_bytes(::parse_compressed(
+      std::string(exchange).c_str(), market_type, msg_type,
+      reinterpret_cast<const uint8_t *>(data.data()), data.size(),
+      compression, received_at, detail::to_c(options)));
+}
+
+/**
+ * Parse a raw message in the given encoding, see `parse_payload_bytes()` in
+ * the C API.
+ */
+inline Result<Bytes> parse_payload_bytes(std::string_view exchange,
+                                         MarketType market_type,
+                                         MessageType msg_type,
+                                         std::string_view data,
+                                         PayloadEncoding encoding,
+                                         int64_t received_at = 0,
+                                         const Options &options = {}) {
+  return detail::take_bytes(::parse_payload_bytes(
+      std::string(exchange).c_str(), market_type, msg_type,
+      reinterpret_cast<const uint8_t *>(data.data()), data.size(), encoding,
+      received_at, detail::to_c(options)));
+}
+
+/**
+ * Reads a crypto-crawler archive line by line, closed on destruction.
+ */
+class RawFileReader {
+ public:
+  /**
+   * Open a plain text, gzip or xz archive.
+   */
+  static Result<RawFileReader> open(std::string_view path) {
+    ::RawFileReader *reader = ::raw_file_reader_open(std::string(path).c_str());
+    if (reader == nullptr) {
+      return detail::last_error("Failed to open the file");
+    }
+    return RawFileReader(reader);
+  }
+
+  /**
+   * Parse the next line into a JSON object with a `line_number` field, and
+   * either a `messages` array or an `error` string, std::nullopt at the end
+   * of the file.
+   */
+  std::optional<std::string> next() {
+    UniqueString line(::raw_file_reader_next(reader_.get()));
+    if (!line) {
+      return std::nullopt;
+    }
+    return std::string(line.get());
+  }
+
+ private:
+  struct Closer {
+    void operator()(::RawFileReader *reader) const noexcept {
+      ::raw_file_reader_close(reader);
+    }
+  };
+
+  explicit RawFileReader(::RawFileReader *reader) : reader_(reader) {}
+
+  std::unique_ptr<::RawFileReader, Closer> reader_;
+};
+
+#if defined(CRYPTO_MSG_PARSER_FFI_PARQUET)
+/**
+ * Writes normalized messages of one message type into a Parquet file.
+ *
+ * The file is incomplete until `close()` is called, the destructor closes it
+ * and ignores errors.
+ */
+class ParquetWriter {
+ public:
+  static Result<ParquetWriter> open(std::string_view path,
+                                    MessageType msg_type) {
+    ::ParquetWriter *writer =
+        ::parquet_writer_open(std::string(path).c_str(), msg_type);
+    if (writer == nullptr) {
+      return detail::last_error("Failed to create the file");
+    }
+    return ParquetWriter(writer);
+  }
+
+  /**
+   * Parse a raw message and write the normalized messages, returns the
+   * number of normalized messages.
+   */
+  Result<int64_t> write(std::string_view exchange, MarketType market_type,
+                        std::string_view msg, int64_t received_at = 0) {
+    int64_t written = ::parquet_writer_write(
+        writer_.get(), std::string(exchange).c_str(), market_type,
+        std::string(msg).c_str(), received_at);
+    if (written < 0) {
+      return detail::last_error("Failed to write the message");
+    }
+    return written;
+  }
+
+  /**
+   * Flush and close the file, returns the error if it can't be completed.
+   */
+  std::optional<Error> close() {
+    if (!::parquet_writer_close(writer_.release())) {
+      return detail::last_error("Failed to close the file");
+    }
+    return std::nullopt;
+  }
+
+ private:
+  struct Closer {
+    void operator()(::ParquetWriter *writer) const noexcept {
+      ::parquet_writer_close(writer);
+    }
+  };
+
+  explicit ParquetWriter(::ParquetWriter *writer) : writer_(writer) {}
+
+  std::unique_ptr<::ParquetWriter, Closer> writer_;
+};
+#endif
+
+}  // namespace crypto_msg_parser
+
+#endif /* CRYPTO_MSG_PARSER_HPP_ */
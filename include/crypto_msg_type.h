@@ -2,6 +2,8 @@
 #ifndef CRYPTO_MSG_TYPE_H_
 #define CRYPTO_MSG_TYPE_H_
 
+/* Generated from crypto-msg-type by `cargo xtask codegen`, don't modify it manually. */
+
 /**
  * Crypto message types.
  *
@@ -12,51 +14,59 @@ typedef enum {
   /**
    * All other messages
    */
-  Other,
+  Other = 0,
   /**
    * tick-by-tick trade messages
    */
-  Trade,
+  Trade = 1,
   /**
    * Incremental level2 orderbook updates
    */
-  L2Event,
+  L2Event = 2,
   /**
    * Level2 snapshot from RESTful API
    */
-  L2Snapshot,
+  L2Snapshot = 3,
   /**
    * Level2 top K snapshots from websocket
    */
-  L2TopK,
+  L2TopK = 4,
   /**
    * Incremental level3 orderbook updates
    */
-  L3Event,
+  L3Event = 5,
   /**
    * Level3 snapshot from RESTful API
    */
-  L3Snapshot,
+  L3Snapshot = 6,
   /**
    * Best bid and ask
    */
-  BBO,
+  BBO = 7,
   /**
    * 24hr rolling window ticker
    */
-  Ticker,
+  Ticker = 8,
   /**
    * OHLCV candlestick
    */
-  Candlestick,
+  Candlestick = 9,
   /**
    * Funding rate
    */
-  FundingRate,
+  FundingRate = 10,
   /**
    * Open interest
    */
-  OpenInterest,
+  OpenInterest = 11,
+  /**
+   * Long/short ratio
+   */
+  LongShortRatio = 12,
+  /**
+   * Taker buy/sell volume
+   */
+  TakerVolume = 13,
 } MessageType;
 
 #endif /* CRYPTO_MSG_TYPE_H_ */
@@ -0,0 +1,87 @@
+package cryptomsgparser;
+
+/** Best bid and offer. */
+public final class BboMsg extends Message {
+  private final double bidPrice;
+  private final double bidQuantityBase;
+  private final double bidQuantityQuote;
+  private final Double bidQuantityContract;
+  private final double askPrice;
+  private final double askQuantityBase;
+  private final double askQuantityQuote;
+  private final Double askQuantityContract;
+  private final Long id;
+
+  BboMsg(
+      String exchange,
+      String marketType,
+      String msgType,
+      String pair,
+      String symbol,
+      long timestamp,
+      String json,
+      double bidPrice,
+      double bidQuantityBase,
+      double bidQuantityQuote,
+      Double bidQuantityContract,
+      double askPrice,
+      double askQuantityBase,
+      double askQuantityQuote,
+      Double askQuantityContract,
+      Long id) {
+    super(exchange, marketType, msgType, pair, symbol, timestamp, json);
+    this.bidPrice = bidPrice;
+    this.bidQuantityBase = bidQuantityBase;
+    this.bidQuantityQuote = bidQuantityQuote;
+    this.bidQuantityContract = bidQuantityContract;
+    this.askPrice = askPrice;
+    this.askQuantityBase = askQuantityBase;
+    this.askQuantityQuote = askQuantityQuote;
+    this.askQuantityContract = askQuantityContract;
+    this.id = id;
+  }
+
+  public double getBidPrice() {
+    return bidPrice;
+  }
+
+  public double getBidQuantityBase() {
+    return bidQuantityBase;
+  }
+
+  public double getBidQuantityQuote() {
+    return bidQuantityQuote;
+  }
+
+  public Double getBidQuantityContract() {
+    return bidQuantityContract;
+  }
+
+  public double getAskPrice() {
+    return askPrice;
+  }
+
+  public double getAskQuantityBase() {
+    return askQuantityBase;
+  }
+
+  public double getAskQuantityQuote() {
+    return askQuantityQuote;
+  }
+
+  public Double getAskQuantityContract() {
+    return askQuantityContract;
+  }
+
+  /** Update id, null if the exchange doesn't provide one. */
+  public Long getId() {
+    return id;
+  }
+
+  @Override
+  public String toString() {
+    return "BboMsg{exchange=" + getExchange() + ", marketType=" + getMarketType()
+        + ", symbol=" + getSymbol() + ", timestamp=" + getTimestamp() + ", bidPrice=" + bidPrice
+        + ", askPrice=" + askPrice + "}";
+  }
+}
@@ -0,0 +1,84 @@
+package cryptomsgparser;
+
+/** OHLCV candlestick. */
+public final class CandlestickMsg extends Message {
+  private final long beginTime;
+  private final double open;
+  private final double high;
+  private final double low;
+  private final double close;
+  private final double volume;
+  private final String period;
+  private final Double quoteVolume;
+
+  CandlestickMsg(
+      String exchange,
+      String marketType,
+      String msgType,
+      String pair,
+      String symbol,
+      long timestamp,
+      String json,
+      long beginTime,
+      double open,
+      double high,
+      double low,
+      double close,
+      double volume,
+      String period,
+      Double quoteVolume) {
+    super(exchange, marketType, msgType, pair, symbol, timestamp, json);
+    this.beginTime = beginTime;
+    this.open = open;
+    this.high = high;
+    this.low = low;
+    this.close = close;
+    this.volume = volume;
+    this.period = period;
+    this.quoteVolume = quoteVolume;
+  }
+
+  /** Begin time of the candle cycle, in seconds. */
+  public long getBeginTime() {
+    return beginTime;
+  }
+
+  public double getOpen() {
+    return open;
+  }
+
+  public double getHigh() {
+    return high;
+  }
+
+  public double getLow() {
+    return low;
+  }
+
+  public double getClose() {
+    return close;
+  }
+
+  /** Base volume. */
+  public double getVolume() {
+    return volume;
+  }
+
+  /** m, minute; H, hour; D, day; W, week; M, month; Y, year. */
+  public String getPeriod() {
+    return period;
+  }
+
+  /** Quote volume, null if unavailable. */
+  public Double getQuoteVolume() {
+    return quoteVolume;
+  }
+
+  @Override
+  public String toString() {
+    return "CandlestickMsg{exchange=" + getExchange() + ", marketType=" + getMarketType()
+        + ", symbol=" + getSymbol() + ", beginTime=" + beginTime + ", period=" + period
+        + ", open=" + open + ", high=" + high + ", low=" + low + ", close=" + close
+        + ", volume=" + volume + "}";
+  }
+}
@@ -0,0 +1,134 @@
+package cryptomsgparser;
+
+import java.util.Arrays;
+import java.util.List;
+import java.util.OptionalLong;
+
+/**
+ * Parse raw messages of cryptocurrency exchanges into normalized messages.
+ *
+ * <p>The native library, built with {@code cargo build --release --features jni}, is loaded from
+ * the {@code CRYPTO_MSG_PARSER_FFI_LIB} environment variable if set, otherwise from {@code
+ * java.library.path}.
+ *
+ * <p>{@code receivedAt} is used by message types whose raw messages may lack a timestamp, a
+ * non-positive value means it is unknown.
+ */
+public final class CryptoMsgParser {
+  static {
+    String path = System.getenv("CRYPTO_MSG_PARSER_FFI_LIB");
+    if (path != null && !path.isEmpty()) {
+      System.load(path);
+    } else {
+      System.loadLibrary("crypto_msg_parser_ffi");
+    }
+  }
+
+  private CryptoMsgParser() {}
+
+  /** Extract the symbol, {@code ALL} for multiple symbols, {@code NONE} for no symbol. */
+  public static String extractSymbol(String exchange, MarketType marketType, String msg)
+      throws ParseException {
+    return nativeExtractSymbol(exchange, marketType.getName(), msg);
+  }
+
+  /** Extract the timestamp in milliseconds, empty if the message has none. */
+  public static OptionalLong extractTimestamp(String exchange, MarketType marketType, String msg)
+      throws ParseException {
+    long timestamp = nativeExtractTimestamp(exchange, marketType.getName(), msg);
+    return timestamp > 0 ? OptionalLong.of(timestamp) : OptionalLong.empty();
+  }
+
+  /** Infer the message type from the message. */
+  public static MessageType getMsgType(String exchange, String msg) throws ParseException {
+    return MessageType.fromName(nativeGetMsgType(exchange, msg));
+  }
+
+  /** Parse a raw trade message. */
+  public static List<TradeMsg> parseTrade(String exchange, MarketType marketType, String msg)
+      throws ParseException {
+    return Arrays.asList(nativeParseTrade(exchange, marketType.getName(), msg, 0));
+  }
+
+  /** Parse a raw level2 orderbook message. */
+  public static List<OrderBookMsg> parseL2(
+      String exchange, MarketType marketType, String msg, long receivedAt) throws ParseException {
+    return Arrays.asList(nativeParseL2(exchange, marketType.getName(), msg, receivedAt));
+  }
+
+  public static List<OrderBookMsg> parseL2(String exchange, MarketType marketType, String msg)
+      throws ParseException {
+    return parseL2(exchange, marketType, msg, 0);
+  }
+
+  /** Parse a level2 topk orderbook message. */
+  public static List<OrderBookMsg> parseL2Topk(
+      String exchange, MarketType marketType, String msg, long receivedAt) throws ParseException {
+    return Arrays.asList(nativeParseL2Topk(exchange, marketType.getName(), msg, receivedAt));
+  }
+
+  public static List<OrderBookMsg> parseL2Topk(String exchange, MarketType marketType, String msg)
+      throws ParseException {
+    return parseL2Topk(exchange, marketType, msg, 0);
+  }
+
+  /** Parse a BBO(best bid&amp;offer) message. */
+  public static List<BboMsg> parseBbo(
+      String exchange, MarketType marketType, String msg, long receivedAt) throws ParseException {
+    return Arrays.asList(nativeParseBbo(exchange, marketType.getName(), msg, receivedAt));
+  }
+
+  public static List<BboMsg> parseBbo(String exchange, MarketType marketType, String msg)
+      throws ParseException {
+    return parseBbo(exchange, marketType, msg, 0);
+  }
+
+  /** Parse a raw funding rate message. */
+  public static List<FundingRateMsg> parseFundingRate(
+      String exchange, MarketType marketType, String msg, long receivedAt) throws ParseException {
+    return Arrays.asList(nativeParseFundingRate(exchange, marketType.getName(), msg, receivedAt));
+  }
+
+  public static List<FundingRateMsg> parseFundingRate(
+      String exchange, MarketType marketType, String msg) throws ParseException {
+    return parseFundingRate(exchange, marketType, msg, 0);
+  }
+
+  /** Parse a raw candlestick message. */
+  public static List<CandlestickMsg> parseCandlestick(
+      String exchange, MarketType marketType, String msg, long receivedAt) throws ParseException {
+    return Arrays.asList(nativeParseCandlestick(exchange, marketType.getName(), msg, receivedAt));
+  }
+
+  public static List<CandlestickMsg> parseCandlestick(
+      String exchange, MarketType marketType, String msg) throws ParseException {
+    return parseCandlestick(exchange, marketType, msg, 0);
+  }
+
+  private static native String nativeExtractSymbol(String exchange, String marketType, String msg)
+      throws ParseException;
+
+  private static native long nativeExtractTimestamp(String exchange, String marketType, String msg)
+      throws ParseException;
+
+  private static native String nativeGetMsgType(String exchange, String msg)
+      throws ParseException;
+
+  private static native TradeMsg[] nativeParseTrade(
+      String exchange, String marketType, String msg, long receivedAt) throws ParseException;
+
+  private static native OrderBookMsg[] nativeParseL2(
+      String exchange, String marketType, String msg, long receivedAt) throws ParseException;
+
+  private static native OrderBookMsg[] nativeParseL2Topk(
+      String exchange, String marketType, String msg, long receivedAt) throws ParseException;
+
+  private static native BboMsg[] nativeParseBbo(
+      String exchange, String marketType, String msg, long receivedAt) throws ParseException;
+
+  private static native FundingRateMsg[] nativeParseFundingRate(
+      String exchange, String marketType, String msg, long receivedAt) throws ParseException;
+
+  private static native CandlestickMsg[] nativeParseCandlestick(
+      String exchange, String marketType, String msg, long receivedAt) throws ParseException;
+}
@@ -0,0 +1,46 @@
+package cryptomsgparser;
+
+/** Funding rate of a perpetual swap. */
+public final class FundingRateMsg extends Message {
+  private final double fundingRate;
+  private final long fundingTime;
+  private final Double estimatedRate;
+
+  FundingRateMsg(
+      String exchange,
+      String marketType,
+      String msgType,
+      String pair,
+      String symbol,
+      long timestamp,
+      String json,
+      double fundingRate,
+      long fundingTime,
+      Double estimatedRate) {
+    super(exchange, marketType, msgType, pair, symbol, timestamp, json);
+    this.fundingRate = fundingRate;
+    this.fundingTime = fundingTime;
+    this.estimatedRate = estimatedRate;
+  }
+
+  public double getFundingRate() {
+    return fundingRate;
+  }
+
+  /** Unix timestamp of the funding, in milliseconds. */
+  public long getFundingTime() {
+    return fundingTime;
+  }
+
+  /** Estimated rate of the next period, null if unavailable. */
+  public Double getEstimatedRate() {
+    return estimatedRate;
+  }
+
+  @Override
+  public String toString() {
+    return "FundingRateMsg{exchange=" + getExchange() + ", marketType=" + getMarketType()
+        + ", symbol=" + getSymbol() + ", timestamp=" + getTimestamp() + ", fundingRate="
+        + fundingRate + ", fundingTime=" + fundingTime + "}";
+  }
+}
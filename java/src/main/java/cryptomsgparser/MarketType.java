@@ -0,0 +1,41 @@
+package cryptomsgparser;
+
+/** Market types, named as in the JSON output, e.g., {@code inverse_swap}. */
+public enum MarketType {
+  UNKNOWN("unknown"),
+  SPOT("spot"),
+  LINEAR_FUTURE("linear_future"),
+  INVERSE_FUTURE("inverse_future"),
+  LINEAR_SWAP("linear_swap"),
+  INVERSE_SWAP("inverse_swap"),
+  AMERICAN_OPTION("american_option"),
+  EUROPEAN_OPTION("european_option"),
+  QUANTO_FUTURE("quanto_future"),
+  QUANTO_SWAP("quanto_swap"),
+  MOVE("move"),
+  BVOL("bvol");
+
+  private final String name;
+
+  MarketType(String name) {
+    this.name = name;
+  }
+
+  public String getName() {
+    return name;
+  }
+
+  public static MarketType fromName(String name) {
+    for (MarketType marketType : values()) {
+      if (marketType.name.equals(name)) {
+        return marketType;
+      }
+    }
+    throw new IllegalArgumentException("Unknown market type " + name);
+  }
+
+  @Override
+  public String toString() {
+    return name;
+  }
+}
@@ -0,0 +1,62 @@
+package cryptomsgparser;
+
+/** Fields shared by all normalized messages. */
+public abstract class Message {
+  private final String exchange;
+  private final MarketType marketType;
+  private final MessageType msgType;
+  private final String pair;
+  private final String symbol;
+  private final long timestamp;
+  private final String json;
+
+  Message(
+      String exchange,
+      String marketType,
+      String msgType,
+      String pair,
+      String symbol,
+      long timestamp,
+      String json) {
+    this.exchange = exchange;
+    this.marketType = MarketType.fromName(marketType);
+    this.msgType = MessageType.fromName(msgType);
+    this.pair = pair;
+    this.symbol = symbol;
+    this.timestamp = timestamp;
+    this.json = json;
+  }
+
+  /** The exchange name, unique for each exchange. */
+  public String getExchange() {
+    return exchange;
+  }
+
+  public MarketType getMarketType() {
+    return marketType;
+  }
+
+  public MessageType getMsgType() {
+    return msgType;
+  }
+
+  /** Unified pair, base/quote, e.g., BTC/USDT. */
+  public String getPair() {
+    return pair;
+  }
+
+  /** Exchange-specific trading symbol or id. */
+  public String getSymbol() {
+    return symbol;
+  }
+
+  /** Unix timestamp, in milliseconds. */
+  public long getTimestamp() {
+    return timestamp;
+  }
+
+  /** The original JSON message. */
+  public String getJson() {
+    return json;
+  }
+}
@@ -0,0 +1,43 @@
+package cryptomsgparser;
+
+/** Message types, named as in the JSON output, e.g., {@code l2_event}. */
+public enum MessageType {
+  OTHER("other"),
+  TRADE("trade"),
+  L2_EVENT("l2_event"),
+  L2_SNAPSHOT("l2_snapshot"),
+  L2_TOPK("l2_topk"),
+  L3_EVENT("l3_event"),
+  L3_SNAPSHOT("l3_snapshot"),
+  BBO("bbo"),
+  TICKER("ticker"),
+  CANDLESTICK("candlestick"),
+  FUNDING_RATE("funding_rate"),
+  OPEN_INTEREST("open_interest"),
+  LONG_SHORT_RATIO("long_short_ratio"),
+  TAKER_VOLUME("taker_volume");
+
+  private final String name;
+
+  MessageType(String name) {
+    this.name = name;
+  }
+
+  public String getName() {
+    return name;
+  }
+
+  public static MessageType fromName(String name) {
+    for (MessageType msgType : values()) {
+      if (msgType.name.equals(name)) {
+        return msgType;
+      }
+    }
+    throw new IllegalArgumentException("Unknown message type " + name);
+  }
+
+  @Override
+  public String toString() {
+    return name;
+  }
+}
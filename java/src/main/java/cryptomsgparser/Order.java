@@ -0,0 +1,38 @@
+package cryptomsgparser;
+
+/** A price level of an orderbook. */
+public final class Order {
+  private final double price;
+  private final double quantityBase;
+  private final double quantityQuote;
+  private final Double quantityContract;
+
+  Order(double price, double quantityBase, double quantityQuote, Double quantityContract) {
+    this.price = price;
+    this.quantityBase = quantityBase;
+    this.quantityQuote = quantityQuote;
+    this.quantityContract = quantityContract;
+  }
+
+  public double getPrice() {
+    return price;
+  }
+
+  public double getQuantityBase() {
+    return quantityBase;
+  }
+
+  public double getQuantityQuote() {
+    return quantityQuote;
+  }
+
+  /** Number of contracts, null in spot market. */
+  public Double getQuantityContract() {
+    return quantityContract;
+  }
+
+  @Override
+  public String toString() {
+    return "[" + price + ", " + quantityBase + ", " + quantityQuote + ", " + quantityContract + "]";
+  }
+}
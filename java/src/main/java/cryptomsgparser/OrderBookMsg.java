@@ -0,0 +1,65 @@
+package cryptomsgparser;
+
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+
+/** Level2 orderbook snapshot or incremental update. */
+public final class OrderBookMsg extends Message {
+  private final boolean snapshot;
+  private final List<Order> asks;
+  private final List<Order> bids;
+  private final Long seqId;
+  private final Long prevSeqId;
+
+  OrderBookMsg(
+      String exchange,
+      String marketType,
+      String msgType,
+      String pair,
+      String symbol,
+      long timestamp,
+      String json,
+      boolean snapshot,
+      Order[] asks,
+      Order[] bids,
+      Long seqId,
+      Long prevSeqId) {
+    super(exchange, marketType, msgType, pair, symbol, timestamp, json);
+    this.snapshot = snapshot;
+    this.asks = Collections.unmodifiableList(Arrays.asList(asks));
+    this.bids = Collections.unmodifiableList(Arrays.asList(bids));
+    this.seqId = seqId;
+    this.prevSeqId = prevSeqId;
+  }
+
+  public boolean isSnapshot() {
+    return snapshot;
+  }
+
+  /** Sorted in ascending order by price. */
+  public List<Order> getAsks() {
+    return asks;
+  }
+
+  /** Sorted in descending order by price. */
+  public List<Order> getBids() {
+    return bids;
+  }
+
+  /** Null if the exchange doesn't provide sequence numbers. */
+  public Long getSeqId() {
+    return seqId;
+  }
+
+  public Long getPrevSeqId() {
+    return prevSeqId;
+  }
+
+  @Override
+  public String toString() {
+    return "OrderBookMsg{exchange=" + getExchange() + ", marketType=" + getMarketType()
+        + ", symbol=" + getSymbol() + ", timestamp=" + getTimestamp() + ", snapshot=" + snapshot
+        + ", asks=" + asks + ", bids=" + bids + "}";
+  }
+}
@@ -0,0 +1,10 @@
+package cryptomsgparser;
+
+/** A raw message can't be parsed, the message is the upstream error. */
+public class ParseException extends Exception {
+  private static final long serialVersionUID = 1L;
+
+  public ParseException(String message) {
+    super(message);
+  }
+}
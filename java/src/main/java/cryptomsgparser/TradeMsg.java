@@ -0,0 +1,67 @@
+package cryptomsgparser;
+
+/** Tick-by-tick trade. */
+public final class TradeMsg extends Message {
+  private final TradeSide side;
+  private final double price;
+  private final double quantityBase;
+  private final double quantityQuote;
+  private final Double quantityContract;
+  private final String tradeId;
+
+  TradeMsg(
+      String exchange,
+      String marketType,
+      String msgType,
+      String pair,
+      String symbol,
+      long timestamp,
+      String json,
+      String side,
+      double price,
+      double quantityBase,
+      double quantityQuote,
+      Double quantityContract,
+      String tradeId) {
+    super(exchange, marketType, msgType, pair, symbol, timestamp, json);
+    this.side = TradeSide.fromName(side);
+    this.price = price;
+    this.quantityBase = quantityBase;
+    this.quantityQuote = quantityQuote;
+    this.quantityContract = quantityContract;
+    this.tradeId = tradeId;
+  }
+
+  /** Which side is taker. */
+  public TradeSide getSide() {
+    return side;
+  }
+
+  public double getPrice() {
+    return price;
+  }
+
+  public double getQuantityBase() {
+    return quantityBase;
+  }
+
+  public double getQuantityQuote() {
+    return quantityQuote;
+  }
+
+  /** Number of contracts, null in spot market. */
+  public Double getQuantityContract() {
+    return quantityContract;
+  }
+
+  public String getTradeId() {
+    return tradeId;
+  }
+
+  @Override
+  public String toString() {
+    return "TradeMsg{exchange=" + getExchange() + ", marketType=" + getMarketType()
+        + ", symbol=" + getSymbol() + ", timestamp=" + getTimestamp() + ", side=" + side
+        + ", price=" + price + ", quantityBase=" + quantityBase + ", tradeId=" + tradeId + "}";
+  }
+}
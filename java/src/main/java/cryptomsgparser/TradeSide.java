@@ -0,0 +1,11 @@
+package cryptomsgparser;
+
+/** Which side is taker. */
+public enum TradeSide {
+  BUY,
+  SELL;
+
+  static TradeSide fromName(String name) {
+    return valueOf(name.toUpperCase(java.util.Locale.ROOT));
+  }
+}
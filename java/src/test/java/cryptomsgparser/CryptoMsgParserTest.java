@@ -0,0 +1,99 @@
+package cryptomsgparser;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+class CryptoMsgParserTest {
+  private static final String TRADE_MSG =
+      "{\"stream\":\"btcusd_perp@aggTrade\",\"data\":{\"e\":\"aggTrade\",\"E\":1616201883458,\"a\":41045788,\"s\":\"BTCUSD_PERP\",\"p\":\"58570.1\",\"q\":\"58\",\"f\":91864326,\"l\":91864327,\"T\":1616201883304,\"m\":true}}";
+  private static final String L2_MSG =
+      "{\"stream\":\"btcusd_perp@depth@100ms\",\"data\":{\"e\":\"depthUpdate\",\"E\":1622370862564,\"T\":1622370862553,\"s\":\"BTCUSD_PERP\",\"ps\":\"BTCUSD\",\"U\":127559587191,\"u\":127559588177,\"pu\":127559587113,\"b\":[[\"35365.9\",\"1400\"],[\"35425.8\",\"561\"]],\"a\":[[\"35817.8\",\"7885\"],[\"35818.7\",\"307\"]]}}";
+  private static final String BBO_MSG =
+      "{\"stream\":\"ethusdt@bookTicker\",\"data\":{\"e\":\"bookTicker\",\"u\":1553413152520,\"s\":\"ETHUSDT\",\"b\":\"1778.54\",\"B\":\"15.164\",\"a\":\"1778.55\",\"A\":\"7.289\",\"T\":1653817855284,\"E\":1653817855289}}";
+  private static final String FUNDING_RATE_MSG =
+      "{\"stream\":\"btcusd_perp@markPrice\",\"data\":{\"e\":\"markPriceUpdate\",\"E\":1617309477000,\"s\":\"BTCUSD_PERP\",\"p\":\"59012.56007222\",\"P\":\"58896.00503145\",\"r\":\"0.00073689\",\"T\":1617321600000}}";
+  private static final String CANDLESTICK_MSG =
+      "{\"stream\":\"btcusdt@kline_1M\",\"data\":{\"e\":\"kline\",\"E\":1653819041520,\"s\":\"BTCUSDT\",\"k\":{\"t\":1651363200000,\"T\":1654041599999,\"s\":\"BTCUSDT\",\"i\":\"1M\",\"f\":2172726276,\"L\":2301806561,\"o\":\"37614.40\",\"c\":\"29075.50\",\"h\":\"40071.70\",\"l\":\"26631.00\",\"v\":\"13431981.671\",\"n\":129025447,\"x\":false,\"q\":\"423075730671.12853\",\"V\":\"6700065.176\",\"Q\":\"211000435586.65000\",\"B\":\"0\"}}}";
+
+  @Test
+  void parseTrade() throws ParseException {
+    List<TradeMsg> trades = CryptoMsgParser.parseTrade("binance", MarketType.INVERSE_SWAP, TRADE_MSG);
+    assertEquals(1, trades.size());
+    TradeMsg trade = trades.get(0);
+    assertEquals("binance", trade.getExchange());
+    assertEquals(MarketType.INVERSE_SWAP, trade.getMarketType());
+    assertEquals(MessageType.TRADE, trade.getMsgType());
+    assertEquals("BTC/USD", trade.getPair());
+    assertEquals(1616201883458L, trade.getTimestamp());
+    assertEquals(TradeSide.SELL, trade.getSide());
+    assertEquals(58570.1, trade.getPrice());
+    assertEquals(5800.0, trade.getQuantityQuote());
+    assertEquals(58.0, trade.getQuantityContract());
+    assertEquals(TRADE_MSG, trade.getJson());
+    assertEquals(MessageType.TRADE, CryptoMsgParser.getMsgType("binance", TRADE_MSG));
+  }
+
+  @Test
+  void parseL2() throws ParseException {
+    OrderBookMsg orderbook = CryptoMsgParser.parseL2("binance", MarketType.INVERSE_SWAP, L2_MSG).get(0);
+    assertEquals(MessageType.L2_EVENT, orderbook.getMsgType());
+    assertFalse(orderbook.isSnapshot());
+    assertEquals(2, orderbook.getAsks().size());
+    assertEquals(35365.9, orderbook.getBids().get(0).getPrice());
+    assertEquals(1400.0, orderbook.getBids().get(0).getQuantityContract());
+    assertEquals(127559588177L, orderbook.getSeqId());
+    assertEquals(127559587113L, orderbook.getPrevSeqId());
+  }
+
+  @Test
+  void parseBbo() throws ParseException {
+    BboMsg bbo = CryptoMsgParser.parseBbo("binance", MarketType.LINEAR_SWAP, BBO_MSG).get(0);
+    assertEquals("ETHUSDT", bbo.getSymbol());
+    assertEquals(1553413152520L, bbo.getId());
+    assertEquals(1778.55, bbo.getAskPrice());
+    assertEquals(15.164, bbo.getBidQuantityBase());
+  }
+
+  @Test
+  void parseFundingRate() throws ParseException {
+    FundingRateMsg rate =
+        CryptoMsgParser.parseFundingRate("binance", MarketType.INVERSE_SWAP, FUNDING_RATE_MSG).get(0);
+    assertEquals(0.00073689, rate.getFundingRate());
+    assertEquals(1617321600000L, rate.getFundingTime());
+    assertNull(rate.getEstimatedRate());
+  }
+
+  @Test
+  void parseCandlestick() throws ParseException {
+    CandlestickMsg candlestick =
+        CryptoMsgParser.parseCandlestick("binance", MarketType.LINEAR_SWAP, CANDLESTICK_MSG).get(0);
+    assertEquals("1M", candlestick.getPeriod());
+    assertEquals(1651363200L, candlestick.getBeginTime());
+    assertEquals(37614.4, candlestick.getOpen());
+    assertEquals(423075730671.12853, candlestick.getQuoteVolume());
+  }
+
+  @Test
+  void extract() throws ParseException {
+    assertEquals(
+        "BTCUSD_PERP", CryptoMsgParser.extractSymbol("binance", MarketType.INVERSE_SWAP, L2_MSG));
+    assertEquals(
+        1622370862564L,
+        CryptoMsgParser.extractTimestamp("binance", MarketType.INVERSE_SWAP, L2_MSG).getAsLong());
+  }
+
+  @Test
+  void parseError() {
+    ParseException err =
+        assertThrows(
+            ParseException.class,
+            () -> CryptoMsgParser.parseTrade("binance", MarketType.INVERSE_SWAP, "not json"));
+    assertTrue(!err.getMessage().isEmpty());
+  }
+}
@@ -0,0 +1,127 @@
+/* tslint:disable */
+/* eslint-disable */
+
+/* auto-generated by NAPI-RS */
+
+export interface TradeMsg {
+  exchange: string
+  marketType: string
+  msgType: string
+  pair: string
+  symbol: string
+  /** Unix timestamp, in milliseconds */
+  timestamp: number
+  /** `buy` or `sell`, which side is taker */
+  side: string
+  price: number
+  quantityBase: number
+  quantityQuote: number
+  /** Number of contracts, absent in spot market */
+  quantityContract?: number
+  tradeId: string
+  /** The original JSON message */
+  json: string
+}
+export interface Order {
+  price: number
+  quantityBase: number
+  quantityQuote: number
+  quantityContract?: number
+}
+export interface OrderBookMsg {
+  exchange: string
+  marketType: string
+  symbol: string
+  pair: string
+  msgType: string
+  /** Unix timestamp, in milliseconds */
+  timestamp: number
+  snapshot: boolean
+  /** Sorted in ascending order by price */
+  asks: Array<Order>
+  /** Sorted in descending order by price */
+  bids: Array<Order>
+  seqId?: bigint
+  prevSeqId?: bigint
+  /** The original JSON message */
+  json: string
+}
+export interface BboMsg {
+  exchange: string
+  marketType: string
+  symbol: string
+  pair: string
+  msgType: string
+  /** Unix timestamp, in milliseconds */
+  timestamp: number
+  bidPrice: number
+  bidQuantityBase: number
+  bidQuantityQuote: number
+  bidQuantityContract?: number
+  askPrice: number
+  askQuantityBase: number
+  askQuantityQuote: number
+  askQuantityContract?: number
+  id?: bigint
+  /** The original JSON message */
+  json: string
+}
+export interface FundingRateMsg {
+  exchange: string
+  marketType: string
+  symbol: string
+  pair: string
+  msgType: string
+  /** Unix timestamp, in milliseconds */
+  timestamp: number
+  fundingRate: number
+  /** Unix timestamp of the funding, in milliseconds */
+  fundingTime: number
+  estimatedRate?: number
+  /** The original JSON message */
+  json: string
+}
+export interface CandlestickMsg {
+  exchange: string
+  marketType: string
+  symbol: string
+  pair: string
+  msgType: string
+  /** Unix timestamp, in milliseconds */
+  timestamp: number
+  /** Begin time of the candle cycle, in seconds */
+  beginTime: number
+  open: number
+  high: number
+  low: number
+  close: number
+  /** Base volume */
+  volume: number
+  /** m, minute; H, hour; D, day; W, week; M, month; Y, year */
+  period: string
+  /** Quote volume */
+  quoteVolume?: number
+  /** The original JSON message */
+  json: string
+}
+/**
+ * Extract the symbol from the message, `ALL` for multiple symbols and `NONE`
+ * for no symbol.
+ */
+export function extractSymbol(exchange: string, marketType: string, msg: string): string
+/** Extract the timestamp in milliseconds, `null` if the message has none. */
+export function extractTimestamp(exchange: string, marketType: string, msg: string): number | null
+/** Infer the message type from the message. */
+export function getMsgType(exchange: string, msg: string): string
+/** Parse a raw trade message. */
+export function parseTrade(exchange: string, marketType: string, msg: string): Array<TradeMsg>
+/** Parse a raw level2 orderbook message. */
+export function parseL2(exchange: string, marketType: string, msg: string, receivedAt?: number | undefined | null): Array<OrderBookMsg>
+/** Parse a level2 topk orderbook message. */
+export function parseL2Topk(exchange: string, marketType: string, msg: string, receivedAt?: number | undefined | null): Array<OrderBookMsg>
+/** Parse a BBO(best bid&offer) message. */
+export function parseBbo(exchange: string, marketType: string, msg: string, receivedAt?: number | undefined | null): Array<BboMsg>
+/** Parse a raw funding rate message. */
+export function parseFundingRate(exchange: string, marketType: string, msg: string, receivedAt?: number | undefined | null): Array<FundingRateMsg>
+/** Parse a raw candlestick message. */
+export function parseCandlestick(exchange: string, marketType: string, msg: string, receivedAt?: number | undefined | null): Array<CandlestickMsg>
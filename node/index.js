@@ -0,0 +1,2 @@
+// The addon is built by `npm run build`, which also regenerates index.d.ts.
+module.exports = require('./crypto-msg-parser.node')
@@ -0,0 +1,82 @@
+const assert = require('node:assert')
+const { test } = require('node:test')
+
+const {
+  extractSymbol,
+  extractTimestamp,
+  getMsgType,
+  parseBbo,
+  parseCandlestick,
+  parseFundingRate,
+  parseL2,
+  parseTrade,
+} = require('..')
+
+const TRADE_MSG =
+  '{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}'
+const L2_MSG =
+  '{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}'
+const BBO_MSG =
+  '{"stream":"ethusdt@bookTicker","data":{"e":"bookTicker","u":1553413152520,"s":"ETHUSDT","b":"1778.54","B":"15.164","a":"1778.55","A":"7.289","T":1653817855284,"E":1653817855289}}'
+const FUNDING_RATE_MSG =
+  '{"stream":"btcusd_perp@markPrice","data":{"e":"markPriceUpdate","E":1617309477000,"s":"BTCUSD_PERP","p":"59012.56007222","P":"58896.00503145","r":"0.00073689","T":1617321600000}}'
+const CANDLESTICK_MSG =
+  '{"stream":"btcusdt@kline_1M","data":{"e":"kline","E":1653819041520,"s":"BTCUSDT","k":{"t":1651363200000,"T":1654041599999,"s":"BTCUSDT","i":"1M","f":2172726276,"L":2301806561,"o":"37614.40","c":"29075.50","h":"40071.70","l":"26631.00","v":"13431981.671","n":129025447,"x":false,"q":"423075730671.12853","V":"6700065.176","Q":"211000435586.65000","B":"0"}}}'
+
+test('parseTrade', () => {
+  const trades = parseTrade('binance', 'inverse_swap', TRADE_MSG)
+  assert.strictEqual(trades.length, 1)
+  const trade = trades[0]
+  assert.strictEqual(trade.exchange, 'binance')
+  assert.strictEqual(trade.marketType, 'inverse_swap')
+  assert.strictEqual(trade.msgType, 'trade')
+  assert.strictEqual(trade.pair, 'BTC/USD')
+  assert.strictEqual(trade.timestamp, 1616201883458)
+  assert.strictEqual(trade.price, 58570.1)
+  assert.strictEqual(trade.quantityQuote, 5800.0)
+  assert.strictEqual(trade.quantityContract, 58.0)
+  assert.strictEqual(trade.side, 'sell')
+  assert.strictEqual(getMsgType('binance', TRADE_MSG), 'trade')
+})
+
+test('parseL2', () => {
+  const orderbook = parseL2('binance', 'inverse_swap', L2_MSG)[0]
+  assert.strictEqual(orderbook.msgType, 'l2_event')
+  assert.strictEqual(orderbook.snapshot, false)
+  assert.strictEqual(orderbook.asks.length, 2)
+  assert.strictEqual(orderbook.bids[0].price, 35365.9)
+  assert.strictEqual(orderbook.bids[0].quantityContract, 1400)
+  assert.strictEqual(orderbook.seqId, 127559588177n)
+  assert.strictEqual(orderbook.prevSeqId, 127559587113n)
+})
+
+test('parseBbo', () => {
+  const bbo = parseBbo('binance', 'linear_swap', BBO_MSG)[0]
+  assert.strictEqual(bbo.symbol, 'ETHUSDT')
+  assert.strictEqual(bbo.id, 1553413152520n)
+  assert.strictEqual(bbo.askPrice, 1778.55)
+  assert.strictEqual(bbo.bidQuantityBase, 15.164)
+})
+
+test('parseFundingRate', () => {
+  const rate = parseFundingRate('binance', 'inverse_swap', FUNDING_RATE_MSG)[0]
+  assert.strictEqual(rate.fundingRate, 0.00073689)
+  assert.strictEqual(rate.fundingTime, 1617321600000)
+})
+
+test('parseCandlestick', () => {
+  const candlestick = parseCandlestick('binance', 'linear_swap', CANDLESTICK_MSG)[0]
+  assert.strictEqual(candlestick.period, '1M')
+  assert.strictEqual(candlestick.beginTime, 1651363200)
+  assert.strictEqual(candlestick.open, 37614.4)
+})
+
+test('extract', () => {
+  assert.strictEqual(extractSymbol('binance', 'inverse_swap', L2_MSG), 'BTCUSD_PERP')
+  assert.strictEqual(extractTimestamp('binance', 'inverse_swap', L2_MSG), 1622370862564)
+})
+
+test('errors are thrown', () => {
+  assert.throws(() => parseTrade('binance', 'inverse_swap', 'not json'))
+  assert.throws(() => parseTrade('binance', 'spot_swap', TRADE_MSG), /Unknown market type/)
+})
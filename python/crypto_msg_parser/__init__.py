@@ -0,0 +1,306 @@
+"""Python bindings of crypto-msg-parser-ffi.
+
+Normalized messages are returned as lists of dicts with the same fields as the
+JSON output of the C API, memory allocated by the shared library is freed
+internally.
+
+The shared library is loaded from the ``CRYPTO_MSG_PARSER_FFI_LIB``
+environment variable if set, otherwise from this package directory, then from
+the system library path.
+"""
+
+import ctypes
+import ctypes.util
+import enum
+import json
+import os
+import sys
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Union
+
+__all__ = [
+    "MarketType",
+    "MessageType",
+    "NumberFormat",
+    "ParseError",
+    "extract_symbol",
+    "extract_timestamp",
+    "get_msg_type",
+    "parse_bbo",
+    "parse_candlestick",
+    "parse_funding_rate",
+    "parse_l2",
+    "parse_l2_topk",
+    "parse_trade",
+]
+
+
+class MarketType(enum.IntEnum):
+    """Same values as ``crypto_market_type.h``."""
+
+    UNKNOWN = 0
+    SPOT = 1
+    LINEAR_FUTURE = 2
+    INVERSE_FUTURE = 3
+    LINEAR_SWAP = 4
+    INVERSE_SWAP = 5
+    AMERICAN_OPTION = 6
+    EUROPEAN_OPTION = 7
+    QUANTO_FUTURE = 8
+    QUANTO_SWAP = 9
+    MOVE = 10
+    BVOL = 11
+
+
+class MessageType(enum.IntEnum):
+    """Same values as ``crypto_msg_type.h``."""
+
+    OTHER = 0
+    TRADE = 1
+    L2_EVENT = 2
+    L2_SNAPSHOT = 3
+    L2_TOPK = 4
+    L3_EVENT = 5
+    L3_SNAPSHOT = 6
+    BBO = 7
+    TICKER = 8
+    CANDLESTICK = 9
+    FUNDING_RATE = 10
+    OPEN_INTEREST = 11
+    LONG_SHORT_RATIO = 12
+    TAKER_VOLUME = 13
+
+
+class NumberFormat(enum.IntEnum):
+    """How prices and quantities are represented, see ``ParseOptions``."""
+
+    FLOAT = 0
+    DECIMAL_STRING = 1
+    FIXED_POINT = 2
+
+
+class ParseError(Exception):
+    """A raw message can't be parsed, the message is the upstream error."""
+
+
+class _ByteBuffer(ctypes.Structure):
+    _fields_ = [("data", ctypes.POINTER(ctypes.c_uint8)), ("len", ctypes.c_size_t)]
+
+
+class _ParseOptions(ctypes.Structure):
+    _fields_ = [
+        ("format", ctypes.c_int),
+        ("number_format", ctypes.c_int),
+        ("fields", ctypes.c_char_p),
+        ("omit_json", ctypes.c_bool),
+    ]
+
+
+_OUTPUT_FORMAT_JSON = 0
+
+
+def _library_name() -> str:
+    if sys.platform == "darwin":
+        return "libcrypto_msg_parser_ffi.dylib"
+    if sys.platform == "win32":
+        return "crypto_msg_parser_ffi.dll"
+    return "libcrypto_msg_parser_ffi.so"
+
+
+def _load_library() -> ctypes.CDLL:
+    path = os.environ.get("CRYPTO_MSG_PARSER_FFI_LIB")
+    if path:
+        return ctypes.CDLL(path)
+    bundled = Path(__file__).parent / _library_name()
+    if bundled.exists():
+        return ctypes.CDLL(str(bundled))
+    found = ctypes.util.find_library("crypto_msg_parser_ffi")
+    if found:
+        return ctypes.CDLL(found)
+    raise OSError(
+        f"{_library_name()} not found, set CRYPTO_MSG_PARSER_FFI_LIB to its path"
+    )
+
+
+_lib = _load_library()
+
+_lib.last_error.argtypes = []
+_lib.last_error.restype = ctypes.c_char_p
+_lib.deallocate_bytes.argtypes = [_ByteBuffer]
+_lib.deallocate_bytes.restype = None
+# c_void_p keeps the pointer so that it can be freed
+_lib.deallocate_string.argtypes = [ctypes.c_void_p]
+_lib.deallocate_string.restype = None
+_lib.get_msg_type.argtypes = [ctypes.c_char_p, ctypes.c_char_p]
+_lib.get_msg_type.restype = ctypes.c_int
+_lib.extract_symbol.argtypes = [ctypes.c_char_p, ctypes.c_int, ctypes.c_char_p]
+_lib.extract_symbol.restype = ctypes.c_void_p
+_lib.extract_timestamp.argtypes = [ctypes.c_char_p, ctypes.c_int, ctypes.c_char_p]
+_lib.extract_timestamp.restype = ctypes.c_int64
+_lib.parse_trade_with_options.argtypes = [
+    ctypes.c_char_p,
+    ctypes.c_int,
+    ctypes.c_char_p,
+    _ParseOptions,
+]
+_lib.parse_trade_with_options.restype = _ByteBuffer
+for _name in [
+    "parse_l2_with_options",
+    "parse_l2_topk_with_options",
+    "parse_bbo_with_options",
+    "parse_funding_rate_with_options",
+    "parse_candlestick_with_options",
+]:
+    _func = getattr(_lib, _name)
+    _func.argtypes = [
+        ctypes.c_char_p,
+        ctypes.c_int,
+        ctypes.c_char_p,
+        ctypes.c_int64,
+        _ParseOptions,
+    ]
+    _func.restype = _ByteBuffer
+
+MarketTypeLike = Union[MarketType, str, int]
+
+
+def _market_type(market_type: MarketTypeLike) -> int:
+    """Accept enum members, names such as ``"inverse_swap"`` and integers."""
+    if isinstance(market_type, str):
+        return MarketType[market_type.upper()]
+    return MarketType(market_type)
+
+
+def _last_error(default: str) -> str:
+    err = _lib.last_error()
+    return err.decode("utf-8") if err else default
+
+
+def _call(
+    name: str,
+    exchange: str,
+    market_type: MarketTypeLike,
+    msg: str,
+    received_at: Optional[int],
+    number_format: NumberFormat,
+    fields: Optional[List[str]],
+    omit_json: bool,
+) -> List[Dict[str, Any]]:
+    options = _ParseOptions(
+        _OUTPUT_FORMAT_JSON,
+        NumberFormat(number_format),
+        ",".join(fields).encode("utf-8") if fields is not None else None,
+        omit_json,
+    )
+    args = [exchange.encode("utf-8"), _market_type(market_type), msg.encode("utf-8")]
+    if received_at is not None:
+        args.append(received_at)
+    buffer = getattr(_lib, name)(*args, options)
+    if not buffer.data:
+        raise ParseError(_last_error(f"Failed to parse {msg}"))
+    try:
+        return json.loads(ctypes.string_at(buffer.data, buffer.len))
+    finally:
+        _lib.deallocate_bytes(buffer)
+
+
+def parse_trade(
+    exchange: str,
+    market_type: MarketTypeLike,
+    msg: str,
+    *,
+    number_format: NumberFormat = NumberFormat.FLOAT,
+    fields: Optional[List[str]] = None,
+    omit_json: bool = False,
+) -> List[Dict[str, Any]]:
+    """Parse a raw trade message into a list of TradeMsg dicts."""
+    return _call(
+        "parse_trade_with_options",
+        exchange,
+        market_type,
+        msg,
+        None,
+        number_format,
+        fields,
+        omit_json,
+    )
+
+
+def _make_parser(name: str, doc: str):
+    def parse(
+        exchange: str,
+        market_type: MarketTypeLike,
+        msg: str,
+        received_at: int = 0,
+        *,
+        number_format: NumberFormat = NumberFormat.FLOAT,
+        fields: Optional[List[str]] = None,
+        omit_json: bool = False,
+    ) -> List[Dict[str, Any]]:
+        return _call(
+            f"{name}_with_options",
+            exchange,
+            market_type,
+            msg,
+            received_at,
+            number_format,
+            fields,
+            omit_json,
+        )
+
+    parse.__name__ = name
+    parse.__qualname__ = name
+    parse.__doc__ = doc
+    return parse
+
+
+parse_l2 = _make_parser(
+    "parse_l2",
+    "Parse a raw level2 orderbook message into a list of OrderBookMsg dicts.",
+)
+parse_l2_topk = _make_parser(
+    "parse_l2_topk",
+    "Parse a level2 topk orderbook message into a list of OrderBookMsg dicts.",
+)
+parse_bbo = _make_parser(
+    "parse_bbo",
+    "Parse a BBO(best bid&offer) message into a list of BboMsg dicts.",
+)
+parse_funding_rate = _make_parser(
+    "parse_funding_rate",
+    "Parse a raw funding rate message into a list of FundingRateMsg dicts.",
+)
+parse_candlestick = _make_parser(
+    "parse_candlestick",
+    "Parse a raw candlestick message into a list of CandlestickMsg dicts.",
+)
+
+
+def get_msg_type(exchange: str, msg: str) -> MessageType:
+    """Infer the message type from the message."""
+    return MessageType(_lib.get_msg_type(exchange.encode("utf-8"), msg.encode("utf-8")))
+
+
+def extract_symbol(exchange: str, market_type: MarketTypeLike, msg: str) -> str:
+    """Extract the symbol, ``ALL`` for multiple symbols, ``NONE`` for no symbol."""
+    ptr = _lib.extract_symbol(
+        exchange.encode("utf-8"), _market_type(market_type), msg.encode("utf-8")
+    )
+    if not ptr:
+        raise ParseError(f"Failed to extract the symbol from {msg}")
+    try:
+        return json.loads(ctypes.string_at(ptr))
+    finally:
+        _lib.deallocate_string(ptr)
+
+
+def extract_timestamp(
+    exchange: str, market_type: MarketTypeLike, msg: str
+) -> Optional[int]:
+    """Extract the timestamp in milliseconds, None if the message has none."""
+    timestamp = _lib.extract_timestamp(
+        exchange.encode("utf-8"), _market_type(market_type), msg.encode("utf-8")
+    )
+    if timestamp < 0:
+        raise ParseError(f"Failed to extract the timestamp from {msg}")
+    return timestamp or None
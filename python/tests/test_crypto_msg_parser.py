@@ -0,0 +1,99 @@
+import unittest
+
+from crypto_msg_parser import (
+    MarketType,
+    MessageType,
+    NumberFormat,
+    ParseError,
+    extract_symbol,
+    extract_timestamp,
+    get_msg_type,
+    parse_bbo,
+    parse_candlestick,
+    parse_funding_rate,
+    parse_l2,
+    parse_trade,
+)
+
+TRADE_MSG = '{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}'
+L2_MSG = '{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}'
+BBO_MSG = '{"stream":"ethusdt@bookTicker","data":{"e":"bookTicker","u":1553413152520,"s":"ETHUSDT","b":"1778.54","B":"15.164","a":"1778.55","A":"7.289","T":1653817855284,"E":1653817855289}}'
+FUNDING_RATE_MSG = '{"stream":"btcusd_perp@markPrice","data":{"e":"markPriceUpdate","E":1617309477000,"s":"BTCUSD_PERP","p":"59012.56007222","P":"58896.00503145","r":"0.00073689","T":1617321600000}}'
+CANDLESTICK_MSG = '{"stream":"btcusdt@kline_1M","data":{"e":"kline","E":1653819041520,"s":"BTCUSDT","k":{"t":1651363200000,"T":1654041599999,"s":"BTCUSDT","i":"1M","f":2172726276,"L":2301806561,"o":"37614.40","c":"29075.50","h":"40071.70","l":"26631.00","v":"13431981.671","n":129025447,"x":false,"q":"423075730671.12853","V":"6700065.176","Q":"211000435586.65000","B":"0"}}}'
+
+
+class ParseTest(unittest.TestCase):
+    def test_parse_trade(self):
+        trades = parse_trade("binance", MarketType.INVERSE_SWAP, TRADE_MSG)
+        self.assertEqual(1, len(trades))
+        trade = trades[0]
+        self.assertEqual("binance", trade["exchange"])
+        self.assertEqual("inverse_swap", trade["market_type"])
+        self.assertEqual("trade", trade["msg_type"])
+        self.assertEqual(58570.1, trade["price"])
+        self.assertAlmostEqual(5800.0 / 58570.1, trade["quantity_base"])
+        self.assertEqual(5800.0, trade["quantity_quote"])
+        self.assertEqual(58.0, trade["quantity_contract"])
+        self.assertEqual("sell", trade["side"])
+        self.assertEqual(MessageType.TRADE, get_msg_type("binance", TRADE_MSG))
+
+    def test_parse_l2(self):
+        orderbooks = parse_l2("binance", "inverse_swap", L2_MSG)
+        self.assertEqual(1, len(orderbooks))
+        orderbook = orderbooks[0]
+        self.assertEqual("l2_event", orderbook["msg_type"])
+        self.assertEqual(1622370862564, orderbook["timestamp"])
+        self.assertFalse(orderbook["snapshot"])
+        self.assertEqual(2, len(orderbook["asks"]))
+        self.assertEqual(35365.9, orderbook["bids"][0][0])
+        self.assertEqual(1400.0, orderbook["bids"][0][3])
+        self.assertEqual(MessageType.L2_EVENT, get_msg_type("binance", L2_MSG))
+
+    def test_parse_bbo(self):
+        bbo = parse_bbo("binance", MarketType.LINEAR_SWAP, BBO_MSG)[0]
+        self.assertEqual("ETHUSDT", bbo["symbol"])
+        self.assertEqual(1653817855289, bbo["timestamp"])
+        self.assertEqual(1553413152520, bbo["id"])
+        self.assertEqual(1778.55, bbo["ask_price"])
+        self.assertEqual(15.164, bbo["bid_quantity_base"])
+
+    def test_parse_funding_rate(self):
+        rate = parse_funding_rate("binance", MarketType.INVERSE_SWAP, FUNDING_RATE_MSG)[0]
+        self.assertEqual("BTC/USD", rate["pair"])
+        self.assertEqual(0.00073689, rate["funding_rate"])
+        self.assertEqual(1617321600000, rate["funding_time"])
+
+    def test_parse_candlestick(self):
+        candlestick = parse_candlestick("binance", MarketType.LINEAR_SWAP, CANDLESTICK_MSG)[0]
+        self.assertEqual("1M", candlestick["period"])
+        self.assertEqual(1651363200, candlestick["begin_time"])
+        self.assertEqual(37614.4, candlestick["open"])
+        self.assertEqual(423075730671.12853, candlestick["quote_volume"])
+
+    def test_options(self):
+        trade = parse_trade(
+            "binance",
+            MarketType.INVERSE_SWAP,
+            TRADE_MSG,
+            number_format=NumberFormat.DECIMAL_STRING,
+            fields=["timestamp", "price", "side"],
+        )[0]
+        self.assertEqual({"timestamp": 1616201883458, "price": "58570.1", "side": "sell"}, trade)
+
+        trade = parse_trade("binance", MarketType.INVERSE_SWAP, TRADE_MSG, omit_json=True)[0]
+        self.assertEqual("", trade["json"])
+
+    def test_extract(self):
+        self.assertEqual("BTCUSD_PERP", extract_symbol("binance", MarketType.INVERSE_SWAP, L2_MSG))
+        self.assertEqual(
+            1622370862564, extract_timestamp("binance", MarketType.INVERSE_SWAP, L2_MSG)
+        )
+
+    def test_parse_error(self):
+        with self.assertRaises(ParseError) as ctx:
+            parse_trade("binance", MarketType.INVERSE_SWAP, "not json")
+        self.assertTrue(str(ctx.exception))
+
+
+if __name__ == "__main__":
+    unittest.main()
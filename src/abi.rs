@@ -0,0 +1,24 @@
+//! ABI version of the C API.
+//!
+//! The ABI version is bumped whenever a change breaks programs compiled
+//! against an older header, e.g., removing or renaming a function, changing
+//! a signature, the layout of a struct or the value of an enumerator.
+//! Additions keep the ABI version. All enumerators, including those of
+//! `MarketType` and `MessageType`, have explicit values in the headers.
+//!
+//! `tests/abi.rs` compares the exported symbols and the declarations in the
+//! headers with the baseline in `tests/abi`. There is no linker version
+//! script, rustc already links the cdylib with its own one, so symbols are
+//! unversioned and programs rely on `abi_version()` at runtime.
+
+/// ABI version of the header a program is compiled against.
+pub const CRYPTO_MSG_PARSER_FFI_ABI_VERSION: u32 = 1;
+
+/// Get the ABI version of the loaded library.
+///
+/// A program should check at startup that it equals the
+/// `CRYPTO_MSG_PARSER_FFI_ABI_VERSION` it was compiled with.
+#[no_mangle]
+pub extern "C" fn abi_version() -> u32 {
+    CRYPTO_MSG_PARSER_FFI_ABI_VERSION
+}
@@ -0,0 +1,447 @@
+//! Arrow record batches of normalized messages.
+//!
+//! There is one schema per message type, with the same columns as the CSV
+//! output, see the `csv` module. Orderbook messages are flattened, one row
+//! per price level.
+
+use crate::message::{parse_msg, Message};
+use arrow::{
+    array::{
+        Array, ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray,
+        StructArray, UInt64Array,
+    },
+    datatypes::{DataType, Field, Schema, SchemaRef},
+    error::ArrowError,
+    ffi::{FFI_ArrowArray, FFI_ArrowSchema},
+};
+use crypto_market_type::MarketType;
+use crypto_message::{BboMsg, CandlestickMsg, FundingRateMsg, OrderBookMsg, TradeMsg};
+use crypto_msg_type::MessageType;
+use std::{ffi::CStr, os::raw::c_char, sync::Arc};
+
+/// Fields shared by all message types.
+struct Common<'a> {
+    exchange: &'a str,
+    market_type: MarketType,
+    msg_type: MessageType,
+    pair: &'a str,
+    symbol: &'a str,
+    timestamp: i64,
+}
+
+macro_rules! common {
+    ($msg:expr) => {
+        Common {
+            exchange: &$msg.exchange,
+            market_type: $msg.market_type,
+            msg_type: $msg.msg_type,
+            pair: &$msg.pair,
+            symbol: &$msg.symbol,
+            timestamp: $msg.timestamp,
+        }
+    };
+}
+
+fn utf8(name: &str) -> Field {
+    Field::new(name, DataType::Utf8, false)
+}
+
+fn float64(name: &str, nullable: bool) -> Field {
+    Field::new(name, DataType::Float64, nullable)
+}
+
+fn int64(name: &str) -> Field {
+    Field::new(name, DataType::Int64, false)
+}
+
+fn uint64(name: &str) -> Field {
+    Field::new(name, DataType::UInt64, true)
+}
+
+fn schema_with(fields: Vec<Field>) -> SchemaRef {
+    let mut all_fields = vec![
+        utf8("exchange"),
+        utf8("market_type"),
+        utf8("msg_type"),
+        utf8("pair"),
+        utf8("symbol"),
+        int64("timestamp"),
+    ];
+    all_fields.extend(fields);
+    Arc::new(Schema::new(all_fields))
+}
+
+/// Get the Arrow schema of a message type.
+pub fn schema(msg_type: MessageType) -> Option<SchemaRef> {
+    let fields = match msg_type {
+        MessageType::Trade => vec![
+            utf8("side"),
+            float64("price", false),
+            float64("quantity_base", false),
+            float64("quantity_quote", false),
+            float64("quantity_contract", true),
+            utf8("trade_id"),
+        ],
+        MessageType::L2Event | MessageType::L2Snapshot | MessageType::L2TopK => vec![
+            Field::new("snapshot", DataType::Boolean, false),
+            uint64("seq_id"),
+            uint64("prev_seq_id"),
+            utf8("side"),
+            float64("price", false),
+            float64("quantity_base", false),
+            float64("quantity_quote", false),
+            float64("quantity_contract", true),
+        ],
+        MessageType::BBO => vec![
+            uint64("id"),
+            float64("bid_price", false),
+            float64("bid_quantity_base", false),
+            float64("bid_quantity_quote", false),
+            float64("bid_quantity_contract", true),
+            float64("ask_price", false),
+            float64("ask_quantity_base", false),
+            float64("ask_quantity_quote", false),
+            float64("ask_quantity_contract", true),
+        ],
+        MessageType::FundingRate => vec![
+            float64("funding_rate", false),
+            int64("funding_time"),
+            float64("estimated_rate", true),
+        ],
+        MessageType::Candlestick => vec![
+            int64("begin_time"),
+            utf8("period"),
+            float64("open", false),
+            float64("high", false),
+            float64("low", false),
+            float64("close", false),
+            float64("volume", false),
+            float64("quote_volume", true),
+        ],
+        _ => return None,
+    };
+    Some(schema_with(fields))
+}
+
+fn strings<'a>(values: impl Iterator<Item = &'a str>) -> ArrayRef {
+    Arc::new(StringArray::from_iter_values(values))
+}
+
+fn floats(values: impl Iterator<Item = Option<f64>>) -> ArrayRef {
+    Arc::new(Float64Array::from_iter(values))
+}
+
+fn ints(values: impl Iterator<Item = i64>) -> ArrayRef {
+    Arc::new(Int64Array::from_iter_values(values))
+}
+
+fn uints(values: impl Iterator<Item = Option<u64>>) -> ArrayRef {
+    Arc::new(UInt64Array::from_iter(values))
+}
+
+fn common_columns(rows: &[Common]) -> Vec<ArrayRef> {
+    let market_types: Vec<String> = rows.iter().map(|r| r.market_type.to_string()).collect();
+    let msg_types: Vec<String> = rows.iter().map(|r| r.msg_type.to_string()).collect();
+    vec![
+        strings(rows.iter().map(|r| r.exchange)),
+        strings(market_types.iter().map(String::as_str)),
+        strings(msg_types.iter().map(String::as_str)),
+        strings(rows.iter().map(|r| r.pair)),
+        strings(rows.iter().map(|r| r.symbol)),
+        ints(rows.iter().map(|r| r.timestamp)),
+    ]
+}
+
+fn trade_columns(msgs: &[&TradeMsg]) -> Vec<ArrayRef> {
+    let rows: Vec<Common> = msgs.iter().map(|m| common!(m)).collect();
+    let sides: Vec<String> = msgs.iter().map(|m| m.side.to_string()).collect();
+    let mut columns = common_columns(&rows);
+    columns.extend([
+        strings(sides.iter().map(String::as_str)),
+        floats(msgs.iter().map(|m| Some(m.price))),
+        floats(msgs.iter().map(|m| Some(m.quantity_base))),
+        floats(msgs.iter().map(|m| Some(m.quantity_quote))),
+        floats(msgs.iter().map(|m| m.quantity_contract)),
+        strings(msgs.iter().map(|m| m.trade_id.as_str())),
+    ]);
+    columns
+}
+
+fn l2_columns(msgs: &[&OrderBookMsg]) -> Vec<ArrayRef> {
+    // one row per price level
+    let levels: Vec<(&OrderBookMsg, &str, &crypto_message::Order)> = msgs
+        .iter()
+        .flat_map(|m| {
+            m.bids
+                .iter()
+                .map(move |order| (*m, "bid", order))
+                .chain(m.asks.iter().map(move |order| (*m, "ask", order)))
+        })
+        .collect();
+    let rows: Vec<Common> = levels.iter().map(|(m, _, _)| common!(m)).collect();
+    let mut columns = common_columns(&rows);
+    columns.extend([
+        Arc::new(BooleanArray::from_iter(
+            levels.iter().map(|(m, _, _)| Some(m.snapshot)),
+        )) as ArrayRef,
+        uints(levels.iter().map(|(m, _, _)| m.seq_id)),
+        uints(levels.iter().map(|(m, _, _)| m.prev_seq_id)),
+        strings(levels.iter().map(|(_, side, _)| *side)),
+        floats(levels.iter().map(|(_, _, o)| Some(o.price))),
+        floats(levels.iter().map(|(_, _, o)| Some(o.quantity_base))),
+        floats(levels.iter().map(|(_, _, o)| Some(o.quantity_quote))),
+        floats(levels.iter().map(|(_, _, o)| o.quantity_contract)),
+    ]);
+    columns
+}
+
+fn bbo_columns(msgs: &[&BboMsg]) -> Vec<ArrayRef> {
+    let rows: Vec<Common> = msgs.iter().map(|m| common!(m)).collect();
+    let mut columns = common_columns(&rows);
+    columns.extend([
+        uints(msgs.iter().map(|m| m.id)),
+        floats(msgs.iter().map(|m| Some(m.bid_price))),
+        floats(msgs.iter().map(|m| Some(m.bid_quantity_base))),
+        floats(msgs.iter().map(|m| Some(m.bid_quantity_quote))),
+        floats(msgs.iter().map(|m| m.bid_quantity_contract)),
+        floats(msgs.iter().map(|m| Some(m.ask_price))),
+        floats(msgs.iter().map(|m| Some(m.ask_quantity_base))),
+        floats(msgs.iter().map(|m| Some(m.ask_quantity_quote))),
+        floats(msgs.iter().map(|m| m.ask_quantity_contract)),
+    ]);
+    columns
+}
+
+fn funding_rate_columns(msgs: &[&FundingRateMsg]) -> Vec<ArrayRef> {
+    let rows: Vec<Common> = msgs.iter().map(|m| common!(m)).collect();
+    let mut columns = common_columns(&rows);
+    columns.extend([
+        floats(msgs.iter().map(|m| Some(m.funding_rate))),
+        ints(msgs.iter().map(|m| m.funding_time)),
+        floats(msgs.iter().map(|m| m.estimated_rate)),
+    ]);
+    columns
+}
+
+fn candlestick_columns(msgs: &[&CandlestickMsg]) -> Vec<ArrayRef> {
+    let rows: Vec<Common> = msgs.iter().map(|m| common!(m)).collect();
+    let mut columns = common_columns(&rows);
+    columns.extend([
+        ints(msgs.iter().map(|m| m.begin_time)),
+        strings(msgs.iter().map(|m| m.period.as_str())),
+        floats(msgs.iter().map(|m| Some(m.open))),
+        floats(msgs.iter().map(|m| Some(m.high))),
+        floats(msgs.iter().map(|m| Some(m.low))),
+        floats(msgs.iter().map(|m| Some(m.close))),
+        floats(msgs.iter().map(|m| Some(m.volume))),
+        floats(msgs.iter().map(|m| m.quote_volume)),
+    ]);
+    columns
+}
+
+/// Collect the inner messages of one variant, fails if any message is of another variant.
+macro_rules! collect_variant {
+    ($msgs:expr, $variant:path) => {
+        $msgs
+            .iter()
+            .map(|msg| match msg {
+                $variant(m) => Ok(m),
+                _ => Err(ArrowError::InvalidArgumentError(format!(
+                    "Unexpected message type {}",
+                    msg.msg_type()
+                ))),
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?
+    };
+}
+
+/// Convert messages of the same message type into a record batch.
+///
+/// The schema is decided by `msg_type`, see `schema()`.
+pub fn to_record_batch(msg_type: MessageType, msgs: &[Message]) -> Result<RecordBatch, ArrowError> {
+    let schema = schema(msg_type).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("Unsupported message type {msg_type}"))
+    })?;
+    let columns = match msg_type {
+        MessageType::Trade => trade_columns(&collect_variant!(msgs, Message::Trade)),
+        MessageType::L2Event | MessageType::L2Snapshot | MessageType::L2TopK => {
+            l2_columns(&collect_variant!(msgs, Message::OrderBook))
+        }
+        MessageType::BBO => bbo_columns(&collect_variant!(msgs, Message::Bbo)),
+        MessageType::FundingRate => {
+            funding_rate_columns(&collect_variant!(msgs, Message::FundingRate))
+        }
+        MessageType::Candlestick => {
+            candlestick_columns(&collect_variant!(msgs, Message::Candlestick))
+        }
+        _ => unreachable!(),
+    };
+    RecordBatch::try_new(schema, columns)
+}
+
+/// Export the Arrow schema of a message type via the Arrow C Data Interface.
+///
+/// Returns false if the message type is not supported. The caller owns
+/// `out_schema` and must call its `release` callback.
+#[no_mangle]
+pub extern "C" fn export_arrow_schema(
+    msg_type: MessageType,
+    out_schema: *mut FFI_ArrowSchema,
+) -> bool {
+    crate::error::clear_last_error();
+    debug_assert!(!out_schema.is_null());
+    match schema(msg_type).map(|schema| FFI_ArrowSchema::try_from(schema.as_ref())) {
+        Some(Ok(ffi_schema)) => {
+            unsafe { std::ptr::write(out_schema, ffi_schema) };
+            true
+        }
+        Some(Err(err)) => {
+            crate::error::set_last_error(&err.to_string());
+            false
+        }
+        None => {
+            crate::error::set_last_error(&format!("No Arrow schema for {msg_type}"));
+            false
+        }
+    }
+}
+
+/// Parse a batch of raw messages of the same message type into an Arrow
+/// record batch, exported via the Arrow C Data Interface.
+///
+/// * `msgs` is an array of `len` raw messages.
+/// * `received_at` is either NULL or an array of `len` timestamps.
+///
+/// The record batch is exported as a struct array, e.g., in Python, use
+/// `pyarrow.RecordBatch._import_from_c(array_ptr, schema_ptr)` to import it.
+/// The caller owns `out_array` and `out_schema` and must call their `release`
+/// callbacks.
+///
+/// Returns the number of messages that failed to parse, or -1 if an error happens.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn parse_batch_arrow(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg_type: MessageType,
+    msgs: *const *const c_char,
+    received_at: *const i64,
+    len: usize,
+    out_array: *mut FFI_ArrowArray,
+    out_schema: *mut FFI_ArrowSchema,
+) -> i64 {
+    crate::error::clear_last_error();
+    let exchange_rust = unsafe {
+        debug_assert!(!exchange.is_null());
+        CStr::from_ptr(exchange).to_str().unwrap()
+    };
+    let msgs_rust = unsafe {
+        debug_assert!(len == 0 || !msgs.is_null());
+        if len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(msgs, len)
+        }
+    };
+    debug_assert!(!out_array.is_null());
+    debug_assert!(!out_schema.is_null());
+
+    let mut failed = 0_i64;
+    let mut parsed: Vec<Message> = Vec::new();
+    for (i, msg) in msgs_rust.iter().enumerate() {
+        let msg_rust = unsafe {
+            debug_assert!(!msg.is_null());
+            CStr::from_ptr(*msg).to_str().unwrap()
+        };
+        let timestamp = if received_at.is_null() {
+            0
+        } else {
+            unsafe { *received_at.add(i) }
+        };
+        match parse_msg(exchange_rust, market_type, msg_type, msg_rust, timestamp) {
+            Ok(msgs) => parsed.extend(msgs),
+            Err(err) => {
+                crate::error::set_last_error(&err.to_string());
+                failed += 1;
+            }
+        }
+    }
+
+    let result = to_record_batch(msg_type, &parsed).and_then(|batch| {
+        let ffi_schema = FFI_ArrowSchema::try_from(batch.schema().as_ref())?;
+        let data = StructArray::from(batch).into_data();
+        Ok((FFI_ArrowArray::new(&data), ffi_schema))
+    });
+    match result {
+        Ok((ffi_array, ffi_schema)) => {
+            unsafe {
+                std::ptr::write(out_array, ffi_array);
+                std::ptr::write(out_schema, ffi_schema);
+            }
+            failed
+        }
+        Err(err) => {
+            crate::error::set_last_error(&err.to_string());
+            -1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_batch_arrow, schema};
+    use arrow::{
+        array::{Array, Float64Array, RecordBatch, StringArray, StructArray},
+        ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema},
+    };
+    use crypto_market_type::MarketType;
+    use crypto_msg_type::MessageType;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_parse_batch_arrow() {
+        let exchange = CString::new("binance").unwrap();
+        let raw_msgs = [
+            CString::new(r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}"#).unwrap(),
+            CString::new("not json").unwrap(),
+        ];
+        let ptrs: Vec<_> = raw_msgs.iter().map(|msg| msg.as_ptr()).collect();
+
+        let mut ffi_array = FFI_ArrowArray::empty();
+        let mut ffi_schema = FFI_ArrowSchema::empty();
+        let failed = parse_batch_arrow(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            MessageType::L2Event,
+            ptrs.as_ptr(),
+            std::ptr::null(),
+            ptrs.len(),
+            &mut ffi_array,
+            &mut ffi_schema,
+        );
+        assert_eq!(1, failed);
+
+        let data = unsafe { from_ffi(ffi_array, &ffi_schema) }.unwrap();
+        let batch = RecordBatch::from(StructArray::from(data));
+        assert_eq!(schema(MessageType::L2Event).unwrap(), batch.schema());
+        assert_eq!(4, batch.num_rows());
+
+        let sides = batch
+            .column_by_name("side")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let prices = batch
+            .column_by_name("price")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!("bid", sides.value(0));
+        assert_eq!(35365.9, prices.value(0));
+        assert_eq!("ask", sides.value(3));
+        assert_eq!(35818.7, prices.value(3));
+        assert!(batch.column_by_name("seq_id").unwrap().is_valid(0));
+    }
+}
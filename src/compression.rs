@@ -0,0 +1,92 @@
+//! Decompress websocket frames, e.g., Huobi sends gzip frames and OKX sends
+//! raw deflate frames.
+
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use std::io::{self, Read};
+
+/// Compression of a raw message.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Compression {
+    /// Uncompressed text
+    Plain = 0,
+    Gzip = 1,
+    /// Raw deflate stream without header, RFC 1951
+    Deflate = 2,
+    /// Deflate stream with a zlib header, RFC 1950
+    Zlib = 3,
+    /// Detect gzip and zlib by their headers, otherwise the frame is plain
+    /// text if it starts with `{` or `[`, or a raw deflate stream
+    Auto = 4,
+}
+
+/// Detect the compression of a frame.
+pub fn detect(bytes: &[u8]) -> Compression {
+    match bytes {
+        [0x1f, 0x8b, ..] => Compression::Gzip,
+        [cmf, flg, ..] if cmf & 0x0f == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0 => {
+            Compression::Zlib
+        }
+        _ => match bytes.iter().find(|b| !b.is_ascii_whitespace()) {
+            Some(b'{' | b'[') | None => Compression::Plain,
+            Some(_) => Compression::Deflate,
+        },
+    }
+}
+
+/// Decompress a frame into text.
+pub fn decompress_frame(bytes: &[u8], compression: Compression) -> io::Result<String> {
+    let compression = match compression {
+        Compression::Auto => detect(bytes),
+        _ => compression,
+    };
+    let mut text = String::new();
+    match compression {
+        Compression::Plain => {
+            text = String::from_utf8(bytes.to_vec())
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+        }
+        Compression::Gzip => {
+            GzDecoder::new(bytes).read_to_string(&mut text)?;
+        }
+        Compression::Deflate => {
+            DeflateDecoder::new(bytes).read_to_string(&mut text)?;
+        }
+        Compression::Zlib => {
+            ZlibDecoder::new(bytes).read_to_string(&mut text)?;
+        }
+        Compression::Auto => unreachable!(),
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decompress_frame, detect, Compression};
+    use flate2::write::{DeflateEncoder, GzEncoder, ZlibEncoder};
+    use std::io::Write;
+
+    const TEXT: &str = r#"{"ch":"market.btcusdt.trade.detail","ts":1616243199157}"#;
+
+    #[test]
+    fn test_decompress_frame() {
+        let mut gzip = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(TEXT.as_bytes()).unwrap();
+        let mut deflate = DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        deflate.write_all(TEXT.as_bytes()).unwrap();
+        let mut zlib = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        zlib.write_all(TEXT.as_bytes()).unwrap();
+
+        for (bytes, compression) in [
+            (TEXT.as_bytes().to_vec(), Compression::Plain),
+            (gzip.finish().unwrap(), Compression::Gzip),
+            (deflate.finish().unwrap(), Compression::Deflate),
+            (zlib.finish().unwrap(), Compression::Zlib),
+        ] {
+            assert_eq!(compression, detect(&bytes));
+            assert_eq!(TEXT, decompress_frame(&bytes, compression).unwrap());
+            assert_eq!(TEXT, decompress_frame(&bytes, Compression::Auto).unwrap());
+        }
+        assert!(decompress_frame(b"not gzip", Compression::Gzip).is_err());
+    }
+}
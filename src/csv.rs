@@ -0,0 +1,240 @@
+//! CSV serialization of normalized messages.
+//!
+//! Every message type has a fixed column order, given by its header row. The
+//! raw `json` field is never included. Optional fields are empty when absent.
+//! Orderbook messages are flattened, one row per price level.
+
+use crate::message::Message;
+use crypto_message::{BboMsg, CandlestickMsg, FundingRateMsg, Order, OrderBookMsg, TradeMsg};
+use crypto_msg_type::MessageType;
+use std::fmt::{Display, Write};
+
+/// Columns of `TradeMsg` rows.
+pub const TRADE_HEADER: &str = "exchange,market_type,msg_type,pair,symbol,timestamp,side,price,quantity_base,quantity_quote,quantity_contract,trade_id";
+
+/// Columns of `OrderBookMsg` rows, `side` is either `bid` or `ask`.
+pub const L2_HEADER: &str = "exchange,market_type,msg_type,pair,symbol,timestamp,snapshot,seq_id,prev_seq_id,side,price,quantity_base,quantity_quote,quantity_contract";
+
+/// Columns of `BboMsg` rows.
+pub const BBO_HEADER: &str = "exchange,market_type,msg_type,pair,symbol,timestamp,id,bid_price,bid_quantity_base,bid_quantity_quote,bid_quantity_contract,ask_price,ask_quantity_base,ask_quantity_quote,ask_quantity_contract";
+
+/// Columns of `FundingRateMsg` rows.
+pub const FUNDING_RATE_HEADER: &str =
+    "exchange,market_type,msg_type,pair,symbol,timestamp,funding_rate,funding_time,estimated_rate";
+
+/// Columns of `CandlestickMsg` rows.
+pub const CANDLESTICK_HEADER: &str = "exchange,market_type,msg_type,pair,symbol,timestamp,begin_time,period,open,high,low,close,volume,quote_volume";
+
+/// Get the CSV header row of a message type, without the trailing newline.
+pub fn csv_header(msg_type: MessageType) -> Option<&'static str> {
+    match msg_type {
+        MessageType::Trade => Some(TRADE_HEADER),
+        MessageType::L2Event | MessageType::L2Snapshot | MessageType::L2TopK => Some(L2_HEADER),
+        MessageType::BBO => Some(BBO_HEADER),
+        MessageType::FundingRate => Some(FUNDING_RATE_HEADER),
+        MessageType::Candlestick => Some(CANDLESTICK_HEADER),
+        _ => None,
+    }
+}
+
+/// Convert a message to CSV rows, each row ends with a newline.
+pub trait ToCsv {
+    fn write_csv(&self, out: &mut String);
+
+    fn to_csv(&self) -> String {
+        let mut out = String::new();
+        self.write_csv(&mut out);
+        out
+    }
+}
+
+/// Quote a text field if it contains special characters.
+fn text(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn opt<T: Display>(x: Option<T>) -> String {
+    x.map(|x| x.to_string()).unwrap_or_default()
+}
+
+macro_rules! common_fields {
+    ($msg:expr) => {
+        format!(
+            "{},{},{},{},{},{}",
+            text(&$msg.exchange),
+            $msg.market_type,
+            $msg.msg_type,
+            text(&$msg.pair),
+            text(&$msg.symbol),
+            $msg.timestamp
+        )
+    };
+}
+
+impl ToCsv for TradeMsg {
+    fn write_csv(&self, out: &mut String) {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            common_fields!(self),
+            self.side,
+            self.price,
+            self.quantity_base,
+            self.quantity_quote,
+            opt(self.quantity_contract),
+            text(&self.trade_id)
+        )
+        .unwrap();
+    }
+}
+
+impl ToCsv for OrderBookMsg {
+    fn write_csv(&self, out: &mut String) {
+        let prefix = format!(
+            "{},{},{},{}",
+            common_fields!(self),
+            self.snapshot,
+            opt(self.seq_id),
+            opt(self.prev_seq_id)
+        );
+        let levels = self
+            .bids
+            .iter()
+            .map(|order| ("bid", order))
+            .chain(self.asks.iter().map(|order| ("ask", order)));
+        for (side, order) in levels {
+            let Order {
+                price,
+                quantity_base,
+                quantity_quote,
+                quantity_contract,
+            } = order;
+            writeln!(
+                out,
+                "{prefix},{side},{price},{quantity_base},{quantity_quote},{}",
+                opt(*quantity_contract)
+            )
+            .unwrap();
+        }
+    }
+}
+
+impl ToCsv for BboMsg {
+    fn write_csv(&self, out: &mut String) {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{}",
+            common_fields!(self),
+            opt(self.id),
+            self.bid_price,
+            self.bid_quantity_base,
+            self.bid_quantity_quote,
+            opt(self.bid_quantity_contract),
+            self.ask_price,
+            self.ask_quantity_base,
+            self.ask_quantity_quote,
+            opt(self.ask_quantity_contract)
+        )
+        .unwrap();
+    }
+}
+
+impl ToCsv for FundingRateMsg {
+    fn write_csv(&self, out: &mut String) {
+        writeln!(
+            out,
+            "{},{},{},{}",
+            common_fields!(self),
+            self.funding_rate,
+            self.funding_time,
+            opt(self.estimated_rate)
+        )
+        .unwrap();
+    }
+}
+
+impl ToCsv for CandlestickMsg {
+    fn write_csv(&self, out: &mut String) {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{}",
+            common_fields!(self),
+            self.begin_time,
+            text(&self.period),
+            self.open,
+            self.high,
+            self.low,
+            self.close,
+            self.volume,
+            opt(self.quote_volume)
+        )
+        .unwrap();
+    }
+}
+
+impl ToCsv for Message {
+    fn write_csv(&self, out: &mut String) {
+        match self {
+            Message::Trade(msg) => msg.write_csv(out),
+            Message::OrderBook(msg) => msg.write_csv(out),
+            Message::Bbo(msg) => msg.write_csv(out),
+            Message::FundingRate(msg) => msg.write_csv(out),
+            Message::Candlestick(msg) => msg.write_csv(out),
+        }
+    }
+}
+
+impl<T: ToCsv> ToCsv for [T] {
+    fn write_csv(&self, out: &mut String) {
+        for msg in self {
+            msg.write_csv(out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_header, ToCsv, L2_HEADER, TRADE_HEADER};
+    use crypto_market_type::MarketType;
+    use crypto_msg_type::MessageType;
+
+    #[test]
+    fn test_trade_csv() {
+        let raw_msg = r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#;
+        let trades =
+            crypto_msg_parser::parse_trade("binance", MarketType::InverseSwap, raw_msg).unwrap();
+        let text = trades.to_csv();
+
+        assert_eq!(
+            "binance,inverse_swap,trade,BTC/USD,BTCUSD_PERP,1616201883458,sell,58570.1,0.09902663645785137,5800,58,41045788\n",
+            text
+        );
+        assert_eq!(
+            TRADE_HEADER.split(',').count(),
+            text.trim_end().split(',').count()
+        );
+    }
+
+    #[test]
+    fn test_l2_csv() {
+        let raw_msg = r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}"#;
+        let orderbooks =
+            crypto_msg_parser::parse_l2("binance", MarketType::InverseSwap, raw_msg, None).unwrap();
+        let text = orderbooks.to_csv();
+        let rows: Vec<&str> = text.lines().collect();
+
+        assert_eq!(Some(L2_HEADER), csv_header(MessageType::L2TopK));
+        assert_eq!(4, rows.len());
+        assert!(rows[0].starts_with(
+            "binance,inverse_swap,l2_event,BTC/USD,BTCUSD_PERP,1622370862564,false,127559588177,127559587113,bid,35365.9,"
+        ));
+        assert!(rows[3].contains(",ask,35818.7,"));
+        for row in rows {
+            assert_eq!(L2_HEADER.split(',').count(), row.split(',').count());
+        }
+    }
+}
@@ -0,0 +1,489 @@
+//! Decimal representations of prices and quantities.
+//!
+//! `crypto-msg-parser` converts the decimal strings of exchanges into `f64`
+//! and doesn't keep them. `Decimals` recovers them from the raw message: it
+//! collects the numbers in it, JSON numbers as well as strings holding just a
+//! number, keyed by their float values, and a parsed value gets the string
+//! converting to the same float. Values computed by the parser, e.g.,
+//! `quantity_quote` of most exchanges or `quantity_base` of inverse contracts,
+//! usually aren't in the raw message, they are null in the output. So are
+//! values of which the raw message has different decimals converting to the
+//! same float, which takes more than 15 significant digits. Equal decimals
+//! written differently, e.g., `"1.0"` and `"1.00"`, get the first string.
+//!
+//! Fixed-point mantissas are computed exactly from these strings, with the
+//! same null values.
+
+use crate::message::Message;
+use serde_json::{Map, Value};
+use std::collections::{hash_map::Entry, HashMap};
+
+/// How prices and quantities are represented in the output.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumberFormat {
+    /// Floating point numbers, same as the `parse_*` functions
+    Float = 0,
+    /// The decimal strings of the exchange, e.g., `"0.00073689"`, null for
+    /// values not in the raw message
+    DecimalString = 1,
+    /// Integer mantissas of the decimal strings of the exchange, each message
+    /// has a decimal exponent per column, e.g., `"price": 585701` with
+    /// `"price_exponent": -1`. Values not in the raw message are null, a
+    /// mantissa overflowing `i64` is an error
+    FixedPoint = 2,
+}
+
+/// Columns of prices, quantities and rates, each has a field holding the
+/// exponent in fixed-point mode, the fields of the column, and the index of
+/// the column in orders of `asks` and `bids` if any.
+const COLUMNS: &[(&str, &[&str], Option<usize>)] = &[
+    (
+        "price_exponent",
+        &[
+            "price",
+            "bid_price",
+            "ask_price",
+            "open",
+            "high",
+            "low",
+            "close",
+        ],
+        Some(0),
+    ),
+    (
+        "quantity_base_exponent",
+        &[
+            "quantity_base",
+            "bid_quantity_base",
+            "ask_quantity_base",
+            "volume",
+        ],
+        Some(1),
+    ),
+    (
+        "quantity_quote_exponent",
+        &[
+            "quantity_quote",
+            "bid_quantity_quote",
+            "ask_quantity_quote",
+            "quote_volume",
+        ],
+        Some(2),
+    ),
+    (
+        "quantity_contract_exponent",
+        &[
+            "quantity_contract",
+            "bid_quantity_contract",
+            "ask_quantity_contract",
+        ],
+        Some(3),
+    ),
+    ("rate_exponent", &["funding_rate", "estimated_rate"], None),
+];
+
+/// Length of the number at the start of `bytes`, 0 if there is none.
+fn number_len(bytes: &[u8]) -> usize {
+    let digits = |from: usize| {
+        from + bytes[from.min(bytes.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let sign = usize::from(bytes.first() == Some(&b'-'));
+    let mut end = digits(sign);
+    if end == sign {
+        return 0;
+    }
+    if bytes.get(end) == Some(&b'.') && digits(end + 1) > end + 1 {
+        end = digits(end + 1);
+    }
+    if let Some(b'e' | b'E') = bytes.get(end) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exponent_end = digits(end + 1 + sign);
+        if exponent_end > end + 1 + sign {
+            end = exponent_end;
+        }
+    }
+    end
+}
+
+/// Key of a float, zeros of both signs are the same.
+fn float_key(x: f64) -> u64 {
+    if x == 0.0 {
+        0
+    } else {
+        x.to_bits()
+    }
+}
+
+/// Whether two decimal strings have the same value.
+fn same_decimal(a: &str, b: &str) -> bool {
+    match (FixedPoint::parse(a), FixedPoint::parse(b)) {
+        (Some(a), Some(b)) => a.normalize() == b.normalize(),
+        _ => a == b,
+    }
+}
+
+/// Decimal strings of the numbers in a raw message, keyed by their float values.
+#[derive(Debug, Default)]
+pub struct Decimals<'a> {
+    /// None if different decimals convert to the same float
+    strings: HashMap<u64, Option<&'a str>>,
+}
+
+impl<'a> Decimals<'a> {
+    /// Collect the JSON numbers of a raw message and its strings holding just
+    /// a number, including those in JSON strings nested in the message.
+    pub fn scan(msg: &'a str) -> Self {
+        let bytes = msg.as_bytes();
+        let mut strings = HashMap::new();
+        let mut start = 0;
+        while start < bytes.len() {
+            let end = start + number_len(&bytes[start..]);
+            let delimited = end > start
+                && (start == 0 || b"\"[{:, \t\r\n".contains(&bytes[start - 1]))
+                && (end == bytes.len() || b"\"]},\\ \t\r\n".contains(&bytes[end]));
+            if delimited {
+                let decimal = &msg[start..end];
+                if let Some(x) = decimal.parse::<f64>().ok().filter(|x| x.is_finite()) {
+                    match strings.entry(float_key(x)) {
+                        Entry::Vacant(entry) => {
+                            entry.insert(Some(decimal));
+                        }
+                        Entry::Occupied(mut entry) => {
+                            if entry
+                                .get()
+                                .is_some_and(|first| !same_decimal(first, decimal))
+                            {
+                                entry.insert(None);
+                            }
+                        }
+                    }
+                }
+            }
+            start = end.max(start + 1);
+        }
+        Decimals { strings }
+    }
+
+    /// Get the decimal string converting to a float, None if the raw message
+    /// has none or different ones.
+    pub fn get(&self, x: f64) -> Option<&'a str> {
+        self.strings.get(&float_key(x)).copied().flatten()
+    }
+}
+
+/// A decimal number `mantissa * 10^exponent`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedPoint {
+    pub mantissa: i64,
+    pub exponent: i32,
+}
+
+impl FixedPoint {
+    /// Parse a decimal string, e.g., `"-0.00073689"` or `"1.5e-8"`.
+    ///
+    /// Returns None if the string is invalid or the mantissa overflows.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (s, exponent) = match s.split_once(['e', 'E']) {
+            Some((s, exponent)) => (s, exponent.parse::<i32>().ok()?),
+            None => (s, 0),
+        };
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        let frac = frac.trim_end_matches('0');
+        if int.is_empty() && frac.is_empty() {
+            return None;
+        }
+        let mut mantissa: i64 = 0;
+        for c in int.chars().chain(frac.chars()) {
+            let digit = c.to_digit(10)? as i64;
+            mantissa = mantissa.checked_mul(10)?.checked_add(digit)?;
+        }
+        Some(FixedPoint {
+            mantissa: if negative { -mantissa } else { mantissa },
+            exponent: exponent.checked_sub(frac.len() as i32)?,
+        })
+    }
+
+    /// Remove trailing zeros of the mantissa.
+    pub fn normalize(self) -> Self {
+        let FixedPoint {
+            mut mantissa,
+            mut exponent,
+        } = self;
+        if mantissa == 0 {
+            return FixedPoint {
+                mantissa,
+                exponent: 0,
+            };
+        }
+        while mantissa % 10 == 0 {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        FixedPoint { mantissa, exponent }
+    }
+
+    /// Convert to the nearest float.
+    pub fn to_f64(&self) -> f64 {
+        format!("{}e{}", self.mantissa, self.exponent)
+            .parse()
+            .unwrap()
+    }
+
+    /// Get the mantissa with a smaller exponent, returns None if it overflows.
+    pub fn mantissa_at(&self, exponent: i32) -> Option<i64> {
+        debug_assert!(exponent <= self.exponent);
+        10i64
+            .checked_pow((self.exponent - exponent) as u32)?
+            .checked_mul(self.mantissa)
+    }
+}
+
+/// Collect the values of fields, and of orders at `index` if given, including nulls.
+fn column_values<'a>(
+    obj: &'a mut Map<String, Value>,
+    fields: &[&str],
+    index: Option<usize>,
+) -> Vec<&'a mut Value> {
+    let mut values = Vec::new();
+    for (key, field) in obj.iter_mut() {
+        if fields.contains(&key.as_str()) {
+            values.push(field);
+        } else if let (Some(index), "asks" | "bids") = (index, key.as_str()) {
+            for order in field.as_array_mut().into_iter().flatten() {
+                values.extend(order.as_array_mut().and_then(|v| v.get_mut(index)));
+            }
+        }
+    }
+    values
+}
+
+fn to_decimal_strings(obj: &mut Map<String, Value>, decimals: &Decimals) {
+    for (_, fields, index) in COLUMNS {
+        for value in column_values(obj, fields, *index) {
+            if let Some(x) = value.as_f64() {
+                *value = decimals.get(x).map_or(Value::Null, Value::from);
+            }
+        }
+    }
+}
+
+fn to_fixed_point(obj: &mut Map<String, Value>, decimals: &Decimals) -> Result<(), String> {
+    for (exponent_field, fields, index) in COLUMNS {
+        let mut values = column_values(obj, fields, *index);
+        if values.is_empty() {
+            continue;
+        }
+        let mut numbers = Vec::with_capacity(values.len());
+        for value in values.iter() {
+            numbers.push(match value.as_f64().and_then(|x| decimals.get(x)) {
+                Some(decimal) => Some(
+                    FixedPoint::parse(decimal)
+                        .map(|number| (number, decimal))
+                        .ok_or_else(|| format!("The mantissa of {decimal} overflows i64"))?,
+                ),
+                None => None,
+            });
+        }
+        let exponent = numbers.iter().flatten().map(|(n, _)| n.exponent).min();
+        for (value, number) in values.iter_mut().zip(numbers) {
+            **value = match (number, exponent) {
+                (Some((number, decimal)), Some(exponent)) => {
+                    Value::from(number.mantissa_at(exponent).ok_or_else(|| {
+                        format!("The mantissa of {decimal} at {exponent_field} {exponent} overflows i64")
+                    })?)
+                }
+                _ => Value::Null,
+            };
+        }
+        obj.insert(exponent_field.to_string(), Value::from(exponent));
+    }
+    Ok(())
+}
+
+/// Convert messages to JSON values with prices and quantities in the given
+/// format, `decimals` are those of the raw message.
+///
+/// Orders in `asks` and `bids` are arrays of `[price, quantity_base,
+/// quantity_quote, quantity_contract]`, all of them are converted. In
+/// both modes, values not in the raw message are null. In fixed-point mode,
+/// values in a column share the smallest exponent needed to represent their
+/// decimal strings exactly, the exponent is null if the column has none of
+/// them. It is an error if a mantissa overflows `i64`.
+pub fn to_value(
+    msgs: &[Message],
+    decimals: &Decimals,
+    number_format: NumberFormat,
+) -> Result<Value, String> {
+    let mut value = serde_json::to_value(msgs).map_err(|err| err.to_string())?;
+    if number_format == NumberFormat::Float {
+        return Ok(value);
+    }
+    for obj in value
+        .as_array_mut()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object_mut)
+    {
+        match number_format {
+            NumberFormat::Float => (),
+            NumberFormat::DecimalString => to_decimal_strings(obj, decimals),
+            NumberFormat::FixedPoint => to_fixed_point(obj, decimals)?,
+        }
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_value, Decimals, FixedPoint, NumberFormat};
+    use crate::message::parse_msg;
+    use crypto_market_type::MarketType;
+    use crypto_msg_type::MessageType;
+
+    #[test]
+    fn test_decimals() {
+        let decimals = Decimals::scan(
+            r#"{"p":"58570.10","q":[1400,"0.00073689"],"t":"2021-05-30","s":"BTCUSD_200925","j":"{\"r\":\"1e-8\"}"}"#,
+        );
+        assert_eq!(Some("58570.10"), decimals.get(58570.1));
+        assert_eq!(Some("1400"), decimals.get(1400.0));
+        assert_eq!(Some("0.00073689"), decimals.get(0.00073689));
+        assert_eq!(Some("1e-8"), decimals.get(1e-8));
+        assert_eq!(None, decimals.get(2021.0));
+        assert_eq!(None, decimals.get(200925.0));
+        assert_eq!(None, decimals.get(0.5));
+
+        // equal decimals keep the first string, different ones are ambiguous
+        let decimals = Decimals::scan(r#"["1.0","1.00","0.1","0.10000000000000000001"]"#);
+        assert_eq!(Some("1.0"), decimals.get(1.0));
+        assert_eq!(None, decimals.get(0.1));
+    }
+
+    #[test]
+    fn test_l2_decimal_string() {
+        let raw_msg = r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.90","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::L2Event,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+        let value = to_value(&msgs, &Decimals::scan(raw_msg), NumberFormat::DecimalString).unwrap();
+
+        let orderbook = &value[0];
+        assert_eq!(1622370862564i64, orderbook["timestamp"]);
+        assert_eq!(127559588177u64, orderbook["seq_id"]);
+        assert_eq!("35365.90", orderbook["bids"][0][0]);
+        assert_eq!("1400", orderbook["bids"][0][3]);
+        assert_eq!("35817.8", orderbook["asks"][0][0]);
+        // derived from the number of contracts
+        assert!(orderbook["bids"][0][1].is_null());
+        assert!(orderbook["bids"][0][2].is_null());
+    }
+
+    #[test]
+    fn test_fixed_point_parse() {
+        assert_eq!(
+            Some(FixedPoint {
+                mantissa: 73689,
+                exponent: -8
+            }),
+            FixedPoint::parse("0.00073689")
+        );
+        assert_eq!(
+            Some(FixedPoint {
+                mantissa: -585701,
+                exponent: -1
+            }),
+            FixedPoint::parse("-58570.10")
+        );
+        assert_eq!(
+            Some(585701000),
+            FixedPoint::parse("58570.1").unwrap().mantissa_at(-4)
+        );
+        assert_eq!(
+            Some(FixedPoint {
+                mantissa: 15,
+                exponent: -9
+            }),
+            FixedPoint::parse("1.5e-8")
+        );
+        assert_eq!(
+            FixedPoint {
+                mantissa: 14,
+                exponent: 2
+            },
+            FixedPoint::parse("1400.0").unwrap().normalize()
+        );
+        assert_eq!(None, FixedPoint::parse("1e"));
+        assert_eq!(None, FixedPoint::parse("NaN"));
+        assert_eq!(None, FixedPoint::parse("99999999999999999999"));
+    }
+
+    #[test]
+    fn test_l2_fixed_point() {
+        let raw_msg = r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::L2Event,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+        let value = to_value(&msgs, &Decimals::scan(raw_msg), NumberFormat::FixedPoint).unwrap();
+
+        let orderbook = &value[0];
+        assert_eq!(-1, orderbook["price_exponent"]);
+        assert_eq!(0, orderbook["quantity_contract_exponent"]);
+        assert_eq!(353659, orderbook["bids"][0][0]);
+        assert_eq!(1400, orderbook["bids"][0][3]);
+        assert_eq!(358187, orderbook["asks"][1][0]);
+        // derived from the number of contracts
+        assert!(orderbook["quantity_base_exponent"].is_null());
+        assert!(orderbook["asks"][1][1].is_null());
+    }
+
+    #[test]
+    fn test_funding_rate_fixed_point() {
+        let raw_msg = r#"{"stream":"btcusd_perp@markPrice","data":{"e":"markPriceUpdate","E":1617309477000,"s":"BTCUSD_PERP","p":"59012.56007222","P":"58896.00503145","r":"0.00073689","T":1617321600000}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::FundingRate,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+        let value = to_value(&msgs, &Decimals::scan(raw_msg), NumberFormat::FixedPoint).unwrap();
+
+        let rate = &value[0];
+        assert_eq!(-8, rate["rate_exponent"]);
+        assert_eq!(73689, rate["funding_rate"]);
+    }
+
+    #[test]
+    fn test_fixed_point_overflow() {
+        let raw_msg = r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1000000000000000000"]],"a":[["35817.8","0.5"]]}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::L2Event,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+        let err = to_value(&msgs, &Decimals::scan(raw_msg), NumberFormat::FixedPoint).unwrap_err();
+        assert!(err.contains("overflows i64"), "{err}");
+    }
+}
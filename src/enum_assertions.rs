@@ -0,0 +1,38 @@
+//! Checks that the values in crypto_market_type.h and crypto_msg_type.h equal
+//! the discriminants of the enums the library is compiled with.
+//!
+//! Generated by `cargo xtask codegen`, don't modify it manually.
+
+use crypto_market_type::MarketType;
+use crypto_msg_type::MessageType;
+use std::{mem::size_of, os::raw::c_int};
+
+const _: () = assert!(size_of::<MarketType>() == size_of::<c_int>());
+const _: () = assert!(MarketType::Unknown as i64 == 0);
+const _: () = assert!(MarketType::Spot as i64 == 1);
+const _: () = assert!(MarketType::LinearFuture as i64 == 2);
+const _: () = assert!(MarketType::InverseFuture as i64 == 3);
+const _: () = assert!(MarketType::LinearSwap as i64 == 4);
+const _: () = assert!(MarketType::InverseSwap as i64 == 5);
+const _: () = assert!(MarketType::AmericanOption as i64 == 6);
+const _: () = assert!(MarketType::EuropeanOption as i64 == 7);
+const _: () = assert!(MarketType::QuantoFuture as i64 == 8);
+const _: () = assert!(MarketType::QuantoSwap as i64 == 9);
+const _: () = assert!(MarketType::Move as i64 == 10);
+const _: () = assert!(MarketType::BVOL as i64 == 11);
+
+const _: () = assert!(size_of::<MessageType>() == size_of::<c_int>());
+const _: () = assert!(MessageType::Other as i64 == 0);
+const _: () = assert!(MessageType::Trade as i64 == 1);
+const _: () = assert!(MessageType::L2Event as i64 == 2);
+const _: () = assert!(MessageType::L2Snapshot as i64 == 3);
+const _: () = assert!(MessageType::L2TopK as i64 == 4);
+const _: () = assert!(MessageType::L3Event as i64 == 5);
+const _: () = assert!(MessageType::L3Snapshot as i64 == 6);
+const _: () = assert!(MessageType::BBO as i64 == 7);
+const _: () = assert!(MessageType::Ticker as i64 == 8);
+const _: () = assert!(MessageType::Candlestick as i64 == 9);
+const _: () = assert!(MessageType::FundingRate as i64 == 10);
+const _: () = assert!(MessageType::OpenInterest as i64 == 11);
+const _: () = assert!(MessageType::LongShortRatio as i64 == 12);
+const _: () = assert!(MessageType::TakerVolume as i64 == 13);
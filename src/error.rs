@@ -0,0 +1,41 @@
+//! Error messages of failed calls, per thread.
+
+use std::{cell::RefCell, ffi::CString, os::raw::c_char};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Record the error of a failed call on the current thread.
+pub(crate) fn set_last_error(err: &str) {
+    let err = CString::new(err.replace('\0', " ")).unwrap();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(err));
+}
+
+/// Clear the error of the current thread at the start of a call that records errors.
+pub(crate) fn clear_last_error() {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+}
+
+/// Get the error message of the last call on the current thread, returns
+/// NULL if it succeeded.
+///
+/// Errors are recorded by the functions returning a `ByteBuffer`
+/// (`parse_*_with_format()`, `parse_*_with_options()`, `parse_compressed()`
+/// and `parse_payload_bytes()`), `raw_file_reader_open()`,
+/// `export_arrow_schema()`, `parse_batch_arrow()` and the `parquet_writer_*`
+/// functions. Each of them clears the error when called, so the result
+/// always belongs to the last of them. Functions returning a string, such as
+/// `parse_trade()` or `extract_symbol()`, only return NULL on failure and
+/// leave the error untouched.
+///
+/// The string is owned by this library and valid until the next call of a
+/// function that records errors on the same thread, don't free it.
+#[no_mangle]
+pub extern "C" fn last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |err| err.as_ptr())
+    })
+}
@@ -0,0 +1,4588 @@
+//! FlatBuffers tables of `fbs/crypto_msg_parser.fbs`.
+//!
+//! Generated by `planus rust -o src/fbs.rs fbs/crypto_msg_parser.fbs` with
+//! planus-cli 1.3.0, don't edit by hand.
+
+pub use root::*;
+
+const _: () = ::planus::check_version_compatibility("planus-1.3.0");
+
+/// The root namespace
+///
+/// Generated from these locations:
+/// * File `fbs/crypto_msg_parser.fbs`
+#[no_implicit_prelude]
+#[allow(clippy::needless_lifetimes)]
+mod root {
+    /// The namespace `crypto_msg_parser`
+    ///
+    /// Generated from these locations:
+    /// * File `fbs/crypto_msg_parser.fbs`
+    pub mod crypto_msg_parser {
+        /// The namespace `crypto_msg_parser.fb`
+        ///
+        /// Generated from these locations:
+        /// * File `fbs/crypto_msg_parser.fbs`
+        pub mod fb {
+            /// The enum `MarketType` in the namespace `crypto_msg_parser.fb`
+            ///
+            /// Generated from these locations:
+            /// * Enum `MarketType` in the file `fbs/crypto_msg_parser.fbs:8`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(i32)]
+            pub enum MarketType {
+                /// The variant `Unknown` in the enum `MarketType`
+                Unknown = 0,
+
+                /// The variant `Spot` in the enum `MarketType`
+                Spot = 1,
+
+                /// The variant `LinearFuture` in the enum `MarketType`
+                LinearFuture = 2,
+
+                /// The variant `InverseFuture` in the enum `MarketType`
+                InverseFuture = 3,
+
+                /// The variant `LinearSwap` in the enum `MarketType`
+                LinearSwap = 4,
+
+                /// The variant `InverseSwap` in the enum `MarketType`
+                InverseSwap = 5,
+
+                /// The variant `AmericanOption` in the enum `MarketType`
+                AmericanOption = 6,
+
+                /// The variant `EuropeanOption` in the enum `MarketType`
+                EuropeanOption = 7,
+
+                /// The variant `QuantoFuture` in the enum `MarketType`
+                QuantoFuture = 8,
+
+                /// The variant `QuantoSwap` in the enum `MarketType`
+                QuantoSwap = 9,
+
+                /// The variant `Move` in the enum `MarketType`
+                Move = 10,
+
+                /// The variant `BVOL` in the enum `MarketType`
+                Bvol = 11,
+            }
+
+            impl MarketType {
+                /// Array containing all valid variants of MarketType
+                pub const ENUM_VALUES: [Self; 12] = [
+                    Self::Unknown,
+                    Self::Spot,
+                    Self::LinearFuture,
+                    Self::InverseFuture,
+                    Self::LinearSwap,
+                    Self::InverseSwap,
+                    Self::AmericanOption,
+                    Self::EuropeanOption,
+                    Self::QuantoFuture,
+                    Self::QuantoSwap,
+                    Self::Move,
+                    Self::Bvol,
+                ];
+            }
+
+            impl ::core::convert::TryFrom<i32> for MarketType {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: i32,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(MarketType::Unknown),
+                        1 => ::core::result::Result::Ok(MarketType::Spot),
+                        2 => ::core::result::Result::Ok(MarketType::LinearFuture),
+                        3 => ::core::result::Result::Ok(MarketType::InverseFuture),
+                        4 => ::core::result::Result::Ok(MarketType::LinearSwap),
+                        5 => ::core::result::Result::Ok(MarketType::InverseSwap),
+                        6 => ::core::result::Result::Ok(MarketType::AmericanOption),
+                        7 => ::core::result::Result::Ok(MarketType::EuropeanOption),
+                        8 => ::core::result::Result::Ok(MarketType::QuantoFuture),
+                        9 => ::core::result::Result::Ok(MarketType::QuantoSwap),
+                        10 => ::core::result::Result::Ok(MarketType::Move),
+                        11 => ::core::result::Result::Ok(MarketType::Bvol),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<MarketType> for i32 {
+                #[inline]
+                fn from(value: MarketType) -> Self {
+                    value as i32
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for MarketType {
+                const ALIGNMENT: usize = 4;
+                const SIZE: usize = 4;
+            }
+
+            impl ::planus::WriteAsPrimitive<MarketType> for MarketType {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as i32).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<MarketType> for MarketType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> MarketType {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<MarketType, MarketType> for MarketType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &MarketType,
+                ) -> ::core::option::Option<MarketType> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<MarketType> for MarketType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<MarketType> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for MarketType {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: i32 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for MarketType {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 4;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value =
+                        unsafe { <i32 as ::planus::VectorRead>::from_buffer(buffer, offset) };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "MarketType",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<MarketType> for MarketType {
+                const STRIDE: usize = 4;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (4 * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The enum `MessageType` in the namespace `crypto_msg_parser.fb`
+            ///
+            /// Generated from these locations:
+            /// * Enum `MessageType` in the file `fbs/crypto_msg_parser.fbs:23`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(i32)]
+            pub enum MessageType {
+                /// The variant `Other` in the enum `MessageType`
+                Other = 0,
+
+                /// The variant `Trade` in the enum `MessageType`
+                Trade = 1,
+
+                /// The variant `L2Event` in the enum `MessageType`
+                L2Event = 2,
+
+                /// The variant `L2Snapshot` in the enum `MessageType`
+                L2Snapshot = 3,
+
+                /// The variant `L2TopK` in the enum `MessageType`
+                L2TopK = 4,
+
+                /// The variant `L3Event` in the enum `MessageType`
+                L3Event = 5,
+
+                /// The variant `L3Snapshot` in the enum `MessageType`
+                L3Snapshot = 6,
+
+                /// The variant `BBO` in the enum `MessageType`
+                Bbo = 7,
+
+                /// The variant `Ticker` in the enum `MessageType`
+                Ticker = 8,
+
+                /// The variant `Candlestick` in the enum `MessageType`
+                Candlestick = 9,
+
+                /// The variant `FundingRate` in the enum `MessageType`
+                FundingRate = 10,
+
+                /// The variant `OpenInterest` in the enum `MessageType`
+                OpenInterest = 11,
+
+                /// The variant `LongShortRatio` in the enum `MessageType`
+                LongShortRatio = 12,
+
+                /// The variant `TakerVolume` in the enum `MessageType`
+                TakerVolume = 13,
+            }
+
+            impl MessageType {
+                /// Array containing all valid variants of MessageType
+                pub const ENUM_VALUES: [Self; 14] = [
+                    Self::Other,
+                    Self::Trade,
+                    Self::L2Event,
+                    Self::L2Snapshot,
+                    Self::L2TopK,
+                    Self::L3Event,
+                    Self::L3Snapshot,
+                    Self::Bbo,
+                    Self::Ticker,
+                    Self::Candlestick,
+                    Self::FundingRate,
+                    Self::OpenInterest,
+                    Self::LongShortRatio,
+                    Self::TakerVolume,
+                ];
+            }
+
+            impl ::core::convert::TryFrom<i32> for MessageType {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: i32,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(MessageType::Other),
+                        1 => ::core::result::Result::Ok(MessageType::Trade),
+                        2 => ::core::result::Result::Ok(MessageType::L2Event),
+                        3 => ::core::result::Result::Ok(MessageType::L2Snapshot),
+                        4 => ::core::result::Result::Ok(MessageType::L2TopK),
+                        5 => ::core::result::Result::Ok(MessageType::L3Event),
+                        6 => ::core::result::Result::Ok(MessageType::L3Snapshot),
+                        7 => ::core::result::Result::Ok(MessageType::Bbo),
+                        8 => ::core::result::Result::Ok(MessageType::Ticker),
+                        9 => ::core::result::Result::Ok(MessageType::Candlestick),
+                        10 => ::core::result::Result::Ok(MessageType::FundingRate),
+                        11 => ::core::result::Result::Ok(MessageType::OpenInterest),
+                        12 => ::core::result::Result::Ok(MessageType::LongShortRatio),
+                        13 => ::core::result::Result::Ok(MessageType::TakerVolume),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<MessageType> for i32 {
+                #[inline]
+                fn from(value: MessageType) -> Self {
+                    value as i32
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for MessageType {
+                const ALIGNMENT: usize = 4;
+                const SIZE: usize = 4;
+            }
+
+            impl ::planus::WriteAsPrimitive<MessageType> for MessageType {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as i32).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<MessageType> for MessageType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> MessageType {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<MessageType, MessageType> for MessageType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &MessageType,
+                ) -> ::core::option::Option<MessageType> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<MessageType> for MessageType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<MessageType> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for MessageType {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: i32 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for MessageType {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 4;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value =
+                        unsafe { <i32 as ::planus::VectorRead>::from_buffer(buffer, offset) };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "MessageType",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<MessageType> for MessageType {
+                const STRIDE: usize = 4;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (4 * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The enum `TradeSide` in the namespace `crypto_msg_parser.fb`
+            ///
+            /// Generated from these locations:
+            /// * Enum `TradeSide` in the file `fbs/crypto_msg_parser.fbs:41`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(i8)]
+            pub enum TradeSide {
+                /// The variant `Buy` in the enum `TradeSide`
+                Buy = 0,
+
+                /// The variant `Sell` in the enum `TradeSide`
+                Sell = 1,
+            }
+
+            impl TradeSide {
+                /// Array containing all valid variants of TradeSide
+                pub const ENUM_VALUES: [Self; 2] = [Self::Buy, Self::Sell];
+            }
+
+            impl ::core::convert::TryFrom<i8> for TradeSide {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: i8,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(TradeSide::Buy),
+                        1 => ::core::result::Result::Ok(TradeSide::Sell),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<TradeSide> for i8 {
+                #[inline]
+                fn from(value: TradeSide) -> Self {
+                    value as i8
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for TradeSide {
+                const ALIGNMENT: usize = 1;
+                const SIZE: usize = 1;
+            }
+
+            impl ::planus::WriteAsPrimitive<TradeSide> for TradeSide {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as i8).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<TradeSide> for TradeSide {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> TradeSide {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<TradeSide, TradeSide> for TradeSide {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &TradeSide,
+                ) -> ::core::option::Option<TradeSide> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<TradeSide> for TradeSide {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<TradeSide> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for TradeSide {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: i8 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for TradeSide {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 1;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value = unsafe { *buffer.buffer.get_unchecked(offset) as i8 };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "TradeSide",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<TradeSide> for TradeSide {
+                const STRIDE: usize = 1;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 1];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - i as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The table `Trade` in the namespace `crypto_msg_parser.fb`
+            ///
+            /// Generated from these locations:
+            /// * Table `Trade` in the file `fbs/crypto_msg_parser.fbs:47`
+            #[derive(
+                Clone, Debug, PartialEq, PartialOrd, ::serde::Serialize, ::serde::Deserialize,
+            )]
+            pub struct Trade {
+                /// The field `exchange` in the table `Trade`
+                pub exchange: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `market_type` in the table `Trade`
+                pub market_type: self::MarketType,
+                /// The field `msg_type` in the table `Trade`
+                pub msg_type: self::MessageType,
+                /// The field `pair` in the table `Trade`
+                pub pair: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `symbol` in the table `Trade`
+                pub symbol: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `timestamp` in the table `Trade`
+                pub timestamp: i64,
+                /// The field `side` in the table `Trade`
+                pub side: self::TradeSide,
+                /// The field `price` in the table `Trade`
+                pub price: f64,
+                /// The field `quantity_base` in the table `Trade`
+                pub quantity_base: f64,
+                /// The field `quantity_quote` in the table `Trade`
+                pub quantity_quote: f64,
+                /// The field `quantity_contract` in the table `Trade`
+                pub quantity_contract: ::core::option::Option<f64>,
+                /// The field `trade_id` in the table `Trade`
+                pub trade_id: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `json` in the table `Trade`
+                pub json: ::core::option::Option<::planus::alloc::string::String>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for Trade {
+                fn default() -> Self {
+                    Self {
+                        exchange: ::core::default::Default::default(),
+                        market_type: self::MarketType::Unknown,
+                        msg_type: self::MessageType::Other,
+                        pair: ::core::default::Default::default(),
+                        symbol: ::core::default::Default::default(),
+                        timestamp: 0,
+                        side: self::TradeSide::Buy,
+                        price: 0.0,
+                        quantity_base: 0.0,
+                        quantity_quote: 0.0,
+                        quantity_contract: ::core::default::Default::default(),
+                        trade_id: ::core::default::Default::default(),
+                        json: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl Trade {
+                /// Creates a [TradeBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> TradeBuilder<()> {
+                    TradeBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_exchange: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_market_type: impl ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    field_msg_type: impl ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    field_pair: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    field_symbol: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_timestamp: impl ::planus::WriteAsDefault<i64, i64>,
+                    field_side: impl ::planus::WriteAsDefault<self::TradeSide, self::TradeSide>,
+                    field_price: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_quantity_base: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_quantity_quote: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_quantity_contract: impl ::planus::WriteAsOptional<f64>,
+                    field_trade_id: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_json: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_exchange = field_exchange.prepare(builder);
+                    let prepared_market_type =
+                        field_market_type.prepare(builder, &self::MarketType::Unknown);
+                    let prepared_msg_type =
+                        field_msg_type.prepare(builder, &self::MessageType::Other);
+                    let prepared_pair = field_pair.prepare(builder);
+                    let prepared_symbol = field_symbol.prepare(builder);
+                    let prepared_timestamp = field_timestamp.prepare(builder, &0);
+                    let prepared_side = field_side.prepare(builder, &self::TradeSide::Buy);
+                    let prepared_price = field_price.prepare(builder, &0.0);
+                    let prepared_quantity_base = field_quantity_base.prepare(builder, &0.0);
+                    let prepared_quantity_quote = field_quantity_quote.prepare(builder, &0.0);
+                    let prepared_quantity_contract = field_quantity_contract.prepare(builder);
+                    let prepared_trade_id = field_trade_id.prepare(builder);
+                    let prepared_json = field_json.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<30> =
+                        ::core::default::Default::default();
+                    if prepared_timestamp.is_some() {
+                        table_writer.write_entry::<i64>(5);
+                    }
+                    if prepared_price.is_some() {
+                        table_writer.write_entry::<f64>(7);
+                    }
+                    if prepared_quantity_base.is_some() {
+                        table_writer.write_entry::<f64>(8);
+                    }
+                    if prepared_quantity_quote.is_some() {
+                        table_writer.write_entry::<f64>(9);
+                    }
+                    if prepared_quantity_contract.is_some() {
+                        table_writer.write_entry::<f64>(10);
+                    }
+                    if prepared_exchange.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(0);
+                    }
+                    if prepared_market_type.is_some() {
+                        table_writer.write_entry::<self::MarketType>(1);
+                    }
+                    if prepared_msg_type.is_some() {
+                        table_writer.write_entry::<self::MessageType>(2);
+                    }
+                    if prepared_pair.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(3);
+                    }
+                    if prepared_symbol.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(4);
+                    }
+                    if prepared_trade_id.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(11);
+                    }
+                    if prepared_json.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(12);
+                    }
+                    if prepared_side.is_some() {
+                        table_writer.write_entry::<self::TradeSide>(6);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            if let ::core::option::Option::Some(prepared_timestamp) =
+                                prepared_timestamp
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_timestamp);
+                            }
+                            if let ::core::option::Option::Some(prepared_price) = prepared_price {
+                                object_writer.write::<_, _, 8>(&prepared_price);
+                            }
+                            if let ::core::option::Option::Some(prepared_quantity_base) =
+                                prepared_quantity_base
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_quantity_base);
+                            }
+                            if let ::core::option::Option::Some(prepared_quantity_quote) =
+                                prepared_quantity_quote
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_quantity_quote);
+                            }
+                            if let ::core::option::Option::Some(prepared_quantity_contract) =
+                                prepared_quantity_contract
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_quantity_contract);
+                            }
+                            if let ::core::option::Option::Some(prepared_exchange) =
+                                prepared_exchange
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_exchange);
+                            }
+                            if let ::core::option::Option::Some(prepared_market_type) =
+                                prepared_market_type
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_market_type);
+                            }
+                            if let ::core::option::Option::Some(prepared_msg_type) =
+                                prepared_msg_type
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_msg_type);
+                            }
+                            if let ::core::option::Option::Some(prepared_pair) = prepared_pair {
+                                object_writer.write::<_, _, 4>(&prepared_pair);
+                            }
+                            if let ::core::option::Option::Some(prepared_symbol) = prepared_symbol {
+                                object_writer.write::<_, _, 4>(&prepared_symbol);
+                            }
+                            if let ::core::option::Option::Some(prepared_trade_id) =
+                                prepared_trade_id
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_trade_id);
+                            }
+                            if let ::core::option::Option::Some(prepared_json) = prepared_json {
+                                object_writer.write::<_, _, 4>(&prepared_json);
+                            }
+                            if let ::core::option::Option::Some(prepared_side) = prepared_side {
+                                object_writer.write::<_, _, 1>(&prepared_side);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<Trade>> for Trade {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Trade> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<Trade>> for Trade {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Trade>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Trade> for Trade {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Trade> {
+                    Trade::create(
+                        builder,
+                        &self.exchange,
+                        self.market_type,
+                        self.msg_type,
+                        &self.pair,
+                        &self.symbol,
+                        self.timestamp,
+                        self.side,
+                        self.price,
+                        self.quantity_base,
+                        self.quantity_quote,
+                        self.quantity_contract,
+                        &self.trade_id,
+                        &self.json,
+                    )
+                }
+            }
+
+            /// Builder for serializing an instance of the [Trade] type.
+            ///
+            /// Can be created using the [Trade::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct TradeBuilder<State>(State);
+
+            impl TradeBuilder<()> {
+                /// Setter for the [`exchange` field](Trade#structfield.exchange).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn exchange<T0>(self, value: T0) -> TradeBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    TradeBuilder((value,))
+                }
+
+                /// Sets the [`exchange` field](Trade#structfield.exchange) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn exchange_as_null(self) -> TradeBuilder<((),)> {
+                    self.exchange(())
+                }
+            }
+
+            impl<T0> TradeBuilder<(T0,)> {
+                /// Setter for the [`market_type` field](Trade#structfield.market_type).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn market_type<T1>(self, value: T1) -> TradeBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                {
+                    let (v0,) = self.0;
+                    TradeBuilder((v0, value))
+                }
+
+                /// Sets the [`market_type` field](Trade#structfield.market_type) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn market_type_as_default(self) -> TradeBuilder<(T0, ::planus::DefaultValue)> {
+                    self.market_type(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1> TradeBuilder<(T0, T1)> {
+                /// Setter for the [`msg_type` field](Trade#structfield.msg_type).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn msg_type<T2>(self, value: T2) -> TradeBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                {
+                    let (v0, v1) = self.0;
+                    TradeBuilder((v0, v1, value))
+                }
+
+                /// Sets the [`msg_type` field](Trade#structfield.msg_type) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn msg_type_as_default(self) -> TradeBuilder<(T0, T1, ::planus::DefaultValue)> {
+                    self.msg_type(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2> TradeBuilder<(T0, T1, T2)> {
+                /// Setter for the [`pair` field](Trade#structfield.pair).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn pair<T3>(self, value: T3) -> TradeBuilder<(T0, T1, T2, T3)>
+                where
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2) = self.0;
+                    TradeBuilder((v0, v1, v2, value))
+                }
+
+                /// Sets the [`pair` field](Trade#structfield.pair) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn pair_as_null(self) -> TradeBuilder<(T0, T1, T2, ())> {
+                    self.pair(())
+                }
+            }
+
+            impl<T0, T1, T2, T3> TradeBuilder<(T0, T1, T2, T3)> {
+                /// Setter for the [`symbol` field](Trade#structfield.symbol).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol<T4>(self, value: T4) -> TradeBuilder<(T0, T1, T2, T3, T4)>
+                where
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, value))
+                }
+
+                /// Sets the [`symbol` field](Trade#structfield.symbol) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol_as_null(self) -> TradeBuilder<(T0, T1, T2, T3, ())> {
+                    self.symbol(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4> TradeBuilder<(T0, T1, T2, T3, T4)> {
+                /// Setter for the [`timestamp` field](Trade#structfield.timestamp).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn timestamp<T5>(self, value: T5) -> TradeBuilder<(T0, T1, T2, T3, T4, T5)>
+                where
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                {
+                    let (v0, v1, v2, v3, v4) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, v4, value))
+                }
+
+                /// Sets the [`timestamp` field](Trade#structfield.timestamp) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn timestamp_as_default(
+                    self,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, ::planus::DefaultValue)> {
+                    self.timestamp(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5> TradeBuilder<(T0, T1, T2, T3, T4, T5)> {
+                /// Setter for the [`side` field](Trade#structfield.side).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn side<T6>(self, value: T6) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6)>
+                where
+                    T6: ::planus::WriteAsDefault<self::TradeSide, self::TradeSide>,
+                {
+                    let (v0, v1, v2, v3, v4, v5) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, v4, v5, value))
+                }
+
+                /// Sets the [`side` field](Trade#structfield.side) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn side_as_default(
+                    self,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, ::planus::DefaultValue)>
+                {
+                    self.side(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6)> {
+                /// Setter for the [`price` field](Trade#structfield.price).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn price<T7>(self, value: T7) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7)>
+                where
+                    T7: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, v4, v5, v6, value))
+                }
+
+                /// Sets the [`price` field](Trade#structfield.price) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn price_as_default(
+                    self,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, ::planus::DefaultValue)>
+                {
+                    self.price(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7)> {
+                /// Setter for the [`quantity_base` field](Trade#structfield.quantity_base).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_base<T8>(
+                    self,
+                    value: T8,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8)>
+                where
+                    T8: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, v4, v5, v6, v7, value))
+                }
+
+                /// Sets the [`quantity_base` field](Trade#structfield.quantity_base) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_base_as_default(
+                    self,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, ::planus::DefaultValue)>
+                {
+                    self.quantity_base(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8)> {
+                /// Setter for the [`quantity_quote` field](Trade#structfield.quantity_quote).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_quote<T9>(
+                    self,
+                    value: T9,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)>
+                where
+                    T9: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, value))
+                }
+
+                /// Sets the [`quantity_quote` field](Trade#structfield.quantity_quote) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_quote_as_default(
+                    self,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, ::planus::DefaultValue)>
+                {
+                    self.quantity_quote(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9>
+                TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)>
+            {
+                /// Setter for the [`quantity_contract` field](Trade#structfield.quantity_contract).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_contract<T10>(
+                    self,
+                    value: T10,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)>
+                where
+                    T10: ::planus::WriteAsOptional<f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, value))
+                }
+
+                /// Sets the [`quantity_contract` field](Trade#structfield.quantity_contract) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_contract_as_null(
+                    self,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, ())> {
+                    self.quantity_contract(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10>
+                TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)>
+            {
+                /// Setter for the [`trade_id` field](Trade#structfield.trade_id).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn trade_id<T11>(
+                    self,
+                    value: T11,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+                where
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, value))
+                }
+
+                /// Sets the [`trade_id` field](Trade#structfield.trade_id) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn trade_id_as_null(
+                    self,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, ())>
+                {
+                    self.trade_id(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>
+                TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                /// Setter for the [`json` field](Trade#structfield.json).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn json<T12>(
+                    self,
+                    value: T12,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12)>
+                where
+                    T12: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11) = self.0;
+                    TradeBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, value))
+                }
+
+                /// Sets the [`json` field](Trade#structfield.json) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn json_as_null(
+                    self,
+                ) -> TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, ())>
+                {
+                    self.json(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12>
+                TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12)>
+            {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Trade].
+                #[inline]
+                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Trade>
+                where
+                    Self: ::planus::WriteAsOffset<Trade>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<self::TradeSide, self::TradeSide>,
+                    T7: ::planus::WriteAsDefault<f64, f64>,
+                    T8: ::planus::WriteAsDefault<f64, f64>,
+                    T9: ::planus::WriteAsDefault<f64, f64>,
+                    T10: ::planus::WriteAsOptional<f64>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T12: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAs<::planus::Offset<Trade>>
+                for TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12)>
+            {
+                type Prepared = ::planus::Offset<Trade>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Trade> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<self::TradeSide, self::TradeSide>,
+                    T7: ::planus::WriteAsDefault<f64, f64>,
+                    T8: ::planus::WriteAsDefault<f64, f64>,
+                    T9: ::planus::WriteAsDefault<f64, f64>,
+                    T10: ::planus::WriteAsOptional<f64>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T12: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAsOptional<::planus::Offset<Trade>>
+                for TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12)>
+            {
+                type Prepared = ::planus::Offset<Trade>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Trade>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<self::TradeSide, self::TradeSide>,
+                    T7: ::planus::WriteAsDefault<f64, f64>,
+                    T8: ::planus::WriteAsDefault<f64, f64>,
+                    T9: ::planus::WriteAsDefault<f64, f64>,
+                    T10: ::planus::WriteAsOptional<f64>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T12: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAsOffset<Trade>
+                for TradeBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12)>
+            {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Trade> {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12) = &self.0;
+                    Trade::create(
+                        builder, v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12,
+                    )
+                }
+            }
+
+            /// Reference to a deserialized [Trade].
+            #[derive(Copy, Clone)]
+            pub struct TradeRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> TradeRef<'a> {
+                /// Getter for the [`exchange` field](Trade#structfield.exchange).
+                #[inline]
+                pub fn exchange(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(0, "Trade", "exchange")
+                }
+
+                /// Getter for the [`market_type` field](Trade#structfield.market_type).
+                #[inline]
+                pub fn market_type(&self) -> ::planus::Result<self::MarketType> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(1, "Trade", "market_type")?
+                            .unwrap_or(self::MarketType::Unknown),
+                    )
+                }
+
+                /// Getter for the [`msg_type` field](Trade#structfield.msg_type).
+                #[inline]
+                pub fn msg_type(&self) -> ::planus::Result<self::MessageType> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(2, "Trade", "msg_type")?
+                            .unwrap_or(self::MessageType::Other),
+                    )
+                }
+
+                /// Getter for the [`pair` field](Trade#structfield.pair).
+                #[inline]
+                pub fn pair(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(3, "Trade", "pair")
+                }
+
+                /// Getter for the [`symbol` field](Trade#structfield.symbol).
+                #[inline]
+                pub fn symbol(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(4, "Trade", "symbol")
+                }
+
+                /// Getter for the [`timestamp` field](Trade#structfield.timestamp).
+                #[inline]
+                pub fn timestamp(&self) -> ::planus::Result<i64> {
+                    ::core::result::Result::Ok(self.0.access(5, "Trade", "timestamp")?.unwrap_or(0))
+                }
+
+                /// Getter for the [`side` field](Trade#structfield.side).
+                #[inline]
+                pub fn side(&self) -> ::planus::Result<self::TradeSide> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(6, "Trade", "side")?
+                            .unwrap_or(self::TradeSide::Buy),
+                    )
+                }
+
+                /// Getter for the [`price` field](Trade#structfield.price).
+                #[inline]
+                pub fn price(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(self.0.access(7, "Trade", "price")?.unwrap_or(0.0))
+                }
+
+                /// Getter for the [`quantity_base` field](Trade#structfield.quantity_base).
+                #[inline]
+                pub fn quantity_base(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0.access(8, "Trade", "quantity_base")?.unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`quantity_quote` field](Trade#structfield.quantity_quote).
+                #[inline]
+                pub fn quantity_quote(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0.access(9, "Trade", "quantity_quote")?.unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`quantity_contract` field](Trade#structfield.quantity_contract).
+                #[inline]
+                pub fn quantity_contract(&self) -> ::planus::Result<::core::option::Option<f64>> {
+                    self.0.access(10, "Trade", "quantity_contract")
+                }
+
+                /// Getter for the [`trade_id` field](Trade#structfield.trade_id).
+                #[inline]
+                pub fn trade_id(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(11, "Trade", "trade_id")
+                }
+
+                /// Getter for the [`json` field](Trade#structfield.json).
+                #[inline]
+                pub fn json(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(12, "Trade", "json")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for TradeRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("TradeRef");
+                    if let ::core::option::Option::Some(field_exchange) =
+                        self.exchange().transpose()
+                    {
+                        f.field("exchange", &field_exchange);
+                    }
+                    f.field("market_type", &self.market_type());
+                    f.field("msg_type", &self.msg_type());
+                    if let ::core::option::Option::Some(field_pair) = self.pair().transpose() {
+                        f.field("pair", &field_pair);
+                    }
+                    if let ::core::option::Option::Some(field_symbol) = self.symbol().transpose() {
+                        f.field("symbol", &field_symbol);
+                    }
+                    f.field("timestamp", &self.timestamp());
+                    f.field("side", &self.side());
+                    f.field("price", &self.price());
+                    f.field("quantity_base", &self.quantity_base());
+                    f.field("quantity_quote", &self.quantity_quote());
+                    if let ::core::option::Option::Some(field_quantity_contract) =
+                        self.quantity_contract().transpose()
+                    {
+                        f.field("quantity_contract", &field_quantity_contract);
+                    }
+                    if let ::core::option::Option::Some(field_trade_id) =
+                        self.trade_id().transpose()
+                    {
+                        f.field("trade_id", &field_trade_id);
+                    }
+                    if let ::core::option::Option::Some(field_json) = self.json().transpose() {
+                        f.field("json", &field_json);
+                    }
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<TradeRef<'a>> for Trade {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: TradeRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        exchange: value.exchange()?.map(::core::convert::Into::into),
+                        market_type: ::core::convert::TryInto::try_into(value.market_type()?)?,
+                        msg_type: ::core::convert::TryInto::try_into(value.msg_type()?)?,
+                        pair: value.pair()?.map(::core::convert::Into::into),
+                        symbol: value.symbol()?.map(::core::convert::Into::into),
+                        timestamp: ::core::convert::TryInto::try_into(value.timestamp()?)?,
+                        side: ::core::convert::TryInto::try_into(value.side()?)?,
+                        price: ::core::convert::TryInto::try_into(value.price()?)?,
+                        quantity_base: ::core::convert::TryInto::try_into(value.quantity_base()?)?,
+                        quantity_quote: ::core::convert::TryInto::try_into(
+                            value.quantity_quote()?,
+                        )?,
+                        quantity_contract: if let ::core::option::Option::Some(quantity_contract) =
+                            value.quantity_contract()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                quantity_contract,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        trade_id: value.trade_id()?.map(::core::convert::Into::into),
+                        json: value.json()?.map(::core::convert::Into::into),
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for TradeRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for TradeRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[TradeRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<Trade>> for Trade {
+                type Value = ::planus::Offset<Trade>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Trade>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for TradeRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[TradeRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `Order` in the namespace `crypto_msg_parser.fb`
+            ///
+            /// Generated from these locations:
+            /// * Table `Order` in the file `fbs/crypto_msg_parser.fbs:66`
+            #[derive(
+                Clone, Debug, PartialEq, PartialOrd, ::serde::Serialize, ::serde::Deserialize,
+            )]
+            pub struct Order {
+                /// The field `price` in the table `Order`
+                pub price: f64,
+                /// The field `quantity_base` in the table `Order`
+                pub quantity_base: f64,
+                /// The field `quantity_quote` in the table `Order`
+                pub quantity_quote: f64,
+                /// The field `quantity_contract` in the table `Order`
+                pub quantity_contract: ::core::option::Option<f64>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for Order {
+                fn default() -> Self {
+                    Self {
+                        price: 0.0,
+                        quantity_base: 0.0,
+                        quantity_quote: 0.0,
+                        quantity_contract: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl Order {
+                /// Creates a [OrderBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> OrderBuilder<()> {
+                    OrderBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_price: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_quantity_base: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_quantity_quote: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_quantity_contract: impl ::planus::WriteAsOptional<f64>,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_price = field_price.prepare(builder, &0.0);
+                    let prepared_quantity_base = field_quantity_base.prepare(builder, &0.0);
+                    let prepared_quantity_quote = field_quantity_quote.prepare(builder, &0.0);
+                    let prepared_quantity_contract = field_quantity_contract.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<12> =
+                        ::core::default::Default::default();
+                    if prepared_price.is_some() {
+                        table_writer.write_entry::<f64>(0);
+                    }
+                    if prepared_quantity_base.is_some() {
+                        table_writer.write_entry::<f64>(1);
+                    }
+                    if prepared_quantity_quote.is_some() {
+                        table_writer.write_entry::<f64>(2);
+                    }
+                    if prepared_quantity_contract.is_some() {
+                        table_writer.write_entry::<f64>(3);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            if let ::core::option::Option::Some(prepared_price) = prepared_price {
+                                object_writer.write::<_, _, 8>(&prepared_price);
+                            }
+                            if let ::core::option::Option::Some(prepared_quantity_base) =
+                                prepared_quantity_base
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_quantity_base);
+                            }
+                            if let ::core::option::Option::Some(prepared_quantity_quote) =
+                                prepared_quantity_quote
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_quantity_quote);
+                            }
+                            if let ::core::option::Option::Some(prepared_quantity_contract) =
+                                prepared_quantity_contract
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_quantity_contract);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<Order>> for Order {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Order> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<Order>> for Order {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Order>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Order> for Order {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Order> {
+                    Order::create(
+                        builder,
+                        self.price,
+                        self.quantity_base,
+                        self.quantity_quote,
+                        self.quantity_contract,
+                    )
+                }
+            }
+
+            /// Builder for serializing an instance of the [Order] type.
+            ///
+            /// Can be created using the [Order::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct OrderBuilder<State>(State);
+
+            impl OrderBuilder<()> {
+                /// Setter for the [`price` field](Order#structfield.price).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn price<T0>(self, value: T0) -> OrderBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    OrderBuilder((value,))
+                }
+
+                /// Sets the [`price` field](Order#structfield.price) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn price_as_default(self) -> OrderBuilder<(::planus::DefaultValue,)> {
+                    self.price(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0> OrderBuilder<(T0,)> {
+                /// Setter for the [`quantity_base` field](Order#structfield.quantity_base).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_base<T1>(self, value: T1) -> OrderBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0,) = self.0;
+                    OrderBuilder((v0, value))
+                }
+
+                /// Sets the [`quantity_base` field](Order#structfield.quantity_base) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_base_as_default(
+                    self,
+                ) -> OrderBuilder<(T0, ::planus::DefaultValue)> {
+                    self.quantity_base(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1> OrderBuilder<(T0, T1)> {
+                /// Setter for the [`quantity_quote` field](Order#structfield.quantity_quote).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_quote<T2>(self, value: T2) -> OrderBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1) = self.0;
+                    OrderBuilder((v0, v1, value))
+                }
+
+                /// Sets the [`quantity_quote` field](Order#structfield.quantity_quote) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_quote_as_default(
+                    self,
+                ) -> OrderBuilder<(T0, T1, ::planus::DefaultValue)> {
+                    self.quantity_quote(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2> OrderBuilder<(T0, T1, T2)> {
+                /// Setter for the [`quantity_contract` field](Order#structfield.quantity_contract).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_contract<T3>(self, value: T3) -> OrderBuilder<(T0, T1, T2, T3)>
+                where
+                    T3: ::planus::WriteAsOptional<f64>,
+                {
+                    let (v0, v1, v2) = self.0;
+                    OrderBuilder((v0, v1, v2, value))
+                }
+
+                /// Sets the [`quantity_contract` field](Order#structfield.quantity_contract) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn quantity_contract_as_null(self) -> OrderBuilder<(T0, T1, T2, ())> {
+                    self.quantity_contract(())
+                }
+            }
+
+            impl<T0, T1, T2, T3> OrderBuilder<(T0, T1, T2, T3)> {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Order].
+                #[inline]
+                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Order>
+                where
+                    Self: ::planus::WriteAsOffset<Order>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<f64, f64>,
+                    T1: ::planus::WriteAsDefault<f64, f64>,
+                    T2: ::planus::WriteAsDefault<f64, f64>,
+                    T3: ::planus::WriteAsOptional<f64>,
+                > ::planus::WriteAs<::planus::Offset<Order>> for OrderBuilder<(T0, T1, T2, T3)>
+            {
+                type Prepared = ::planus::Offset<Order>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Order> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<f64, f64>,
+                    T1: ::planus::WriteAsDefault<f64, f64>,
+                    T2: ::planus::WriteAsDefault<f64, f64>,
+                    T3: ::planus::WriteAsOptional<f64>,
+                > ::planus::WriteAsOptional<::planus::Offset<Order>>
+                for OrderBuilder<(T0, T1, T2, T3)>
+            {
+                type Prepared = ::planus::Offset<Order>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Order>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<f64, f64>,
+                    T1: ::planus::WriteAsDefault<f64, f64>,
+                    T2: ::planus::WriteAsDefault<f64, f64>,
+                    T3: ::planus::WriteAsOptional<f64>,
+                > ::planus::WriteAsOffset<Order> for OrderBuilder<(T0, T1, T2, T3)>
+            {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Order> {
+                    let (v0, v1, v2, v3) = &self.0;
+                    Order::create(builder, v0, v1, v2, v3)
+                }
+            }
+
+            /// Reference to a deserialized [Order].
+            #[derive(Copy, Clone)]
+            pub struct OrderRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> OrderRef<'a> {
+                /// Getter for the [`price` field](Order#structfield.price).
+                #[inline]
+                pub fn price(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(self.0.access(0, "Order", "price")?.unwrap_or(0.0))
+                }
+
+                /// Getter for the [`quantity_base` field](Order#structfield.quantity_base).
+                #[inline]
+                pub fn quantity_base(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0.access(1, "Order", "quantity_base")?.unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`quantity_quote` field](Order#structfield.quantity_quote).
+                #[inline]
+                pub fn quantity_quote(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0.access(2, "Order", "quantity_quote")?.unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`quantity_contract` field](Order#structfield.quantity_contract).
+                #[inline]
+                pub fn quantity_contract(&self) -> ::planus::Result<::core::option::Option<f64>> {
+                    self.0.access(3, "Order", "quantity_contract")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for OrderRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("OrderRef");
+                    f.field("price", &self.price());
+                    f.field("quantity_base", &self.quantity_base());
+                    f.field("quantity_quote", &self.quantity_quote());
+                    if let ::core::option::Option::Some(field_quantity_contract) =
+                        self.quantity_contract().transpose()
+                    {
+                        f.field("quantity_contract", &field_quantity_contract);
+                    }
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<OrderRef<'a>> for Order {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: OrderRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        price: ::core::convert::TryInto::try_into(value.price()?)?,
+                        quantity_base: ::core::convert::TryInto::try_into(value.quantity_base()?)?,
+                        quantity_quote: ::core::convert::TryInto::try_into(
+                            value.quantity_quote()?,
+                        )?,
+                        quantity_contract: if let ::core::option::Option::Some(quantity_contract) =
+                            value.quantity_contract()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                quantity_contract,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for OrderRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for OrderRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[OrderRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<Order>> for Order {
+                type Value = ::planus::Offset<Order>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Order>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for OrderRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[OrderRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `OrderBook` in the namespace `crypto_msg_parser.fb`
+            ///
+            /// Generated from these locations:
+            /// * Table `OrderBook` in the file `fbs/crypto_msg_parser.fbs:76`
+            #[derive(
+                Clone, Debug, PartialEq, PartialOrd, ::serde::Serialize, ::serde::Deserialize,
+            )]
+            pub struct OrderBook {
+                /// The field `exchange` in the table `OrderBook`
+                pub exchange: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `market_type` in the table `OrderBook`
+                pub market_type: self::MarketType,
+                /// The field `msg_type` in the table `OrderBook`
+                pub msg_type: self::MessageType,
+                /// The field `pair` in the table `OrderBook`
+                pub pair: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `symbol` in the table `OrderBook`
+                pub symbol: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `timestamp` in the table `OrderBook`
+                pub timestamp: i64,
+                /// The field `snapshot` in the table `OrderBook`
+                pub snapshot: bool,
+                /// The field `asks` in the table `OrderBook`
+                pub asks: ::core::option::Option<::planus::alloc::vec::Vec<self::Order>>,
+                /// The field `bids` in the table `OrderBook`
+                pub bids: ::core::option::Option<::planus::alloc::vec::Vec<self::Order>>,
+                /// The field `seq_id` in the table `OrderBook`
+                pub seq_id: ::core::option::Option<u64>,
+                /// The field `prev_seq_id` in the table `OrderBook`
+                pub prev_seq_id: ::core::option::Option<u64>,
+                /// The field `json` in the table `OrderBook`
+                pub json: ::core::option::Option<::planus::alloc::string::String>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for OrderBook {
+                fn default() -> Self {
+                    Self {
+                        exchange: ::core::default::Default::default(),
+                        market_type: self::MarketType::Unknown,
+                        msg_type: self::MessageType::Other,
+                        pair: ::core::default::Default::default(),
+                        symbol: ::core::default::Default::default(),
+                        timestamp: 0,
+                        snapshot: false,
+                        asks: ::core::default::Default::default(),
+                        bids: ::core::default::Default::default(),
+                        seq_id: ::core::default::Default::default(),
+                        prev_seq_id: ::core::default::Default::default(),
+                        json: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl OrderBook {
+                /// Creates a [OrderBookBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> OrderBookBuilder<()> {
+                    OrderBookBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_exchange: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_market_type: impl ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    field_msg_type: impl ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    field_pair: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    field_symbol: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_timestamp: impl ::planus::WriteAsDefault<i64, i64>,
+                    field_snapshot: impl ::planus::WriteAsDefault<bool, bool>,
+                    field_asks: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::Order>]>,
+                    >,
+                    field_bids: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::Order>]>,
+                    >,
+                    field_seq_id: impl ::planus::WriteAsOptional<u64>,
+                    field_prev_seq_id: impl ::planus::WriteAsOptional<u64>,
+                    field_json: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_exchange = field_exchange.prepare(builder);
+                    let prepared_market_type =
+                        field_market_type.prepare(builder, &self::MarketType::Unknown);
+                    let prepared_msg_type =
+                        field_msg_type.prepare(builder, &self::MessageType::Other);
+                    let prepared_pair = field_pair.prepare(builder);
+                    let prepared_symbol = field_symbol.prepare(builder);
+                    let prepared_timestamp = field_timestamp.prepare(builder, &0);
+                    let prepared_snapshot = field_snapshot.prepare(builder, &false);
+                    let prepared_asks = field_asks.prepare(builder);
+                    let prepared_bids = field_bids.prepare(builder);
+                    let prepared_seq_id = field_seq_id.prepare(builder);
+                    let prepared_prev_seq_id = field_prev_seq_id.prepare(builder);
+                    let prepared_json = field_json.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<28> =
+                        ::core::default::Default::default();
+                    if prepared_timestamp.is_some() {
+                        table_writer.write_entry::<i64>(5);
+                    }
+                    if prepared_seq_id.is_some() {
+                        table_writer.write_entry::<u64>(9);
+                    }
+                    if prepared_prev_seq_id.is_some() {
+                        table_writer.write_entry::<u64>(10);
+                    }
+                    if prepared_exchange.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(0);
+                    }
+                    if prepared_market_type.is_some() {
+                        table_writer.write_entry::<self::MarketType>(1);
+                    }
+                    if prepared_msg_type.is_some() {
+                        table_writer.write_entry::<self::MessageType>(2);
+                    }
+                    if prepared_pair.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(3);
+                    }
+                    if prepared_symbol.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(4);
+                    }
+                    if prepared_asks.is_some() {
+                        table_writer
+                            .write_entry::<::planus::Offset<[::planus::Offset<self::Order>]>>(7);
+                    }
+                    if prepared_bids.is_some() {
+                        table_writer
+                            .write_entry::<::planus::Offset<[::planus::Offset<self::Order>]>>(8);
+                    }
+                    if prepared_json.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(11);
+                    }
+                    if prepared_snapshot.is_some() {
+                        table_writer.write_entry::<bool>(6);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            if let ::core::option::Option::Some(prepared_timestamp) =
+                                prepared_timestamp
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_timestamp);
+                            }
+                            if let ::core::option::Option::Some(prepared_seq_id) = prepared_seq_id {
+                                object_writer.write::<_, _, 8>(&prepared_seq_id);
+                            }
+                            if let ::core::option::Option::Some(prepared_prev_seq_id) =
+                                prepared_prev_seq_id
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_prev_seq_id);
+                            }
+                            if let ::core::option::Option::Some(prepared_exchange) =
+                                prepared_exchange
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_exchange);
+                            }
+                            if let ::core::option::Option::Some(prepared_market_type) =
+                                prepared_market_type
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_market_type);
+                            }
+                            if let ::core::option::Option::Some(prepared_msg_type) =
+                                prepared_msg_type
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_msg_type);
+                            }
+                            if let ::core::option::Option::Some(prepared_pair) = prepared_pair {
+                                object_writer.write::<_, _, 4>(&prepared_pair);
+                            }
+                            if let ::core::option::Option::Some(prepared_symbol) = prepared_symbol {
+                                object_writer.write::<_, _, 4>(&prepared_symbol);
+                            }
+                            if let ::core::option::Option::Some(prepared_asks) = prepared_asks {
+                                object_writer.write::<_, _, 4>(&prepared_asks);
+                            }
+                            if let ::core::option::Option::Some(prepared_bids) = prepared_bids {
+                                object_writer.write::<_, _, 4>(&prepared_bids);
+                            }
+                            if let ::core::option::Option::Some(prepared_json) = prepared_json {
+                                object_writer.write::<_, _, 4>(&prepared_json);
+                            }
+                            if let ::core::option::Option::Some(prepared_snapshot) =
+                                prepared_snapshot
+                            {
+                                object_writer.write::<_, _, 1>(&prepared_snapshot);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<OrderBook>> for OrderBook {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<OrderBook> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<OrderBook>> for OrderBook {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<OrderBook>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<OrderBook> for OrderBook {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<OrderBook> {
+                    OrderBook::create(
+                        builder,
+                        &self.exchange,
+                        self.market_type,
+                        self.msg_type,
+                        &self.pair,
+                        &self.symbol,
+                        self.timestamp,
+                        self.snapshot,
+                        &self.asks,
+                        &self.bids,
+                        self.seq_id,
+                        self.prev_seq_id,
+                        &self.json,
+                    )
+                }
+            }
+
+            /// Builder for serializing an instance of the [OrderBook] type.
+            ///
+            /// Can be created using the [OrderBook::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct OrderBookBuilder<State>(State);
+
+            impl OrderBookBuilder<()> {
+                /// Setter for the [`exchange` field](OrderBook#structfield.exchange).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn exchange<T0>(self, value: T0) -> OrderBookBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    OrderBookBuilder((value,))
+                }
+
+                /// Sets the [`exchange` field](OrderBook#structfield.exchange) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn exchange_as_null(self) -> OrderBookBuilder<((),)> {
+                    self.exchange(())
+                }
+            }
+
+            impl<T0> OrderBookBuilder<(T0,)> {
+                /// Setter for the [`market_type` field](OrderBook#structfield.market_type).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn market_type<T1>(self, value: T1) -> OrderBookBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                {
+                    let (v0,) = self.0;
+                    OrderBookBuilder((v0, value))
+                }
+
+                /// Sets the [`market_type` field](OrderBook#structfield.market_type) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn market_type_as_default(
+                    self,
+                ) -> OrderBookBuilder<(T0, ::planus::DefaultValue)> {
+                    self.market_type(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1> OrderBookBuilder<(T0, T1)> {
+                /// Setter for the [`msg_type` field](OrderBook#structfield.msg_type).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn msg_type<T2>(self, value: T2) -> OrderBookBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                {
+                    let (v0, v1) = self.0;
+                    OrderBookBuilder((v0, v1, value))
+                }
+
+                /// Sets the [`msg_type` field](OrderBook#structfield.msg_type) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn msg_type_as_default(
+                    self,
+                ) -> OrderBookBuilder<(T0, T1, ::planus::DefaultValue)> {
+                    self.msg_type(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2> OrderBookBuilder<(T0, T1, T2)> {
+                /// Setter for the [`pair` field](OrderBook#structfield.pair).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn pair<T3>(self, value: T3) -> OrderBookBuilder<(T0, T1, T2, T3)>
+                where
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2) = self.0;
+                    OrderBookBuilder((v0, v1, v2, value))
+                }
+
+                /// Sets the [`pair` field](OrderBook#structfield.pair) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn pair_as_null(self) -> OrderBookBuilder<(T0, T1, T2, ())> {
+                    self.pair(())
+                }
+            }
+
+            impl<T0, T1, T2, T3> OrderBookBuilder<(T0, T1, T2, T3)> {
+                /// Setter for the [`symbol` field](OrderBook#structfield.symbol).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol<T4>(self, value: T4) -> OrderBookBuilder<(T0, T1, T2, T3, T4)>
+                where
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3) = self.0;
+                    OrderBookBuilder((v0, v1, v2, v3, value))
+                }
+
+                /// Sets the [`symbol` field](OrderBook#structfield.symbol) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol_as_null(self) -> OrderBookBuilder<(T0, T1, T2, T3, ())> {
+                    self.symbol(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4> OrderBookBuilder<(T0, T1, T2, T3, T4)> {
+                /// Setter for the [`timestamp` field](OrderBook#structfield.timestamp).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn timestamp<T5>(self, value: T5) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5)>
+                where
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                {
+                    let (v0, v1, v2, v3, v4) = self.0;
+                    OrderBookBuilder((v0, v1, v2, v3, v4, value))
+                }
+
+                /// Sets the [`timestamp` field](OrderBook#structfield.timestamp) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn timestamp_as_default(
+                    self,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, ::planus::DefaultValue)>
+                {
+                    self.timestamp(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5> OrderBookBuilder<(T0, T1, T2, T3, T4, T5)> {
+                /// Setter for the [`snapshot` field](OrderBook#structfield.snapshot).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn snapshot<T6>(
+                    self,
+                    value: T6,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6)>
+                where
+                    T6: ::planus::WriteAsDefault<bool, bool>,
+                {
+                    let (v0, v1, v2, v3, v4, v5) = self.0;
+                    OrderBookBuilder((v0, v1, v2, v3, v4, v5, value))
+                }
+
+                /// Sets the [`snapshot` field](OrderBook#structfield.snapshot) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn snapshot_as_default(
+                    self,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, ::planus::DefaultValue)>
+                {
+                    self.snapshot(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6)> {
+                /// Setter for the [`asks` field](OrderBook#structfield.asks).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn asks<T7>(
+                    self,
+                    value: T7,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7)>
+                where
+                    T7: ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::Order>]>,
+                    >,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6) = self.0;
+                    OrderBookBuilder((v0, v1, v2, v3, v4, v5, v6, value))
+                }
+
+                /// Sets the [`asks` field](OrderBook#structfield.asks) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn asks_as_null(self) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, ())> {
+                    self.asks(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7)> {
+                /// Setter for the [`bids` field](OrderBook#structfield.bids).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bids<T8>(
+                    self,
+                    value: T8,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8)>
+                where
+                    T8: ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::Order>]>,
+                    >,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7) = self.0;
+                    OrderBookBuilder((v0, v1, v2, v3, v4, v5, v6, v7, value))
+                }
+
+                /// Sets the [`bids` field](OrderBook#structfield.bids) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bids_as_null(
+                    self,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, ())> {
+                    self.bids(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8)> {
+                /// Setter for the [`seq_id` field](OrderBook#structfield.seq_id).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn seq_id<T9>(
+                    self,
+                    value: T9,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)>
+                where
+                    T9: ::planus::WriteAsOptional<u64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8) = self.0;
+                    OrderBookBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, value))
+                }
+
+                /// Sets the [`seq_id` field](OrderBook#structfield.seq_id) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn seq_id_as_null(
+                    self,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, ())> {
+                    self.seq_id(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9>
+                OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)>
+            {
+                /// Setter for the [`prev_seq_id` field](OrderBook#structfield.prev_seq_id).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn prev_seq_id<T10>(
+                    self,
+                    value: T10,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)>
+                where
+                    T10: ::planus::WriteAsOptional<u64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9) = self.0;
+                    OrderBookBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, value))
+                }
+
+                /// Sets the [`prev_seq_id` field](OrderBook#structfield.prev_seq_id) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn prev_seq_id_as_null(
+                    self,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, ())>
+                {
+                    self.prev_seq_id(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10>
+                OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)>
+            {
+                /// Setter for the [`json` field](OrderBook#structfield.json).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn json<T11>(
+                    self,
+                    value: T11,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+                where
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10) = self.0;
+                    OrderBookBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, value))
+                }
+
+                /// Sets the [`json` field](OrderBook#structfield.json) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn json_as_null(
+                    self,
+                ) -> OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, ())>
+                {
+                    self.json(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>
+                OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [OrderBook].
+                #[inline]
+                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<OrderBook>
+                where
+                    Self: ::planus::WriteAsOffset<OrderBook>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<bool, bool>,
+                    T7: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Order>]>>,
+                    T8: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Order>]>>,
+                    T9: ::planus::WriteAsOptional<u64>,
+                    T10: ::planus::WriteAsOptional<u64>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAs<::planus::Offset<OrderBook>>
+                for OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                type Prepared = ::planus::Offset<OrderBook>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<OrderBook> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<bool, bool>,
+                    T7: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Order>]>>,
+                    T8: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Order>]>>,
+                    T9: ::planus::WriteAsOptional<u64>,
+                    T10: ::planus::WriteAsOptional<u64>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAsOptional<::planus::Offset<OrderBook>>
+                for OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                type Prepared = ::planus::Offset<OrderBook>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<OrderBook>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<bool, bool>,
+                    T7: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Order>]>>,
+                    T8: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Order>]>>,
+                    T9: ::planus::WriteAsOptional<u64>,
+                    T10: ::planus::WriteAsOptional<u64>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAsOffset<OrderBook>
+                for OrderBookBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<OrderBook> {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11) = &self.0;
+                    OrderBook::create(builder, v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11)
+                }
+            }
+
+            /// Reference to a deserialized [OrderBook].
+            #[derive(Copy, Clone)]
+            pub struct OrderBookRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> OrderBookRef<'a> {
+                /// Getter for the [`exchange` field](OrderBook#structfield.exchange).
+                #[inline]
+                pub fn exchange(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(0, "OrderBook", "exchange")
+                }
+
+                /// Getter for the [`market_type` field](OrderBook#structfield.market_type).
+                #[inline]
+                pub fn market_type(&self) -> ::planus::Result<self::MarketType> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(1, "OrderBook", "market_type")?
+                            .unwrap_or(self::MarketType::Unknown),
+                    )
+                }
+
+                /// Getter for the [`msg_type` field](OrderBook#structfield.msg_type).
+                #[inline]
+                pub fn msg_type(&self) -> ::planus::Result<self::MessageType> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(2, "OrderBook", "msg_type")?
+                            .unwrap_or(self::MessageType::Other),
+                    )
+                }
+
+                /// Getter for the [`pair` field](OrderBook#structfield.pair).
+                #[inline]
+                pub fn pair(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(3, "OrderBook", "pair")
+                }
+
+                /// Getter for the [`symbol` field](OrderBook#structfield.symbol).
+                #[inline]
+                pub fn symbol(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(4, "OrderBook", "symbol")
+                }
+
+                /// Getter for the [`timestamp` field](OrderBook#structfield.timestamp).
+                #[inline]
+                pub fn timestamp(&self) -> ::planus::Result<i64> {
+                    ::core::result::Result::Ok(
+                        self.0.access(5, "OrderBook", "timestamp")?.unwrap_or(0),
+                    )
+                }
+
+                /// Getter for the [`snapshot` field](OrderBook#structfield.snapshot).
+                #[inline]
+                pub fn snapshot(&self) -> ::planus::Result<bool> {
+                    ::core::result::Result::Ok(
+                        self.0.access(6, "OrderBook", "snapshot")?.unwrap_or(false),
+                    )
+                }
+
+                /// Getter for the [`asks` field](OrderBook#structfield.asks).
+                #[inline]
+                pub fn asks(
+                    &self,
+                ) -> ::planus::Result<
+                    ::core::option::Option<
+                        ::planus::Vector<'a, ::planus::Result<self::OrderRef<'a>>>,
+                    >,
+                > {
+                    self.0.access(7, "OrderBook", "asks")
+                }
+
+                /// Getter for the [`bids` field](OrderBook#structfield.bids).
+                #[inline]
+                pub fn bids(
+                    &self,
+                ) -> ::planus::Result<
+                    ::core::option::Option<
+                        ::planus::Vector<'a, ::planus::Result<self::OrderRef<'a>>>,
+                    >,
+                > {
+                    self.0.access(8, "OrderBook", "bids")
+                }
+
+                /// Getter for the [`seq_id` field](OrderBook#structfield.seq_id).
+                #[inline]
+                pub fn seq_id(&self) -> ::planus::Result<::core::option::Option<u64>> {
+                    self.0.access(9, "OrderBook", "seq_id")
+                }
+
+                /// Getter for the [`prev_seq_id` field](OrderBook#structfield.prev_seq_id).
+                #[inline]
+                pub fn prev_seq_id(&self) -> ::planus::Result<::core::option::Option<u64>> {
+                    self.0.access(10, "OrderBook", "prev_seq_id")
+                }
+
+                /// Getter for the [`json` field](OrderBook#structfield.json).
+                #[inline]
+                pub fn json(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(11, "OrderBook", "json")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for OrderBookRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("OrderBookRef");
+                    if let ::core::option::Option::Some(field_exchange) =
+                        self.exchange().transpose()
+                    {
+                        f.field("exchange", &field_exchange);
+                    }
+                    f.field("market_type", &self.market_type());
+                    f.field("msg_type", &self.msg_type());
+                    if let ::core::option::Option::Some(field_pair) = self.pair().transpose() {
+                        f.field("pair", &field_pair);
+                    }
+                    if let ::core::option::Option::Some(field_symbol) = self.symbol().transpose() {
+                        f.field("symbol", &field_symbol);
+                    }
+                    f.field("timestamp", &self.timestamp());
+                    f.field("snapshot", &self.snapshot());
+                    if let ::core::option::Option::Some(field_asks) = self.asks().transpose() {
+                        f.field("asks", &field_asks);
+                    }
+                    if let ::core::option::Option::Some(field_bids) = self.bids().transpose() {
+                        f.field("bids", &field_bids);
+                    }
+                    if let ::core::option::Option::Some(field_seq_id) = self.seq_id().transpose() {
+                        f.field("seq_id", &field_seq_id);
+                    }
+                    if let ::core::option::Option::Some(field_prev_seq_id) =
+                        self.prev_seq_id().transpose()
+                    {
+                        f.field("prev_seq_id", &field_prev_seq_id);
+                    }
+                    if let ::core::option::Option::Some(field_json) = self.json().transpose() {
+                        f.field("json", &field_json);
+                    }
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<OrderBookRef<'a>> for OrderBook {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: OrderBookRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        exchange: value.exchange()?.map(::core::convert::Into::into),
+                        market_type: ::core::convert::TryInto::try_into(value.market_type()?)?,
+                        msg_type: ::core::convert::TryInto::try_into(value.msg_type()?)?,
+                        pair: value.pair()?.map(::core::convert::Into::into),
+                        symbol: value.symbol()?.map(::core::convert::Into::into),
+                        timestamp: ::core::convert::TryInto::try_into(value.timestamp()?)?,
+                        snapshot: ::core::convert::TryInto::try_into(value.snapshot()?)?,
+                        asks: if let ::core::option::Option::Some(asks) = value.asks()? {
+                            ::core::option::Option::Some(asks.to_vec_result()?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        bids: if let ::core::option::Option::Some(bids) = value.bids()? {
+                            ::core::option::Option::Some(bids.to_vec_result()?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        seq_id: if let ::core::option::Option::Some(seq_id) = value.seq_id()? {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                seq_id,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        prev_seq_id: if let ::core::option::Option::Some(prev_seq_id) =
+                            value.prev_seq_id()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                prev_seq_id,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        json: value.json()?.map(::core::convert::Into::into),
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for OrderBookRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for OrderBookRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[OrderBookRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<OrderBook>> for OrderBook {
+                type Value = ::planus::Offset<OrderBook>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<OrderBook>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for OrderBookRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[OrderBookRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `Bbo` in the namespace `crypto_msg_parser.fb`
+            ///
+            /// Generated from these locations:
+            /// * Table `Bbo` in the file `fbs/crypto_msg_parser.fbs:95`
+            #[derive(
+                Clone, Debug, PartialEq, PartialOrd, ::serde::Serialize, ::serde::Deserialize,
+            )]
+            pub struct Bbo {
+                /// The field `exchange` in the table `Bbo`
+                pub exchange: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `market_type` in the table `Bbo`
+                pub market_type: self::MarketType,
+                /// The field `msg_type` in the table `Bbo`
+                pub msg_type: self::MessageType,
+                /// The field `pair` in the table `Bbo`
+                pub pair: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `symbol` in the table `Bbo`
+                pub symbol: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `timestamp` in the table `Bbo`
+                pub timestamp: i64,
+                /// The field `bid_price` in the table `Bbo`
+                pub bid_price: f64,
+                /// The field `bid_quantity_base` in the table `Bbo`
+                pub bid_quantity_base: f64,
+                /// The field `bid_quantity_quote` in the table `Bbo`
+                pub bid_quantity_quote: f64,
+                /// The field `bid_quantity_contract` in the table `Bbo`
+                pub bid_quantity_contract: ::core::option::Option<f64>,
+                /// The field `ask_price` in the table `Bbo`
+                pub ask_price: f64,
+                /// The field `ask_quantity_base` in the table `Bbo`
+                pub ask_quantity_base: f64,
+                /// The field `ask_quantity_quote` in the table `Bbo`
+                pub ask_quantity_quote: f64,
+                /// The field `ask_quantity_contract` in the table `Bbo`
+                pub ask_quantity_contract: ::core::option::Option<f64>,
+                /// The field `id` in the table `Bbo`
+                pub id: ::core::option::Option<u64>,
+                /// The field `json` in the table `Bbo`
+                pub json: ::core::option::Option<::planus::alloc::string::String>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for Bbo {
+                fn default() -> Self {
+                    Self {
+                        exchange: ::core::default::Default::default(),
+                        market_type: self::MarketType::Unknown,
+                        msg_type: self::MessageType::Other,
+                        pair: ::core::default::Default::default(),
+                        symbol: ::core::default::Default::default(),
+                        timestamp: 0,
+                        bid_price: 0.0,
+                        bid_quantity_base: 0.0,
+                        bid_quantity_quote: 0.0,
+                        bid_quantity_contract: ::core::default::Default::default(),
+                        ask_price: 0.0,
+                        ask_quantity_base: 0.0,
+                        ask_quantity_quote: 0.0,
+                        ask_quantity_contract: ::core::default::Default::default(),
+                        id: ::core::default::Default::default(),
+                        json: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl Bbo {
+                /// Creates a [BboBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> BboBuilder<()> {
+                    BboBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_exchange: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_market_type: impl ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    field_msg_type: impl ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    field_pair: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    field_symbol: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_timestamp: impl ::planus::WriteAsDefault<i64, i64>,
+                    field_bid_price: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_bid_quantity_base: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_bid_quantity_quote: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_bid_quantity_contract: impl ::planus::WriteAsOptional<f64>,
+                    field_ask_price: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_ask_quantity_base: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_ask_quantity_quote: impl ::planus::WriteAsDefault<f64, f64>,
+                    field_ask_quantity_contract: impl ::planus::WriteAsOptional<f64>,
+                    field_id: impl ::planus::WriteAsOptional<u64>,
+                    field_json: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_exchange = field_exchange.prepare(builder);
+                    let prepared_market_type =
+                        field_market_type.prepare(builder, &self::MarketType::Unknown);
+                    let prepared_msg_type =
+                        field_msg_type.prepare(builder, &self::MessageType::Other);
+                    let prepared_pair = field_pair.prepare(builder);
+                    let prepared_symbol = field_symbol.prepare(builder);
+                    let prepared_timestamp = field_timestamp.prepare(builder, &0);
+                    let prepared_bid_price = field_bid_price.prepare(builder, &0.0);
+                    let prepared_bid_quantity_base = field_bid_quantity_base.prepare(builder, &0.0);
+                    let prepared_bid_quantity_quote =
+                        field_bid_quantity_quote.prepare(builder, &0.0);
+                    let prepared_bid_quantity_contract =
+                        field_bid_quantity_contract.prepare(builder);
+                    let prepared_ask_price = field_ask_price.prepare(builder, &0.0);
+                    let prepared_ask_quantity_base = field_ask_quantity_base.prepare(builder, &0.0);
+                    let prepared_ask_quantity_quote =
+                        field_ask_quantity_quote.prepare(builder, &0.0);
+                    let prepared_ask_quantity_contract =
+                        field_ask_quantity_contract.prepare(builder);
+                    let prepared_id = field_id.prepare(builder);
+                    let prepared_json = field_json.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<36> =
+                        ::core::default::Default::default();
+                    if prepared_timestamp.is_some() {
+                        table_writer.write_entry::<i64>(5);
+                    }
+                    if prepared_bid_price.is_some() {
+                        table_writer.write_entry::<f64>(6);
+                    }
+                    if prepared_bid_quantity_base.is_some() {
+                        table_writer.write_entry::<f64>(7);
+                    }
+                    if prepared_bid_quantity_quote.is_some() {
+                        table_writer.write_entry::<f64>(8);
+                    }
+                    if prepared_bid_quantity_contract.is_some() {
+                        table_writer.write_entry::<f64>(9);
+                    }
+                    if prepared_ask_price.is_some() {
+                        table_writer.write_entry::<f64>(10);
+                    }
+                    if prepared_ask_quantity_base.is_some() {
+                        table_writer.write_entry::<f64>(11);
+                    }
+                    if prepared_ask_quantity_quote.is_some() {
+                        table_writer.write_entry::<f64>(12);
+                    }
+                    if prepared_ask_quantity_contract.is_some() {
+                        table_writer.write_entry::<f64>(13);
+                    }
+                    if prepared_id.is_some() {
+                        table_writer.write_entry::<u64>(14);
+                    }
+                    if prepared_exchange.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(0);
+                    }
+                    if prepared_market_type.is_some() {
+                        table_writer.write_entry::<self::MarketType>(1);
+                    }
+                    if prepared_msg_type.is_some() {
+                        table_writer.write_entry::<self::MessageType>(2);
+                    }
+                    if prepared_pair.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(3);
+                    }
+                    if prepared_symbol.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(4);
+                    }
+                    if prepared_json.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(15);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            if let ::core::option::Option::Some(prepared_timestamp) =
+                                prepared_timestamp
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_timestamp);
+                            }
+                            if let ::core::option::Option::Some(prepared_bid_price) =
+                                prepared_bid_price
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_bid_price);
+                            }
+                            if let ::core::option::Option::Some(prepared_bid_quantity_base) =
+                                prepared_bid_quantity_base
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_bid_quantity_base);
+                            }
+                            if let ::core::option::Option::Some(prepared_bid_quantity_quote) =
+                                prepared_bid_quantity_quote
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_bid_quantity_quote);
+                            }
+                            if let ::core::option::Option::Some(prepared_bid_quantity_contract) =
+                                prepared_bid_quantity_contract
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_bid_quantity_contract);
+                            }
+                            if let ::core::option::Option::Some(prepared_ask_price) =
+                                prepared_ask_price
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_ask_price);
+                            }
+                            if let ::core::option::Option::Some(prepared_ask_quantity_base) =
+                                prepared_ask_quantity_base
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_ask_quantity_base);
+                            }
+                            if let ::core::option::Option::Some(prepared_ask_quantity_quote) =
+                                prepared_ask_quantity_quote
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_ask_quantity_quote);
+                            }
+                            if let ::core::option::Option::Some(prepared_ask_quantity_contract) =
+                                prepared_ask_quantity_contract
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_ask_quantity_contract);
+                            }
+                            if let ::core::option::Option::Some(prepared_id) = prepared_id {
+                                object_writer.write::<_, _, 8>(&prepared_id);
+                            }
+                            if let ::core::option::Option::Some(prepared_exchange) =
+                                prepared_exchange
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_exchange);
+                            }
+                            if let ::core::option::Option::Some(prepared_market_type) =
+                                prepared_market_type
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_market_type);
+                            }
+                            if let ::core::option::Option::Some(prepared_msg_type) =
+                                prepared_msg_type
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_msg_type);
+                            }
+                            if let ::core::option::Option::Some(prepared_pair) = prepared_pair {
+                                object_writer.write::<_, _, 4>(&prepared_pair);
+                            }
+                            if let ::core::option::Option::Some(prepared_symbol) = prepared_symbol {
+                                object_writer.write::<_, _, 4>(&prepared_symbol);
+                            }
+                            if let ::core::option::Option::Some(prepared_json) = prepared_json {
+                                object_writer.write::<_, _, 4>(&prepared_json);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<Bbo>> for Bbo {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Bbo> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<Bbo>> for Bbo {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Bbo>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Bbo> for Bbo {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Bbo> {
+                    Bbo::create(
+                        builder,
+                        &self.exchange,
+                        self.market_type,
+                        self.msg_type,
+                        &self.pair,
+                        &self.symbol,
+                        self.timestamp,
+                        self.bid_price,
+                        self.bid_quantity_base,
+                        self.bid_quantity_quote,
+                        self.bid_quantity_contract,
+                        self.ask_price,
+                        self.ask_quantity_base,
+                        self.ask_quantity_quote,
+                        self.ask_quantity_contract,
+                        self.id,
+                        &self.json,
+                    )
+                }
+            }
+
+            /// Builder for serializing an instance of the [Bbo] type.
+            ///
+            /// Can be created using the [Bbo::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct BboBuilder<State>(State);
+
+            impl BboBuilder<()> {
+                /// Setter for the [`exchange` field](Bbo#structfield.exchange).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn exchange<T0>(self, value: T0) -> BboBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    BboBuilder((value,))
+                }
+
+                /// Sets the [`exchange` field](Bbo#structfield.exchange) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn exchange_as_null(self) -> BboBuilder<((),)> {
+                    self.exchange(())
+                }
+            }
+
+            impl<T0> BboBuilder<(T0,)> {
+                /// Setter for the [`market_type` field](Bbo#structfield.market_type).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn market_type<T1>(self, value: T1) -> BboBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                {
+                    let (v0,) = self.0;
+                    BboBuilder((v0, value))
+                }
+
+                /// Sets the [`market_type` field](Bbo#structfield.market_type) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn market_type_as_default(self) -> BboBuilder<(T0, ::planus::DefaultValue)> {
+                    self.market_type(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1> BboBuilder<(T0, T1)> {
+                /// Setter for the [`msg_type` field](Bbo#structfield.msg_type).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn msg_type<T2>(self, value: T2) -> BboBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                {
+                    let (v0, v1) = self.0;
+                    BboBuilder((v0, v1, value))
+                }
+
+                /// Sets the [`msg_type` field](Bbo#structfield.msg_type) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn msg_type_as_default(self) -> BboBuilder<(T0, T1, ::planus::DefaultValue)> {
+                    self.msg_type(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2> BboBuilder<(T0, T1, T2)> {
+                /// Setter for the [`pair` field](Bbo#structfield.pair).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn pair<T3>(self, value: T3) -> BboBuilder<(T0, T1, T2, T3)>
+                where
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2) = self.0;
+                    BboBuilder((v0, v1, v2, value))
+                }
+
+                /// Sets the [`pair` field](Bbo#structfield.pair) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn pair_as_null(self) -> BboBuilder<(T0, T1, T2, ())> {
+                    self.pair(())
+                }
+            }
+
+            impl<T0, T1, T2, T3> BboBuilder<(T0, T1, T2, T3)> {
+                /// Setter for the [`symbol` field](Bbo#structfield.symbol).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol<T4>(self, value: T4) -> BboBuilder<(T0, T1, T2, T3, T4)>
+                where
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3) = self.0;
+                    BboBuilder((v0, v1, v2, v3, value))
+                }
+
+                /// Sets the [`symbol` field](Bbo#structfield.symbol) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol_as_null(self) -> BboBuilder<(T0, T1, T2, T3, ())> {
+                    self.symbol(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4> BboBuilder<(T0, T1, T2, T3, T4)> {
+                /// Setter for the [`timestamp` field](Bbo#structfield.timestamp).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn timestamp<T5>(self, value: T5) -> BboBuilder<(T0, T1, T2, T3, T4, T5)>
+                where
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                {
+                    let (v0, v1, v2, v3, v4) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, value))
+                }
+
+                /// Sets the [`timestamp` field](Bbo#structfield.timestamp) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn timestamp_as_default(
+                    self,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, ::planus::DefaultValue)> {
+                    self.timestamp(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5> BboBuilder<(T0, T1, T2, T3, T4, T5)> {
+                /// Setter for the [`bid_price` field](Bbo#structfield.bid_price).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bid_price<T6>(self, value: T6) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6)>
+                where
+                    T6: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, v5, value))
+                }
+
+                /// Sets the [`bid_price` field](Bbo#structfield.bid_price) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bid_price_as_default(
+                    self,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, ::planus::DefaultValue)> {
+                    self.bid_price(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6> BboBuilder<(T0, T1, T2, T3, T4, T5, T6)> {
+                /// Setter for the [`bid_quantity_base` field](Bbo#structfield.bid_quantity_base).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bid_quantity_base<T7>(
+                    self,
+                    value: T7,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7)>
+                where
+                    T7: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, v5, v6, value))
+                }
+
+                /// Sets the [`bid_quantity_base` field](Bbo#structfield.bid_quantity_base) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bid_quantity_base_as_default(
+                    self,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, ::planus::DefaultValue)>
+                {
+                    self.bid_quantity_base(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7)> {
+                /// Setter for the [`bid_quantity_quote` field](Bbo#structfield.bid_quantity_quote).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bid_quantity_quote<T8>(
+                    self,
+                    value: T8,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8)>
+                where
+                    T8: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, v5, v6, v7, value))
+                }
+
+                /// Sets the [`bid_quantity_quote` field](Bbo#structfield.bid_quantity_quote) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bid_quantity_quote_as_default(
+                    self,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, ::planus::DefaultValue)>
+                {
+                    self.bid_quantity_quote(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8)> {
+                /// Setter for the [`bid_quantity_contract` field](Bbo#structfield.bid_quantity_contract).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bid_quantity_contract<T9>(
+                    self,
+                    value: T9,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)>
+                where
+                    T9: ::planus::WriteAsOptional<f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, value))
+                }
+
+                /// Sets the [`bid_quantity_contract` field](Bbo#structfield.bid_quantity_contract) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bid_quantity_contract_as_null(
+                    self,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, ())> {
+                    self.bid_quantity_contract(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)> {
+                /// Setter for the [`ask_price` field](Bbo#structfield.ask_price).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ask_price<T10>(
+                    self,
+                    value: T10,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)>
+                where
+                    T10: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, value))
+                }
+
+                /// Sets the [`ask_price` field](Bbo#structfield.ask_price) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ask_price_as_default(
+                    self,
+                ) -> BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    ::planus::DefaultValue,
+                )> {
+                    self.ask_price(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10>
+                BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)>
+            {
+                /// Setter for the [`ask_quantity_base` field](Bbo#structfield.ask_quantity_base).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ask_quantity_base<T11>(
+                    self,
+                    value: T11,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+                where
+                    T11: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, value))
+                }
+
+                /// Sets the [`ask_quantity_base` field](Bbo#structfield.ask_quantity_base) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ask_quantity_base_as_default(
+                    self,
+                ) -> BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    ::planus::DefaultValue,
+                )> {
+                    self.ask_quantity_base(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>
+                BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                /// Setter for the [`ask_quantity_quote` field](Bbo#structfield.ask_quantity_quote).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ask_quantity_quote<T12>(
+                    self,
+                    value: T12,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12)>
+                where
+                    T12: ::planus::WriteAsDefault<f64, f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, value))
+                }
+
+                /// Sets the [`ask_quantity_quote` field](Bbo#structfield.ask_quantity_quote) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ask_quantity_quote_as_default(
+                    self,
+                ) -> BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    ::planus::DefaultValue,
+                )> {
+                    self.ask_quantity_quote(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12>
+                BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12)>
+            {
+                /// Setter for the [`ask_quantity_contract` field](Bbo#structfield.ask_quantity_contract).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ask_quantity_contract<T13>(
+                    self,
+                    value: T13,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13)>
+                where
+                    T13: ::planus::WriteAsOptional<f64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12) = self.0;
+                    BboBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, value))
+                }
+
+                /// Sets the [`ask_quantity_contract` field](Bbo#structfield.ask_quantity_contract) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ask_quantity_contract_as_null(
+                    self,
+                ) -> BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, ())>
+                {
+                    self.ask_quantity_contract(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13>
+                BboBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13)>
+            {
+                /// Setter for the [`id` field](Bbo#structfield.id).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn id<T14>(
+                    self,
+                    value: T14,
+                ) -> BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    T14,
+                )>
+                where
+                    T14: ::planus::WriteAsOptional<u64>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13) = self.0;
+                    BboBuilder((
+                        v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, value,
+                    ))
+                }
+
+                /// Sets the [`id` field](Bbo#structfield.id) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn id_as_null(
+                    self,
+                ) -> BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    (),
+                )> {
+                    self.id(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14>
+                BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    T14,
+                )>
+            {
+                /// Setter for the [`json` field](Bbo#structfield.json).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn json<T15>(
+                    self,
+                    value: T15,
+                ) -> BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    T14,
+                    T15,
+                )>
+                where
+                    T15: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, v14) = self.0;
+                    BboBuilder((
+                        v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, v14, value,
+                    ))
+                }
+
+                /// Sets the [`json` field](Bbo#structfield.json) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn json_as_null(
+                    self,
+                ) -> BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    T14,
+                    (),
+                )> {
+                    self.json(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15>
+                BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    T14,
+                    T15,
+                )>
+            {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Bbo].
+                #[inline]
+                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Bbo>
+                where
+                    Self: ::planus::WriteAsOffset<Bbo>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<f64, f64>,
+                    T7: ::planus::WriteAsDefault<f64, f64>,
+                    T8: ::planus::WriteAsDefault<f64, f64>,
+                    T9: ::planus::WriteAsOptional<f64>,
+                    T10: ::planus::WriteAsDefault<f64, f64>,
+                    T11: ::planus::WriteAsDefault<f64, f64>,
+                    T12: ::planus::WriteAsDefault<f64, f64>,
+                    T13: ::planus::WriteAsOptional<f64>,
+                    T14: ::planus::WriteAsOptional<u64>,
+                    T15: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAs<::planus::Offset<Bbo>>
+                for BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    T14,
+                    T15,
+                )>
+            {
+                type Prepared = ::planus::Offset<Bbo>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Bbo> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<f64, f64>,
+                    T7: ::planus::WriteAsDefault<f64, f64>,
+                    T8: ::planus::WriteAsDefault<f64, f64>,
+                    T9: ::planus::WriteAsOptional<f64>,
+                    T10: ::planus::WriteAsDefault<f64, f64>,
+                    T11: ::planus::WriteAsDefault<f64, f64>,
+                    T12: ::planus::WriteAsDefault<f64, f64>,
+                    T13: ::planus::WriteAsOptional<f64>,
+                    T14: ::planus::WriteAsOptional<u64>,
+                    T15: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAsOptional<::planus::Offset<Bbo>>
+                for BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    T14,
+                    T15,
+                )>
+            {
+                type Prepared = ::planus::Offset<Bbo>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Bbo>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T1: ::planus::WriteAsDefault<self::MarketType, self::MarketType>,
+                    T2: ::planus::WriteAsDefault<self::MessageType, self::MessageType>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T5: ::planus::WriteAsDefault<i64, i64>,
+                    T6: ::planus::WriteAsDefault<f64, f64>,
+                    T7: ::planus::WriteAsDefault<f64, f64>,
+                    T8: ::planus::WriteAsDefault<f64, f64>,
+                    T9: ::planus::WriteAsOptional<f64>,
+                    T10: ::planus::WriteAsDefault<f64, f64>,
+                    T11: ::planus::WriteAsDefault<f64, f64>,
+                    T12: ::planus::WriteAsDefault<f64, f64>,
+                    T13: ::planus::WriteAsOptional<f64>,
+                    T14: ::planus::WriteAsOptional<u64>,
+                    T15: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAsOffset<Bbo>
+                for BboBuilder<(
+                    T0,
+                    T1,
+                    T2,
+                    T3,
+                    T4,
+                    T5,
+                    T6,
+                    T7,
+                    T8,
+                    T9,
+                    T10,
+                    T11,
+                    T12,
+                    T13,
+                    T14,
+                    T15,
+                )>
+            {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Bbo> {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, v14, v15) =
+                        &self.0;
+                    Bbo::create(
+                        builder, v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, v14,
+                        v15,
+                    )
+                }
+            }
+
+            /// Reference to a deserialized [Bbo].
+            #[derive(Copy, Clone)]
+            pub struct BboRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> BboRef<'a> {
+                /// Getter for the [`exchange` field](Bbo#structfield.exchange).
+                #[inline]
+                pub fn exchange(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(0, "Bbo", "exchange")
+                }
+
+                /// Getter for the [`market_type` field](Bbo#structfield.market_type).
+                #[inline]
+                pub fn market_type(&self) -> ::planus::Result<self::MarketType> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(1, "Bbo", "market_type")?
+                            .unwrap_or(self::MarketType::Unknown),
+                    )
+                }
+
+                /// Getter for the [`msg_type` field](Bbo#structfield.msg_type).
+                #[inline]
+                pub fn msg_type(&self) -> ::planus::Result<self::MessageType> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(2, "Bbo", "msg_type")?
+                            .unwrap_or(self::MessageType::Other),
+                    )
+                }
+
+                /// Getter for the [`pair` field](Bbo#structfield.pair).
+                #[inline]
+                pub fn pair(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(3, "Bbo", "pair")
+                }
+
+                /// Getter for the [`symbol` field](Bbo#structfield.symbol).
+                #[inline]
+                pub fn symbol(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(4, "Bbo", "symbol")
+                }
+
+                /// Getter for the [`timestamp` field](Bbo#structfield.timestamp).
+                #[inline]
+                pub fn timestamp(&self) -> ::planus::Result<i64> {
+                    ::core::result::Result::Ok(self.0.access(5, "Bbo", "timestamp")?.unwrap_or(0))
+                }
+
+                /// Getter for the [`bid_price` field](Bbo#structfield.bid_price).
+                #[inline]
+                pub fn bid_price(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(self.0.access(6, "Bbo", "bid_price")?.unwrap_or(0.0))
+                }
+
+                /// Getter for the [`bid_quantity_base` field](Bbo#structfield.bid_quantity_base).
+                #[inline]
+                pub fn bid_quantity_base(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0.access(7, "Bbo", "bid_quantity_base")?.unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`bid_quantity_quote` field](Bbo#structfield.bid_quantity_quote).
+                #[inline]
+                pub fn bid_quantity_quote(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(8, "Bbo", "bid_quantity_quote")?
+                            .unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`bid_quantity_contract` field](Bbo#structfield.bid_quantity_contract).
+                #[inline]
+                pub fn bid_quantity_contract(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<f64>> {
+                    self.0.access(9, "Bbo", "bid_quantity_contract")
+                }
+
+                /// Getter for the [`ask_price` field](Bbo#structfield.ask_price).
+                #[inline]
+                pub fn ask_price(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0.access(10, "Bbo", "ask_price")?.unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`ask_quantity_base` field](Bbo#structfield.ask_quantity_base).
+                #[inline]
+                pub fn ask_quantity_base(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(11, "Bbo", "ask_quantity_base")?
+                            .unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`ask_quantity_quote` field](Bbo#structfield.ask_quantity_quote).
+                #[inline]
+                pub fn ask_quantity_quote(&self) -> ::planus::Result<f64> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(12, "Bbo", "ask_quantity_quote")?
+                            .unwrap_or(0.0),
+                    )
+                }
+
+                /// Getter for the [`ask_quantity_contract` field](Bbo#structfield.ask_quantity_contract).
+                #[inline]
+                pub fn ask_quantity_contract(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<f64>> {
+                    self.0.access(13, "Bbo", "ask_quantity_contract")
+                }
+
+                /// Getter for the [`id` field](Bbo#structfield.id).
+                #[inline]
+                pub fn id(&self) -> ::planus::Result<::core::option::Option<u64>> {
+                    self.0.access(14, "Bbo", "id")
+                }
+
+                /// Getter for the [`json` field](Bbo#structfield.json).
+                #[inline]
+                pub fn json(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(15, "Bbo", "json")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for BboRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("BboRef");
+                    if let ::core::option::Option::Some(field_exchange) =
+                        self.exchange().transpose()
+                    {
+                        f.field("exchange", &field_exchange);
+                    }
+                    f.field("market_type", &self.market_type());
+                    f.field("msg_type", &self.msg_type());
+                    if let ::core::option::Option::Some(field_pair) = self.pair().transpose() {
+                        f.field("pair", &field_pair);
+                    }
+                    if let ::core::option::Option::Some(field_symbol) = self.symbol().transpose() {
+                        f.field("symbol", &field_symbol);
+                    }
+                    f.field("timestamp", &self.timestamp());
+                    f.field("bid_price", &self.bid_price());
+                    f.field("bid_quantity_base", &self.bid_quantity_base());
+                    f.field("bid_quantity_quote", &self.bid_quantity_quote());
+                    if let ::core::option::Option::Some(field_bid_quantity_contract) =
+                        self.bid_quantity_contract().transpose()
+                    {
+                        f.field("bid_quantity_contract", &field_bid_quantity_contract);
+                    }
+                    f.field("ask_price", &self.ask_price());
+                    f.field("ask_quantity_base", &self.ask_quantity_base());
+                    f.field("ask_quantity_quote", &self.ask_quantity_quote());
+                    if let ::core::option::Option::Some(field_ask_quantity_contract) =
+                        self.ask_quantity_contract().transpose()
+                    {
+                        f.field("ask_quantity_contract", &field_ask_quantity_contract);
+                    }
+                    if let ::core::option::Option::Some(field_id) = self.id().transpose() {
+                        f.field("id", &field_id);
+                    }
+                    if let ::core::option::Option::Some(field_json) = self.json().transpose() {
+                        f.field("json", &field_json);
+                    }
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<BboRef<'a>> for Bbo {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: BboRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        exchange: value.exchange()?.map(::core::convert::Into::into),
+                        market_type: ::core::convert::TryInto::try_into(value.market_type()?)?,
+                        msg_type: ::core::convert::TryInto::try_into(value.msg_type()?)?,
+                        pair: value.pair()?.map(::core::convert::Into::into),
+                        symbol: value.symbol()?.map(::core::convert::Into::into),
+                        timestamp: ::core::convert::TryInto::try_into(value.timestamp()?)?,
+                        bid_price: ::core::convert::TryInto::try_into(value.bid_price()?)?,
+                        bid_quantity_base: ::core::convert::TryInto::try_into(
+                            value.bid_quantity_base()?,
+                        )?,
+                        bid_quantity_quote: ::core::convert::TryInto::try_into(
+                            value.bid_quantity_quote()?,
+                        )?,
+                        bid_quantity_contract: if let ::core::option::Option::Some(
+                            bid_quantity_contract,
+                        ) = value.bid_quantity_contract()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                bid_quantity_contract,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        ask_price: ::core::convert::TryInto::try_into(value.ask_price()?)?,
+                        ask_quantity_base: ::core::convert::TryInto::try_into(
+                            value.ask_quantity_base()?,
+                        )?,
+                        ask_quantity_quote: ::core::convert::TryInto::try_into(
+                            value.ask_quantity_quote()?,
+                        )?,
+                        ask_quantity_contract: if let ::core::option::Option::Some(
+                            ask_quantity_contract,
+                        ) = value.ask_quantity_contract()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                ask_quantity_contract,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        id: if let ::core::option::Option::Some(id) = value.id()? {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(id)?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        json: value.json()?.map(::core::convert::Into::into),
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for BboRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for BboRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location("[BboRef]", "get", buffer.offset_from_start)
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<Bbo>> for Bbo {
+                type Value = ::planus::Offset<Bbo>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Bbo>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for BboRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[BboRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `Messages` in the namespace `crypto_msg_parser.fb`
+            ///
+            /// Generated from these locations:
+            /// * Table `Messages` in the file `fbs/crypto_msg_parser.fbs:115`
+            #[derive(
+                Clone, Debug, PartialEq, PartialOrd, ::serde::Serialize, ::serde::Deserialize,
+            )]
+            pub struct Messages {
+                /// The field `trades` in the table `Messages`
+                pub trades: ::core::option::Option<::planus::alloc::vec::Vec<self::Trade>>,
+                /// The field `orderbooks` in the table `Messages`
+                pub orderbooks: ::core::option::Option<::planus::alloc::vec::Vec<self::OrderBook>>,
+                /// The field `bbos` in the table `Messages`
+                pub bbos: ::core::option::Option<::planus::alloc::vec::Vec<self::Bbo>>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for Messages {
+                fn default() -> Self {
+                    Self {
+                        trades: ::core::default::Default::default(),
+                        orderbooks: ::core::default::Default::default(),
+                        bbos: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl Messages {
+                /// Creates a [MessagesBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> MessagesBuilder<()> {
+                    MessagesBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_trades: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::Trade>]>,
+                    >,
+                    field_orderbooks: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::OrderBook>]>,
+                    >,
+                    field_bbos: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::Bbo>]>,
+                    >,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_trades = field_trades.prepare(builder);
+                    let prepared_orderbooks = field_orderbooks.prepare(builder);
+                    let prepared_bbos = field_bbos.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<10> =
+                        ::core::default::Default::default();
+                    if prepared_trades.is_some() {
+                        table_writer
+                            .write_entry::<::planus::Offset<[::planus::Offset<self::Trade>]>>(0);
+                    }
+                    if prepared_orderbooks.is_some() {
+                        table_writer
+                            .write_entry::<::planus::Offset<[::planus::Offset<self::OrderBook>]>>(
+                                1,
+                            );
+                    }
+                    if prepared_bbos.is_some() {
+                        table_writer
+                            .write_entry::<::planus::Offset<[::planus::Offset<self::Bbo>]>>(2);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            if let ::core::option::Option::Some(prepared_trades) = prepared_trades {
+                                object_writer.write::<_, _, 4>(&prepared_trades);
+                            }
+                            if let ::core::option::Option::Some(prepared_orderbooks) =
+                                prepared_orderbooks
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_orderbooks);
+                            }
+                            if let ::core::option::Option::Some(prepared_bbos) = prepared_bbos {
+                                object_writer.write::<_, _, 4>(&prepared_bbos);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<Messages>> for Messages {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Messages> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<Messages>> for Messages {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Messages>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Messages> for Messages {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Messages> {
+                    Messages::create(builder, &self.trades, &self.orderbooks, &self.bbos)
+                }
+            }
+
+            /// Builder for serializing an instance of the [Messages] type.
+            ///
+            /// Can be created using the [Messages::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct MessagesBuilder<State>(State);
+
+            impl MessagesBuilder<()> {
+                /// Setter for the [`trades` field](Messages#structfield.trades).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn trades<T0>(self, value: T0) -> MessagesBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::Trade>]>,
+                    >,
+                {
+                    MessagesBuilder((value,))
+                }
+
+                /// Sets the [`trades` field](Messages#structfield.trades) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn trades_as_null(self) -> MessagesBuilder<((),)> {
+                    self.trades(())
+                }
+            }
+
+            impl<T0> MessagesBuilder<(T0,)> {
+                /// Setter for the [`orderbooks` field](Messages#structfield.orderbooks).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn orderbooks<T1>(self, value: T1) -> MessagesBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::OrderBook>]>,
+                    >,
+                {
+                    let (v0,) = self.0;
+                    MessagesBuilder((v0, value))
+                }
+
+                /// Sets the [`orderbooks` field](Messages#structfield.orderbooks) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn orderbooks_as_null(self) -> MessagesBuilder<(T0, ())> {
+                    self.orderbooks(())
+                }
+            }
+
+            impl<T0, T1> MessagesBuilder<(T0, T1)> {
+                /// Setter for the [`bbos` field](Messages#structfield.bbos).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bbos<T2>(self, value: T2) -> MessagesBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Bbo>]>>,
+                {
+                    let (v0, v1) = self.0;
+                    MessagesBuilder((v0, v1, value))
+                }
+
+                /// Sets the [`bbos` field](Messages#structfield.bbos) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn bbos_as_null(self) -> MessagesBuilder<(T0, T1, ())> {
+                    self.bbos(())
+                }
+            }
+
+            impl<T0, T1, T2> MessagesBuilder<(T0, T1, T2)> {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Messages].
+                #[inline]
+                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Messages>
+                where
+                    Self: ::planus::WriteAsOffset<Messages>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Trade>]>>,
+                    T1: ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::OrderBook>]>,
+                    >,
+                    T2: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Bbo>]>>,
+                > ::planus::WriteAs<::planus::Offset<Messages>> for MessagesBuilder<(T0, T1, T2)>
+            {
+                type Prepared = ::planus::Offset<Messages>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Messages> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Trade>]>>,
+                    T1: ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::OrderBook>]>,
+                    >,
+                    T2: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Bbo>]>>,
+                > ::planus::WriteAsOptional<::planus::Offset<Messages>>
+                for MessagesBuilder<(T0, T1, T2)>
+            {
+                type Prepared = ::planus::Offset<Messages>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Messages>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Trade>]>>,
+                    T1: ::planus::WriteAsOptional<
+                        ::planus::Offset<[::planus::Offset<self::OrderBook>]>,
+                    >,
+                    T2: ::planus::WriteAsOptional<::planus::Offset<[::planus::Offset<self::Bbo>]>>,
+                > ::planus::WriteAsOffset<Messages> for MessagesBuilder<(T0, T1, T2)>
+            {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Messages> {
+                    let (v0, v1, v2) = &self.0;
+                    Messages::create(builder, v0, v1, v2)
+                }
+            }
+
+            /// Reference to a deserialized [Messages].
+            #[derive(Copy, Clone)]
+            pub struct MessagesRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> MessagesRef<'a> {
+                /// Getter for the [`trades` field](Messages#structfield.trades).
+                #[inline]
+                pub fn trades(
+                    &self,
+                ) -> ::planus::Result<
+                    ::core::option::Option<
+                        ::planus::Vector<'a, ::planus::Result<self::TradeRef<'a>>>,
+                    >,
+                > {
+                    self.0.access(0, "Messages", "trades")
+                }
+
+                /// Getter for the [`orderbooks` field](Messages#structfield.orderbooks).
+                #[inline]
+                pub fn orderbooks(
+                    &self,
+                ) -> ::planus::Result<
+                    ::core::option::Option<
+                        ::planus::Vector<'a, ::planus::Result<self::OrderBookRef<'a>>>,
+                    >,
+                > {
+                    self.0.access(1, "Messages", "orderbooks")
+                }
+
+                /// Getter for the [`bbos` field](Messages#structfield.bbos).
+                #[inline]
+                pub fn bbos(
+                    &self,
+                ) -> ::planus::Result<
+                    ::core::option::Option<
+                        ::planus::Vector<'a, ::planus::Result<self::BboRef<'a>>>,
+                    >,
+                > {
+                    self.0.access(2, "Messages", "bbos")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for MessagesRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("MessagesRef");
+                    if let ::core::option::Option::Some(field_trades) = self.trades().transpose() {
+                        f.field("trades", &field_trades);
+                    }
+                    if let ::core::option::Option::Some(field_orderbooks) =
+                        self.orderbooks().transpose()
+                    {
+                        f.field("orderbooks", &field_orderbooks);
+                    }
+                    if let ::core::option::Option::Some(field_bbos) = self.bbos().transpose() {
+                        f.field("bbos", &field_bbos);
+                    }
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<MessagesRef<'a>> for Messages {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: MessagesRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        trades: if let ::core::option::Option::Some(trades) = value.trades()? {
+                            ::core::option::Option::Some(trades.to_vec_result()?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        orderbooks: if let ::core::option::Option::Some(orderbooks) =
+                            value.orderbooks()?
+                        {
+                            ::core::option::Option::Some(orderbooks.to_vec_result()?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        bbos: if let ::core::option::Option::Some(bbos) = value.bbos()? {
+                            ::core::option::Option::Some(bbos.to_vec_result()?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for MessagesRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for MessagesRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[MessagesRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<Messages>> for Messages {
+                type Value = ::planus::Offset<Messages>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Messages>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for MessagesRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[MessagesRef]", "read_as_root", 0)
+                    })
+                }
+            }
+        }
+    }
+}
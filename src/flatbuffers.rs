@@ -0,0 +1,266 @@
+//! FlatBuffers encoding of normalized messages, see `fbs/crypto_msg_parser.fbs`.
+//!
+//! Only trade, orderbook and BBO messages are supported.
+
+use crate::{fbs::crypto_msg_parser::fb, message::Message};
+use crypto_market_type::MarketType;
+use crypto_message::{BboMsg, Order, OrderBookMsg, TradeMsg, TradeSide};
+use crypto_msg_type::MessageType;
+use planus::{Builder, Offset};
+
+fn market_type(market_type: MarketType) -> Result<fb::MarketType, String> {
+    fb::MarketType::try_from(market_type as i32)
+        .map_err(|_| format!("Unexpected market type {market_type}"))
+}
+
+fn msg_type(msg_type: MessageType) -> Result<fb::MessageType, String> {
+    fb::MessageType::try_from(msg_type as i32)
+        .map_err(|_| format!("Unexpected message type {msg_type}"))
+}
+
+fn create_trade(builder: &mut Builder, msg: &TradeMsg) -> Result<Offset<fb::Trade>, String> {
+    let side = match msg.side {
+        TradeSide::Buy => fb::TradeSide::Buy,
+        TradeSide::Sell => fb::TradeSide::Sell,
+    };
+    Ok(fb::Trade::create(
+        builder,
+        msg.exchange.as_str(),
+        market_type(msg.market_type)?,
+        msg_type(msg.msg_type)?,
+        msg.pair.as_str(),
+        msg.symbol.as_str(),
+        msg.timestamp,
+        side,
+        msg.price,
+        msg.quantity_base,
+        msg.quantity_quote,
+        msg.quantity_contract,
+        msg.trade_id.as_str(),
+        msg.json.as_str(),
+    ))
+}
+
+fn create_orders(builder: &mut Builder, orders: &[Order]) -> Offset<[Offset<fb::Order>]> {
+    let orders: Vec<_> = orders
+        .iter()
+        .map(|order| {
+            fb::Order::create(
+                builder,
+                order.price,
+                order.quantity_base,
+                order.quantity_quote,
+                order.quantity_contract,
+            )
+        })
+        .collect();
+    builder.create_vector(orders)
+}
+
+fn create_orderbook(
+    builder: &mut Builder,
+    msg: &OrderBookMsg,
+) -> Result<Offset<fb::OrderBook>, String> {
+    let asks = create_orders(builder, &msg.asks);
+    let bids = create_orders(builder, &msg.bids);
+    Ok(fb::OrderBook::create(
+        builder,
+        msg.exchange.as_str(),
+        market_type(msg.market_type)?,
+        msg_type(msg.msg_type)?,
+        msg.pair.as_str(),
+        msg.symbol.as_str(),
+        msg.timestamp,
+        msg.snapshot,
+        asks,
+        bids,
+        msg.seq_id,
+        msg.prev_seq_id,
+        msg.json.as_str(),
+    ))
+}
+
+fn create_bbo(builder: &mut Builder, msg: &BboMsg) -> Result<Offset<fb::Bbo>, String> {
+    Ok(fb::Bbo::create(
+        builder,
+        msg.exchange.as_str(),
+        market_type(msg.market_type)?,
+        msg_type(msg.msg_type)?,
+        msg.pair.as_str(),
+        msg.symbol.as_str(),
+        msg.timestamp,
+        msg.bid_price,
+        msg.bid_quantity_base,
+        msg.bid_quantity_quote,
+        msg.bid_quantity_contract,
+        msg.ask_price,
+        msg.ask_quantity_base,
+        msg.ask_quantity_quote,
+        msg.ask_quantity_contract,
+        msg.id,
+        msg.json.as_str(),
+    ))
+}
+
+/// Encode normalized messages of the same message type as a `Messages` root table.
+pub fn encode(msgs: &[Message]) -> Result<Vec<u8>, String> {
+    let mut builder = Builder::new();
+    let mut trades = Vec::new();
+    let mut orderbooks = Vec::new();
+    let mut bbos = Vec::new();
+    for msg in msgs {
+        if msg.msg_type() != msgs[0].msg_type() {
+            return Err(format!("Unexpected message type {}", msg.msg_type()));
+        }
+        match msg {
+            Message::Trade(m) => trades.push(create_trade(&mut builder, m)?),
+            Message::OrderBook(m) => orderbooks.push(create_orderbook(&mut builder, m)?),
+            Message::Bbo(m) => bbos.push(create_bbo(&mut builder, m)?),
+            _ => {
+                return Err(format!(
+                    "FlatBuffers doesn't support message type {}",
+                    msg.msg_type()
+                ))
+            }
+        }
+    }
+
+    // only the vector of the message type is present, trades if there are no messages
+    let root = if !orderbooks.is_empty() {
+        fb::Messages::create(&mut builder, (), orderbooks, ())
+    } else if !bbos.is_empty() {
+        fb::Messages::create(&mut builder, (), (), bbos)
+    } else {
+        fb::Messages::create(&mut builder, trades, (), ())
+    };
+    Ok(builder.finish(root, None).to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode;
+    use crate::{fbs::crypto_msg_parser::fb, message::parse_msg};
+    use crypto_market_type::MarketType;
+    use crypto_msg_type::MessageType;
+    use planus::ReadAsRoot;
+
+    #[test]
+    fn test_encode_trade() {
+        let raw_msg = r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::Trade,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+        let bytes = encode(&msgs).unwrap();
+
+        let root = fb::MessagesRef::read_as_root(&bytes).unwrap();
+        assert!(root.orderbooks().unwrap().is_none());
+        assert!(root.bbos().unwrap().is_none());
+        let trades = root.trades().unwrap().unwrap();
+        assert_eq!(1, trades.len());
+
+        let trade = trades.get(0).unwrap().unwrap();
+        assert_eq!(Some("binance"), trade.exchange().unwrap());
+        assert_eq!(fb::MarketType::InverseSwap, trade.market_type().unwrap());
+        assert_eq!(fb::MessageType::Trade, trade.msg_type().unwrap());
+        assert_eq!(Some("BTC/USD"), trade.pair().unwrap());
+        assert_eq!(Some("BTCUSD_PERP"), trade.symbol().unwrap());
+        assert_eq!(1616201883458, trade.timestamp().unwrap());
+        assert_eq!(fb::TradeSide::Sell, trade.side().unwrap());
+        assert_eq!(58570.1, trade.price().unwrap());
+        assert_eq!(Some(58.0), trade.quantity_contract().unwrap());
+        assert_eq!(Some("41045788"), trade.trade_id().unwrap());
+        assert_eq!(Some(raw_msg), trade.json().unwrap());
+    }
+
+    #[test]
+    fn test_encode_l2() {
+        let raw_msg = r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::L2Event,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+        let bytes = encode(&msgs).unwrap();
+
+        let root = fb::MessagesRef::read_as_root(&bytes).unwrap();
+        assert!(root.trades().unwrap().is_none());
+        let orderbooks = root.orderbooks().unwrap().unwrap();
+        assert_eq!(1, orderbooks.len());
+
+        let orderbook = orderbooks.get(0).unwrap().unwrap();
+        assert_eq!(Some("binance"), orderbook.exchange().unwrap());
+        assert_eq!(
+            fb::MarketType::InverseSwap,
+            orderbook.market_type().unwrap()
+        );
+        assert_eq!(fb::MessageType::L2Event, orderbook.msg_type().unwrap());
+        assert_eq!(1622370862564, orderbook.timestamp().unwrap());
+        assert!(!orderbook.snapshot().unwrap());
+        assert_eq!(Some(127559588177), orderbook.seq_id().unwrap());
+        assert_eq!(Some(127559587113), orderbook.prev_seq_id().unwrap());
+
+        let bids = orderbook.bids().unwrap().unwrap();
+        assert_eq!(2, bids.len());
+        let bid = bids.get(0).unwrap().unwrap();
+        assert_eq!(35365.9, bid.price().unwrap());
+        assert_eq!(Some(1400.0), bid.quantity_contract().unwrap());
+        let asks = orderbook.asks().unwrap().unwrap();
+        assert_eq!(2, asks.len());
+        assert_eq!(35817.8, asks.get(0).unwrap().unwrap().price().unwrap());
+    }
+
+    #[test]
+    fn test_encode_spot_bbo() {
+        let raw_msg = r#"{"stream":"btcusdt@bookTicker","data":{"u":7016594716,"s":"BTCUSDT","b":"57573.82000000","B":"0.23584900","a":"57573.83000000","A":"1.71611200"}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::Spot,
+            MessageType::BBO,
+            raw_msg,
+            1616201883500,
+        )
+        .unwrap();
+        let bytes = encode(&msgs).unwrap();
+
+        let root = fb::MessagesRef::read_as_root(&bytes).unwrap();
+        let bbos = root.bbos().unwrap().unwrap();
+        assert_eq!(1, bbos.len());
+
+        let bbo = bbos.get(0).unwrap().unwrap();
+        assert_eq!(fb::MarketType::Spot, bbo.market_type().unwrap());
+        assert_eq!(fb::MessageType::Bbo, bbo.msg_type().unwrap());
+        assert_eq!(57573.82, bbo.bid_price().unwrap());
+        assert_eq!(57573.83, bbo.ask_price().unwrap());
+        assert_eq!(None, bbo.bid_quantity_contract().unwrap());
+        assert_eq!(Some(7016594716), bbo.id().unwrap());
+    }
+
+    #[test]
+    fn test_encode_empty() {
+        let bytes = encode(&[]).unwrap();
+        let root = fb::MessagesRef::read_as_root(&bytes).unwrap();
+        assert_eq!(0, root.trades().unwrap().unwrap().len());
+    }
+
+    #[test]
+    fn test_encode_unsupported() {
+        let raw_msg = r#"{"stream":"btcusd_perp@markPrice","data":{"e":"markPriceUpdate","E":1617309477000,"s":"BTCUSD_PERP","p":"59012.56007222","P":"58896.00503145","r":"0.00073689","T":1617321600000}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::FundingRate,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+        assert!(encode(&msgs).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    csv::ToCsv,
+    decimal::{self, Decimals, NumberFormat},
+    message::Message,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::{ffi::CStr, os::raw::c_char};
+
+/// Serialization format of normalized messages.
+///
+/// All formats keep the field names of the JSON output.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json = 0,
+    MessagePack = 1,
+    Cbor = 2,
+    /// A list message defined in `proto/crypto_msg_parser.proto`, e.g., `TradeList`
+    Protobuf = 3,
+    /// A `Messages` root table defined in `fbs/crypto_msg_parser.fbs`, only
+    /// trade, orderbook and BBO messages are supported
+    FlatBuffers = 4,
+    /// CSV rows without the header row, see `csv_header()` and the `csv` module
+    Csv = 5,
+}
+
+/// Options of the `parse_*_with_options()` functions.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub format: OutputFormat,
+    /// Only JSON, MessagePack and CBOR support number formats other than `Float`
+    pub number_format: NumberFormat,
+    /// Comma separated names of fields to keep, e.g., `"timestamp,price,quantity_base,side"`,
+    /// NULL means all fields.
+    ///
+    /// Only JSON, MessagePack and CBOR support it. Exponent fields of the
+    /// `FixedPoint` number format are always kept.
+    pub fields: *const c_char,
+    /// Replace the original message in the `json` field with an empty string,
+    /// which roughly halves the output size.
+    pub omit_json: bool,
+}
+
+impl ParseOptions {
+    /// Names of fields to keep, None means all fields.
+    ///
+    /// Returns an error if `fields` isn't valid UTF-8.
+    pub fn field_names(&self) -> Result<Option<Vec<&str>>, String> {
+        if self.fields.is_null() {
+            return Ok(None);
+        }
+        let fields = unsafe { CStr::from_ptr(self.fields) }
+            .to_str()
+            .map_err(|err| format!("Invalid fields: {err}"))?;
+        Ok(Some(
+            fields
+                .split(',')
+                .map(str::trim)
+                .filter(|field| !field.is_empty())
+                .collect(),
+        ))
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            format: OutputFormat::Json,
+            number_format: NumberFormat::Float,
+            fields: std::ptr::null(),
+            omit_json: false,
+        }
+    }
+}
+
+/// Get the default options, i.e., JSON output with floating point numbers.
+#[no_mangle]
+pub extern "C" fn default_parse_options() -> ParseOptions {
+    ParseOptions::default()
+}
+
+/// A byte buffer allocated by this library.
+///
+/// `data` is NULL if an error happens, call `deallocate_bytes()` to free it.
+#[repr(C)]
+pub struct ByteBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl ByteBuffer {
+    pub fn null() -> Self {
+        ByteBuffer {
+            data: std::ptr::null_mut(),
+            len: 0,
+        }
+    }
+}
+
+impl From<Vec<u8>> for ByteBuffer {
+    fn from(bytes: Vec<u8>) -> Self {
+        let boxed = bytes.into_boxed_slice();
+        let len = boxed.len();
+        ByteBuffer {
+            data: Box::into_raw(boxed) as *mut u8,
+            len,
+        }
+    }
+}
+
+fn serialize_self_describing<T: Serialize + ?Sized>(
+    value: &T,
+    format: OutputFormat,
+) -> Result<Vec<u8>, String> {
+    match format {
+        OutputFormat::Json => serde_json::to_vec(value).map_err(|err| err.to_string()),
+        OutputFormat::MessagePack => rmp_serde::to_vec_named(value).map_err(|err| err.to_string()),
+        OutputFormat::Cbor => {
+            let mut bytes = Vec::new();
+            ciborium::into_writer(value, &mut bytes).map_err(|err| err.to_string())?;
+            Ok(bytes)
+        }
+        OutputFormat::Protobuf | OutputFormat::FlatBuffers | OutputFormat::Csv => unreachable!(),
+    }
+}
+
+/// Serialize normalized messages in the given format.
+pub fn serialize(msgs: &[Message], format: OutputFormat) -> Result<Vec<u8>, String> {
+    match format {
+        OutputFormat::Protobuf => crate::proto::encode(msgs),
+        OutputFormat::FlatBuffers => crate::flatbuffers::encode(msgs),
+        OutputFormat::Csv => Ok(msgs.to_csv().into_bytes()),
+        _ => serialize_self_describing(msgs, format),
+    }
+}
+
+/// Remove fields not in `fields` from each message.
+fn retain_fields(value: &mut Value, fields: &[&str]) {
+    for obj in value
+        .as_array_mut()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object_mut)
+    {
+        obj.retain(|key, _| fields.contains(&key.as_str()) || key.ends_with("_exponent"));
+    }
+}
+
+/// Serialize normalized messages with the given options.
+///
+/// `raw_msg` is the text the messages are parsed from, number formats other
+/// than `Float` take the decimal strings from it.
+pub fn serialize_with_options(
+    msgs: &[Message],
+    raw_msg: Option<&str>,
+    options: &ParseOptions,
+) -> Result<Vec<u8>, String> {
+    let fields = options.field_names()?;
+    if options.number_format == NumberFormat::Float && fields.is_none() {
+        return serialize(msgs, options.format);
+    }
+    match options.format {
+        OutputFormat::Protobuf | OutputFormat::FlatBuffers | OutputFormat::Csv => Err(format!(
+            "{:?} only supports the default number format and fields",
+            options.format
+        )),
+        _ => {
+            let decimals = match (options.number_format, raw_msg) {
+                (_, Some(raw_msg)) => Decimals::scan(raw_msg),
+                (NumberFormat::Float, None) => Decimals::default(),
+                (number_format, None) => {
+                    return Err(format!("{number_format:?} needs a text message"))
+                }
+            };
+            let mut value = decimal::to_value(msgs, &decimals, options.number_format)?;
+            if let Some(fields) = fields {
+                retain_fields(&mut value, &fields);
+            }
+            serialize_self_describing(&value, options.format)
+        }
+    }
+}
+
+/// Deallocate a byte buffer.
+#[no_mangle]
+pub extern "C" fn deallocate_bytes(buffer: ByteBuffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+            buffer.data,
+            buffer.len,
+        )));
+    }
+}
@@ -0,0 +1,417 @@
+//! JNI bindings of the `cryptomsgparser.CryptoMsgParser` class, see `java/`.
+//!
+//! Normalized messages are returned as arrays of POJOs created by their
+//! package-private constructors. Parse errors are thrown as
+//! `cryptomsgparser.ParseException`.
+
+use crate::message::{catch_panic, parse_msg, Message};
+use crypto_market_type::MarketType;
+use crypto_message::{BboMsg, CandlestickMsg, FundingRateMsg, Order, OrderBookMsg, TradeMsg};
+use crypto_msg_type::MessageType;
+use jni::{
+    objects::{JClass, JObject, JString, JValue},
+    sys::{jlong, jobjectArray, jstring},
+    JNIEnv,
+};
+use std::str::FromStr;
+
+const PARSE_EXCEPTION: &str = "cryptomsgparser/ParseException";
+
+/// Constructor parameters shared by all message types: exchange, marketType,
+/// msgType, pair, symbol, timestamp and json.
+const COMMON_SIG: &str = "Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;\
+                          Ljava/lang/String;Ljava/lang/String;JLjava/lang/String;";
+
+enum Error {
+    Jni(jni::errors::Error),
+    Argument(String),
+    Parse(String),
+}
+
+impl From<jni::errors::Error> for Error {
+    fn from(err: jni::errors::Error) -> Self {
+        Error::Jni(err)
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// Throw the error as a Java exception, unless one is already pending.
+fn throw(env: &mut JNIEnv, err: Error) {
+    let result = match err {
+        Error::Jni(jni::errors::Error::JavaException) => Ok(()),
+        Error::Jni(err) => env.throw_new("java/lang/RuntimeException", err.to_string()),
+        Error::Argument(msg) => env.throw_new("java/lang/IllegalArgumentException", msg),
+        Error::Parse(msg) => env.throw_new(PARSE_EXCEPTION, msg),
+    };
+    if let Err(err) = result {
+        eprintln!("Failed to throw a Java exception, error: {err}");
+    }
+}
+
+fn market_type_from_java(env: &mut JNIEnv, market_type: &JString) -> Result<MarketType> {
+    let market_type: String = env.get_string(market_type)?.into();
+    MarketType::from_str(&market_type)
+        .map_err(|_| Error::Argument(format!("Unknown market type {market_type}")))
+}
+
+fn boxed_double<'local>(env: &mut JNIEnv<'local>, value: Option<f64>) -> Result<JObject<'local>> {
+    match value {
+        Some(value) => Ok(env
+            .call_static_method(
+                "java/lang/Double",
+                "valueOf",
+                "(D)Ljava/lang/Double;",
+                &[JValue::Double(value)],
+            )?
+            .l()?),
+        None => Ok(JObject::null()),
+    }
+}
+
+fn boxed_long<'local>(env: &mut JNIEnv<'local>, value: Option<u64>) -> Result<JObject<'local>> {
+    match value {
+        Some(value) => Ok(env
+            .call_static_method(
+                "java/lang/Long",
+                "valueOf",
+                "(J)Ljava/lang/Long;",
+                &[JValue::Long(value as i64)],
+            )?
+            .l()?),
+        None => Ok(JObject::null()),
+    }
+}
+
+/// Java strings of the common fields, in the order of `COMMON_SIG`.
+struct Common<'local> {
+    exchange: JObject<'local>,
+    market_type: JObject<'local>,
+    msg_type: JObject<'local>,
+    pair: JObject<'local>,
+    symbol: JObject<'local>,
+    timestamp: i64,
+    json: JObject<'local>,
+}
+
+macro_rules! common {
+    ($env:expr, $msg:expr) => {
+        Common {
+            exchange: $env.new_string(&$msg.exchange)?.into(),
+            market_type: $env.new_string($msg.market_type.to_string())?.into(),
+            msg_type: $env.new_string($msg.msg_type.to_string())?.into(),
+            pair: $env.new_string(&$msg.pair)?.into(),
+            symbol: $env.new_string(&$msg.symbol)?.into(),
+            timestamp: $msg.timestamp,
+            json: $env.new_string(&$msg.json)?.into(),
+        }
+    };
+}
+
+impl<'local> Common<'local> {
+    fn args<'a>(&'a self) -> Vec<JValue<'local, 'a>> {
+        vec![
+            JValue::Object(&self.exchange),
+            JValue::Object(&self.market_type),
+            JValue::Object(&self.msg_type),
+            JValue::Object(&self.pair),
+            JValue::Object(&self.symbol),
+            JValue::Long(self.timestamp),
+            JValue::Object(&self.json),
+        ]
+    }
+}
+
+fn new_trade<'local>(env: &mut JNIEnv<'local>, msg: &TradeMsg) -> Result<JObject<'local>> {
+    let common = common!(env, msg);
+    let side: JObject = env.new_string(msg.side.to_string())?.into();
+    let quantity_contract = boxed_double(env, msg.quantity_contract)?;
+    let trade_id: JObject = env.new_string(&msg.trade_id)?.into();
+    let mut args = common.args();
+    args.extend([
+        JValue::Object(&side),
+        JValue::Double(msg.price),
+        JValue::Double(msg.quantity_base),
+        JValue::Double(msg.quantity_quote),
+        JValue::Object(&quantity_contract),
+        JValue::Object(&trade_id),
+    ]);
+    let sig = format!("({COMMON_SIG}Ljava/lang/String;DDDLjava/lang/Double;Ljava/lang/String;)V");
+    Ok(env.new_object("cryptomsgparser/TradeMsg", sig, &args)?)
+}
+
+fn new_orders<'local>(env: &mut JNIEnv<'local>, orders: &[Order]) -> Result<JObject<'local>> {
+    let class = env.find_class("cryptomsgparser/Order")?;
+    let array = env.new_object_array(orders.len() as i32, &class, JObject::null())?;
+    for (i, order) in orders.iter().enumerate() {
+        let obj = env.with_local_frame_returning_local(8, |env| -> Result<JObject> {
+            let quantity_contract = boxed_double(env, order.quantity_contract)?;
+            Ok(env.new_object(
+                "cryptomsgparser/Order",
+                "(DDDLjava/lang/Double;)V",
+                &[
+                    JValue::Double(order.price),
+                    JValue::Double(order.quantity_base),
+                    JValue::Double(order.quantity_quote),
+                    JValue::Object(&quantity_contract),
+                ],
+            )?)
+        })?;
+        env.set_object_array_element(&array, i as i32, &obj)?;
+        env.delete_local_ref(obj)?;
+    }
+    Ok(array.into())
+}
+
+fn new_orderbook<'local>(env: &mut JNIEnv<'local>, msg: &OrderBookMsg) -> Result<JObject<'local>> {
+    let common = common!(env, msg);
+    let asks = new_orders(env, &msg.asks)?;
+    let bids = new_orders(env, &msg.bids)?;
+    let seq_id = boxed_long(env, msg.seq_id)?;
+    let prev_seq_id = boxed_long(env, msg.prev_seq_id)?;
+    let mut args = common.args();
+    args.extend([
+        JValue::Bool(msg.snapshot.into()),
+        JValue::Object(&asks),
+        JValue::Object(&bids),
+        JValue::Object(&seq_id),
+        JValue::Object(&prev_seq_id),
+    ]);
+    let sig = format!(
+        "({COMMON_SIG}Z[Lcryptomsgparser/Order;[Lcryptomsgparser/Order;Ljava/lang/Long;Ljava/lang/Long;)V"
+    );
+    Ok(env.new_object("cryptomsgparser/OrderBookMsg", sig, &args)?)
+}
+
+fn new_bbo<'local>(env: &mut JNIEnv<'local>, msg: &BboMsg) -> Result<JObject<'local>> {
+    let common = common!(env, msg);
+    let bid_quantity_contract = boxed_double(env, msg.bid_quantity_contract)?;
+    let ask_quantity_contract = boxed_double(env, msg.ask_quantity_contract)?;
+    let id = boxed_long(env, msg.id)?;
+    let mut args = common.args();
+    args.extend([
+        JValue::Double(msg.bid_price),
+        JValue::Double(msg.bid_quantity_base),
+        JValue::Double(msg.bid_quantity_quote),
+        JValue::Object(&bid_quantity_contract),
+        JValue::Double(msg.ask_price),
+        JValue::Double(msg.ask_quantity_base),
+        JValue::Double(msg.ask_quantity_quote),
+        JValue::Object(&ask_quantity_contract),
+        JValue::Object(&id),
+    ]);
+    let sig = format!("({COMMON_SIG}DDDLjava/lang/Double;DDDLjava/lang/Double;Ljava/lang/Long;)V");
+    Ok(env.new_object("cryptomsgparser/BboMsg", sig, &args)?)
+}
+
+fn new_funding_rate<'local>(
+    env: &mut JNIEnv<'local>,
+    msg: &FundingRateMsg,
+) -> Result<JObject<'local>> {
+    let common = common!(env, msg);
+    let estimated_rate = boxed_double(env, msg.estimated_rate)?;
+    let mut args = common.args();
+    args.extend([
+        JValue::Double(msg.funding_rate),
+        JValue::Long(msg.funding_time),
+        JValue::Object(&estimated_rate),
+    ]);
+    let sig = format!("({COMMON_SIG}DJLjava/lang/Double;)V");
+    Ok(env.new_object("cryptomsgparser/FundingRateMsg", sig, &args)?)
+}
+
+fn new_candlestick<'local>(
+    env: &mut JNIEnv<'local>,
+    msg: &CandlestickMsg,
+) -> Result<JObject<'local>> {
+    let common = common!(env, msg);
+    let period: JObject = env.new_string(&msg.period)?.into();
+    let quote_volume = boxed_double(env, msg.quote_volume)?;
+    let mut args = common.args();
+    args.extend([
+        JValue::Long(msg.begin_time),
+        JValue::Double(msg.open),
+        JValue::Double(msg.high),
+        JValue::Double(msg.low),
+        JValue::Double(msg.close),
+        JValue::Double(msg.volume),
+        JValue::Object(&period),
+        JValue::Object(&quote_volume),
+    ]);
+    let sig = format!("({COMMON_SIG}JDDDDDLjava/lang/String;Ljava/lang/Double;)V");
+    Ok(env.new_object("cryptomsgparser/CandlestickMsg", sig, &args)?)
+}
+
+fn new_message<'local>(env: &mut JNIEnv<'local>, msg: &Message) -> Result<JObject<'local>> {
+    match msg {
+        Message::Trade(msg) => new_trade(env, msg),
+        Message::OrderBook(msg) => new_orderbook(env, msg),
+        Message::Bbo(msg) => new_bbo(env, msg),
+        Message::FundingRate(msg) => new_funding_rate(env, msg),
+        Message::Candlestick(msg) => new_candlestick(env, msg),
+    }
+}
+
+/// Parse a raw message into an array of `class`.
+fn parse<'local>(
+    env: &mut JNIEnv<'local>,
+    exchange: &JString,
+    market_type: &JString,
+    msg_type: MessageType,
+    msg: &JString,
+    received_at: jlong,
+    class: &str,
+) -> Result<JObject<'local>> {
+    let exchange: String = env.get_string(exchange)?.into();
+    let market_type = market_type_from_java(env, market_type)?;
+    let msg: String = env.get_string(msg)?.into();
+    let msgs =
+        parse_msg(&exchange, market_type, msg_type, &msg, received_at).map_err(Error::Parse)?;
+
+    let class = env.find_class(class)?;
+    let array = env.new_object_array(msgs.len() as i32, &class, JObject::null())?;
+    for (i, msg) in msgs.iter().enumerate() {
+        let obj = env.with_local_frame_returning_local(32, |env| new_message(env, msg))?;
+        env.set_object_array_element(&array, i as i32, &obj)?;
+        env.delete_local_ref(obj)?;
+    }
+    Ok(array.into())
+}
+
+macro_rules! parse_fn {
+    ($name:ident, $msg_type:expr, $class:literal) => {
+        #[no_mangle]
+        pub extern "system" fn $name<'local>(
+            mut env: JNIEnv<'local>,
+            _class: JClass<'local>,
+            exchange: JString<'local>,
+            market_type: JString<'local>,
+            msg: JString<'local>,
+            received_at: jlong,
+        ) -> jobjectArray {
+            match parse(
+                &mut env,
+                &exchange,
+                &market_type,
+                $msg_type,
+                &msg,
+                received_at,
+                $class,
+            ) {
+                Ok(array) => array.into_raw(),
+                Err(err) => {
+                    throw(&mut env, err);
+                    std::ptr::null_mut()
+                }
+            }
+        }
+    };
+}
+
+parse_fn!(
+    Java_cryptomsgparser_CryptoMsgParser_nativeParseTrade,
+    MessageType::Trade,
+    "cryptomsgparser/TradeMsg"
+);
+parse_fn!(
+    Java_cryptomsgparser_CryptoMsgParser_nativeParseL2,
+    MessageType::L2Event,
+    "cryptomsgparser/OrderBookMsg"
+);
+parse_fn!(
+    Java_cryptomsgparser_CryptoMsgParser_nativeParseL2Topk,
+    MessageType::L2TopK,
+    "cryptomsgparser/OrderBookMsg"
+);
+parse_fn!(
+    Java_cryptomsgparser_CryptoMsgParser_nativeParseBbo,
+    MessageType::BBO,
+    "cryptomsgparser/BboMsg"
+);
+parse_fn!(
+    Java_cryptomsgparser_CryptoMsgParser_nativeParseFundingRate,
+    MessageType::FundingRate,
+    "cryptomsgparser/FundingRateMsg"
+);
+parse_fn!(
+    Java_cryptomsgparser_CryptoMsgParser_nativeParseCandlestick,
+    MessageType::Candlestick,
+    "cryptomsgparser/CandlestickMsg"
+);
+
+#[no_mangle]
+pub extern "system" fn Java_cryptomsgparser_CryptoMsgParser_nativeExtractSymbol<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    exchange: JString<'local>,
+    market_type: JString<'local>,
+    msg: JString<'local>,
+) -> jstring {
+    let result = (|| -> Result<JString> {
+        let exchange: String = env.get_string(&exchange)?.into();
+        let market_type = market_type_from_java(&mut env, &market_type)?;
+        let msg: String = env.get_string(&msg)?.into();
+        let symbol =
+            catch_panic(|| crypto_msg_parser::extract_symbol(&exchange, market_type, &msg))
+                .map_err(Error::Parse)?;
+        Ok(env.new_string(symbol)?)
+    })();
+    match result {
+        Ok(symbol) => symbol.into_raw(),
+        Err(err) => {
+            throw(&mut env, err);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Returns 0 if the message doesn't have a timestamp.
+#[no_mangle]
+pub extern "system" fn Java_cryptomsgparser_CryptoMsgParser_nativeExtractTimestamp<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    exchange: JString<'local>,
+    market_type: JString<'local>,
+    msg: JString<'local>,
+) -> jlong {
+    let result = (|| -> Result<jlong> {
+        let exchange: String = env.get_string(&exchange)?.into();
+        let market_type = market_type_from_java(&mut env, &market_type)?;
+        let msg: String = env.get_string(&msg)?.into();
+        let timestamp =
+            catch_panic(|| crypto_msg_parser::extract_timestamp(&exchange, market_type, &msg))
+                .map_err(Error::Parse)?;
+        Ok(timestamp.unwrap_or(0))
+    })();
+    match result {
+        Ok(timestamp) => timestamp,
+        Err(err) => {
+            throw(&mut env, err);
+            -1
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_cryptomsgparser_CryptoMsgParser_nativeGetMsgType<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    exchange: JString<'local>,
+    msg: JString<'local>,
+) -> jstring {
+    let result = (|| -> Result<JString> {
+        let exchange: String = env.get_string(&exchange)?.into();
+        let msg: String = env.get_string(&msg)?.into();
+        let msg_type =
+            catch_panic(|| Ok::<_, String>(crypto_msg_parser::get_msg_type(&exchange, &msg)))
+                .map_err(Error::Parse)?;
+        Ok(env.new_string(msg_type.to_string())?)
+    })();
+    match result {
+        Ok(msg_type) => msg_type.into_raw(),
+        Err(err) => {
+            throw(&mut env, err);
+            std::ptr::null_mut()
+        }
+    }
+}
@@ -1,6 +1,32 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
+pub mod abi;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod compression;
+pub mod csv;
+pub mod decimal;
+mod enum_assertions;
+pub mod error;
+pub mod fbs;
+pub mod flatbuffers;
+pub mod format;
+#[cfg(feature = "jni")]
+pub mod jni;
+pub mod message;
+#[cfg(feature = "node")]
+pub mod node;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+pub mod pb;
+pub mod proto;
+pub mod reader;
+pub mod sbe;
+
+use compression::{decompress_frame, Compression};
 use crypto_market_type::MarketType;
 use crypto_msg_type::MessageType;
+use format::{ByteBuffer, OutputFormat, ParseOptions};
+use message::{parse_msg, parse_payload, Message, PayloadEncoding};
 
 use std::{
     ffi::{CStr, CString},
@@ -354,6 +380,395 @@ pub extern "C" fn parse_candlestick(
     }
 }
 
+/// Get the CSV header row of a message type, returns NULL if the message
+/// type has no CSV representation.
+///
+/// See the `csv` module for the columns of each message type.
+#[no_mangle]
+pub extern "C" fn csv_header(msg_type: MessageType) -> *const c_char {
+    match csv::csv_header(msg_type) {
+        Some(header) => {
+            let raw = CString::new(header).unwrap();
+            raw.into_raw() as *const c_char
+        }
+        None => std::ptr::null(),
+    }
+}
+
+/// Parse a raw message with the `parse_*` function matching `msg_type`, then
+/// serialize the messages with the given options.
+fn parse_and_serialize(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg_type: MessageType,
+    msg: *const c_char,
+    received_at: i64,
+    options: ParseOptions,
+) -> ByteBuffer {
+    error::clear_last_error();
+    let exchange_rust = unsafe {
+        debug_assert!(!exchange.is_null());
+        CStr::from_ptr(exchange).to_str().unwrap()
+    };
+    let msg_rust = unsafe {
+        debug_assert!(!msg.is_null());
+        CStr::from_ptr(msg).to_str().unwrap()
+    };
+
+    match parse_msg(exchange_rust, market_type, msg_type, msg_rust, received_at)
+        .and_then(|msgs| serialize_msgs(msgs, Some(msg_rust), &options))
+    {
+        Ok(bytes) => ByteBuffer::from(bytes),
+        Err(err) => {
+            error::set_last_error(&err.to_string());
+            ByteBuffer::null()
+        }
+    }
+}
+
+/// Serialize messages parsed from `raw_msg` with the given options.
+fn serialize_msgs(
+    mut msgs: Vec<Message>,
+    raw_msg: Option<&str>,
+    options: &ParseOptions,
+) -> Result<Vec<u8>, String> {
+    if options.omit_json {
+        msgs.iter_mut().for_each(Message::clear_json);
+    }
+    format::serialize_with_options(&msgs, raw_msg, options)
+}
+
+/// Parse a raw trade message into a Vec<TradeMsg> and then serialize it in the given format.
+#[no_mangle]
+pub extern "C" fn parse_trade_with_format(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    format: OutputFormat,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::Trade,
+        msg,
+        0,
+        ParseOptions {
+            format,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse a raw level2 orderbook message into a Vec<OrderBookMsg> and then serialize it in the given format.
+#[no_mangle]
+pub extern "C" fn parse_l2_with_format(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    format: OutputFormat,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::L2Event,
+        msg,
+        received_at,
+        ParseOptions {
+            format,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse a level2 topk orderbook message into a Vec<OrderBookMsg> and then serialize it in the given format.
+#[no_mangle]
+pub extern "C" fn parse_l2_topk_with_format(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    format: OutputFormat,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::L2TopK,
+        msg,
+        received_at,
+        ParseOptions {
+            format,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse a BBO(best bid&offer) message into a Vec<BboMsg> and then serialize it in the given format.
+#[no_mangle]
+pub extern "C" fn parse_bbo_with_format(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    format: OutputFormat,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::BBO,
+        msg,
+        received_at,
+        ParseOptions {
+            format,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse a raw funding rate message into a Vec<FundingRateMsg> and then serialize it in the given format.
+#[no_mangle]
+pub extern "C" fn parse_funding_rate_with_format(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    format: OutputFormat,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::FundingRate,
+        msg,
+        received_at,
+        ParseOptions {
+            format,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse a raw candlestick message into a Vec<CandlestickMsg> and then serialize it in the given format.
+#[no_mangle]
+pub extern "C" fn parse_candlestick_with_format(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    format: OutputFormat,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::Candlestick,
+        msg,
+        received_at,
+        ParseOptions {
+            format,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse a raw trade message into a Vec<TradeMsg> and then serialize it with the given options.
+#[no_mangle]
+pub extern "C" fn parse_trade_with_options(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    options: ParseOptions,
+) -> ByteBuffer {
+    parse_and_serialize(exchange, market_type, MessageType::Trade, msg, 0, options)
+}
+
+/// Parse a raw level2 orderbook message into a Vec<OrderBookMsg> and then serialize it with the given options.
+#[no_mangle]
+pub extern "C" fn parse_l2_with_options(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    options: ParseOptions,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::L2Event,
+        msg,
+        received_at,
+        options,
+    )
+}
+
+/// Parse a level2 topk orderbook message into a Vec<OrderBookMsg> and then serialize it with the given options.
+#[no_mangle]
+pub extern "C" fn parse_l2_topk_with_options(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    options: ParseOptions,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::L2TopK,
+        msg,
+        received_at,
+        options,
+    )
+}
+
+/// Parse a BBO(best bid&offer) message into a Vec<BboMsg> and then serialize it with the given options.
+#[no_mangle]
+pub extern "C" fn parse_bbo_with_options(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    options: ParseOptions,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::BBO,
+        msg,
+        received_at,
+        options,
+    )
+}
+
+/// Parse a raw funding rate message into a Vec<FundingRateMsg> and then serialize it with the given options.
+#[no_mangle]
+pub extern "C" fn parse_funding_rate_with_options(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    options: ParseOptions,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::FundingRate,
+        msg,
+        received_at,
+        options,
+    )
+}
+
+/// Parse a raw candlestick message into a Vec<CandlestickMsg> and then serialize it with the given options.
+#[no_mangle]
+pub extern "C" fn parse_candlestick_with_options(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+    options: ParseOptions,
+) -> ByteBuffer {
+    parse_and_serialize(
+        exchange,
+        market_type,
+        MessageType::Candlestick,
+        msg,
+        received_at,
+        options,
+    )
+}
+
+/// Parse a possibly compressed raw message of `len` bytes with the `parse_*`
+/// function matching `msg_type`, then serialize the messages with the given options.
+///
+/// The message is decompressed according to `compression` before parsing,
+/// `received_at` is ignored by trade messages.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn parse_compressed(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg_type: MessageType,
+    data: *const u8,
+    len: usize,
+    compression: Compression,
+    received_at: i64,
+    options: ParseOptions,
+) -> ByteBuffer {
+    error::clear_last_error();
+    let exchange_rust = unsafe {
+        debug_assert!(!exchange.is_null());
+        CStr::from_ptr(exchange).to_str().unwrap()
+    };
+    let data_rust = unsafe {
+        debug_assert!(!data.is_null());
+        std::slice::from_raw_parts(data, len)
+    };
+
+    let msg_rust = match decompress_frame(data_rust, compression) {
+        Ok(text) => text,
+        Err(err) => {
+            error::set_last_error(&err.to_string());
+            return ByteBuffer::null();
+        }
+    };
+    match parse_msg(exchange_rust, market_type, msg_type, &msg_rust, received_at)
+        .and_then(|msgs| serialize_msgs(msgs, Some(&msg_rust), &options))
+    {
+        Ok(bytes) => ByteBuffer::from(bytes),
+        Err(err) => {
+            error::set_last_error(&err.to_string());
+            ByteBuffer::null()
+        }
+    }
+}
+
+/// Parse a raw message of `len` bytes in the given encoding, then serialize
+/// the messages with the given options.
+///
+/// Binary payloads such as `BinanceSbe` produce the same normalized messages
+/// as JSON ones, with an empty `json` field.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn parse_payload_bytes(
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg_type: MessageType,
+    data: *const u8,
+    len: usize,
+    encoding: PayloadEncoding,
+    received_at: i64,
+    options: ParseOptions,
+) -> ByteBuffer {
+    error::clear_last_error();
+    let exchange_rust = unsafe {
+        debug_assert!(!exchange.is_null());
+        CStr::from_ptr(exchange).to_str().unwrap()
+    };
+    let data_rust = unsafe {
+        debug_assert!(!data.is_null());
+        std::slice::from_raw_parts(data, len)
+    };
+    let raw_msg = match encoding {
+        PayloadEncoding::Json => std::str::from_utf8(data_rust).ok(),
+        PayloadEncoding::BinanceSbe => None,
+    };
+
+    match parse_payload(
+        exchange_rust,
+        market_type,
+        msg_type,
+        data_rust,
+        encoding,
+        received_at,
+    )
+    .and_then(|msgs| serialize_msgs(msgs, raw_msg, &options))
+    {
+        Ok(bytes) => ByteBuffer::from(bytes),
+        Err(err) => {
+            error::set_last_error(&err.to_string());
+            ByteBuffer::null()
+        }
+    }
+}
+
 /// Deallocate a string.
 #[no_mangle]
 pub extern "C" fn deallocate_string(pointer: *const c_char) {
@@ -361,8 +776,8 @@ pub extern "C" fn deallocate_string(pointer: *const c_char) {
         if pointer.is_null() {
             return;
         }
-        CString::from_raw(pointer as *mut c_char)
-    };
+        drop(CString::from_raw(pointer as *mut c_char));
+    }
 }
 
 #[cfg(test)]
@@ -371,8 +786,15 @@ mod tests {
     use crypto_msg_type::MessageType;
 
     use super::{
-        deallocate_string, extract_timestamp, get_msg_type, parse_bbo, parse_candlestick,
-        parse_funding_rate, parse_l2, parse_trade,
+        csv_header, deallocate_string, extract_timestamp, get_msg_type, parse_bbo,
+        parse_bbo_with_format, parse_candlestick, parse_compressed, parse_funding_rate, parse_l2,
+        parse_trade, parse_trade_with_format, parse_trade_with_options,
+    };
+    use crate::{
+        compression::Compression,
+        decimal::NumberFormat,
+        error::last_error,
+        format::{deallocate_bytes, OutputFormat, ParseOptions},
     };
     use float_cmp::approx_eq;
     use std::ffi::{CStr, CString};
@@ -587,4 +1009,243 @@ mod tests {
 
         deallocate_string(json_ptr);
     }
+
+    #[test]
+    fn test_parse_bbo_csv() {
+        let exchange = CString::new("binance").unwrap();
+        let raw_msg = CString::new(r#"{"stream":"ethusdt@bookTicker","data":{"e":"bookTicker","u":1553413152520,"s":"ETHUSDT","b":"1778.54","B":"15.164","a":"1778.55","A":"7.289","T":1653817855284,"E":1653817855289}}"#).unwrap();
+        let buffer = parse_bbo_with_format(
+            exchange.as_ptr(),
+            MarketType::LinearSwap,
+            raw_msg.as_ptr(),
+            0,
+            OutputFormat::Csv,
+        );
+        let header_ptr = csv_header(MessageType::BBO);
+        let (csv_str, header_str) = unsafe {
+            debug_assert!(!buffer.data.is_null());
+            debug_assert!(!header_ptr.is_null());
+            (
+                std::str::from_utf8(std::slice::from_raw_parts(buffer.data, buffer.len)).unwrap(),
+                CStr::from_ptr(header_ptr).to_str().unwrap(),
+            )
+        };
+
+        assert_eq!(super::csv::BBO_HEADER, header_str);
+        assert!(csv_str.starts_with(
+            "binance,linear_swap,bbo,ETH/USDT,ETHUSDT,1653817855289,1553413152520,1778.54,15.164,"
+        ));
+        assert!(csv_str.ends_with('\n'));
+        assert!(csv_header(MessageType::Ticker).is_null());
+
+        deallocate_bytes(buffer);
+        deallocate_string(header_ptr);
+    }
+
+    #[test]
+    fn test_parse_trade_with_format() {
+        let exchange = CString::new("binance").unwrap();
+        let raw_msg = CString::new(r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#).unwrap();
+
+        for format in [
+            OutputFormat::Json,
+            OutputFormat::MessagePack,
+            OutputFormat::Cbor,
+        ] {
+            let buffer = parse_trade_with_format(
+                exchange.as_ptr(),
+                MarketType::InverseSwap,
+                raw_msg.as_ptr(),
+                format,
+            );
+            let bytes = unsafe {
+                debug_assert!(!buffer.data.is_null());
+                std::slice::from_raw_parts(buffer.data, buffer.len)
+            };
+
+            let trades: Vec<crypto_message::TradeMsg> = match format {
+                OutputFormat::Json => serde_json::from_slice(bytes).unwrap(),
+                OutputFormat::MessagePack => rmp_serde::from_slice(bytes).unwrap(),
+                OutputFormat::Cbor => ciborium::from_reader(bytes).unwrap(),
+                _ => unreachable!(),
+            };
+            assert_eq!(trades.len(), 1);
+            let trade = &trades[0];
+            assert_eq!(trade.exchange, "binance");
+            assert_eq!(trade.price, 58570.1);
+            assert_eq!(trade.quantity_contract, Some(58.0));
+            assert_eq!(trade.side, crypto_message::TradeSide::Sell);
+
+            deallocate_bytes(buffer);
+        }
+
+        let raw_msg = CString::new("not json").unwrap();
+        let buffer = parse_trade_with_format(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            raw_msg.as_ptr(),
+            OutputFormat::Cbor,
+        );
+        assert!(buffer.data.is_null());
+    }
+
+    #[test]
+    fn test_parse_trade_with_options() {
+        let exchange = CString::new("binance").unwrap();
+        let raw_msg = CString::new(r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#).unwrap();
+
+        let fields = CString::new("timestamp, price,quantity_contract,side").unwrap();
+        let options = ParseOptions {
+            number_format: NumberFormat::DecimalString,
+            fields: fields.as_ptr(),
+            ..Default::default()
+        };
+        let buffer = parse_trade_with_options(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            raw_msg.as_ptr(),
+            options,
+        );
+        let bytes = unsafe {
+            debug_assert!(!buffer.data.is_null());
+            std::slice::from_raw_parts(buffer.data, buffer.len)
+        };
+        let trades: serde_json::Value = serde_json::from_slice(bytes).unwrap();
+        assert_eq!(trades[0]["price"], "58570.1");
+        assert_eq!(trades[0]["quantity_contract"], "58");
+        assert_eq!(trades[0]["timestamp"], 1616201883458i64);
+        assert_eq!(trades[0].as_object().unwrap().len(), 4);
+        deallocate_bytes(buffer);
+
+        let options = ParseOptions {
+            format: OutputFormat::MessagePack,
+            omit_json: true,
+            ..Default::default()
+        };
+        let buffer = parse_trade_with_options(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            raw_msg.as_ptr(),
+            options,
+        );
+        let bytes = unsafe {
+            debug_assert!(!buffer.data.is_null());
+            std::slice::from_raw_parts(buffer.data, buffer.len)
+        };
+        let trades: Vec<crypto_message::TradeMsg> = rmp_serde::from_slice(bytes).unwrap();
+        assert_eq!(trades[0].price, 58570.1);
+        assert!(trades[0].json.is_empty());
+        deallocate_bytes(buffer);
+
+        let options = ParseOptions {
+            format: OutputFormat::Protobuf,
+            number_format: NumberFormat::DecimalString,
+            ..Default::default()
+        };
+        let buffer = parse_trade_with_options(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            raw_msg.as_ptr(),
+            options,
+        );
+        assert!(buffer.data.is_null());
+
+        let fields = CString::new(b"price,\xff".to_vec()).unwrap();
+        let options = ParseOptions {
+            fields: fields.as_ptr(),
+            ..Default::default()
+        };
+        let buffer = parse_trade_with_options(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            raw_msg.as_ptr(),
+            options,
+        );
+        assert!(buffer.data.is_null());
+        let err = unsafe { CStr::from_ptr(last_error()) };
+        assert!(err.to_str().unwrap().starts_with("Invalid fields"));
+    }
+
+    #[test]
+    fn test_parse_compressed() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let exchange = CString::new("binance").unwrap();
+        let raw_msg = r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#;
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(raw_msg.as_bytes()).unwrap();
+        let data = encoder.finish().unwrap();
+
+        for compression in [Compression::Gzip, Compression::Auto] {
+            let buffer = parse_compressed(
+                exchange.as_ptr(),
+                MarketType::InverseSwap,
+                MessageType::Trade,
+                data.as_ptr(),
+                data.len(),
+                compression,
+                0,
+                ParseOptions::default(),
+            );
+            let bytes = unsafe {
+                debug_assert!(!buffer.data.is_null());
+                std::slice::from_raw_parts(buffer.data, buffer.len)
+            };
+            let trades: Vec<crypto_message::TradeMsg> = serde_json::from_slice(bytes).unwrap();
+            assert_eq!(trades[0].price, 58570.1);
+            assert_eq!(trades[0].json, raw_msg);
+            deallocate_bytes(buffer);
+        }
+
+        let buffer = parse_compressed(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            MessageType::Trade,
+            data.as_ptr(),
+            data.len(),
+            Compression::Zlib,
+            0,
+            ParseOptions::default(),
+        );
+        assert!(buffer.data.is_null());
+        let err = unsafe { CStr::from_ptr(last_error()) };
+        assert!(!err.to_str().unwrap().is_empty());
+
+        // a successful call clears the error
+        let buffer = parse_compressed(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            MessageType::Trade,
+            raw_msg.as_ptr(),
+            raw_msg.len(),
+            Compression::Plain,
+            0,
+            ParseOptions::default(),
+        );
+        assert!(!buffer.data.is_null());
+        assert!(last_error().is_null());
+        deallocate_bytes(buffer);
+
+        // the .proto file defines OpenInterest, but there is no parser for it
+        let buffer = parse_compressed(
+            exchange.as_ptr(),
+            MarketType::InverseSwap,
+            MessageType::OpenInterest,
+            raw_msg.as_ptr(),
+            raw_msg.len(),
+            Compression::Plain,
+            0,
+            ParseOptions {
+                format: OutputFormat::Protobuf,
+                ..Default::default()
+            },
+        );
+        assert!(buffer.data.is_null());
+        let err = unsafe { CStr::from_ptr(last_error()) };
+        assert_eq!(
+            "Unsupported message type open_interest",
+            err.to_str().unwrap()
+        );
+    }
 }
@@ -0,0 +1,365 @@
+use clap::{Parser, ValueEnum};
+use crypto_market_type::MarketType;
+use crypto_msg_parser_ffi::{
+    csv::{csv_header, ToCsv},
+    message::{is_supported, parse_msg, Message},
+    reader::{decompress, RawMsg},
+};
+use crypto_msg_type::MessageType;
+use std::{
+    cell::Cell,
+    fs::File,
+    io::{self, BufRead, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+    str::FromStr,
+};
+
+/// Parse raw exchange messages into normalized messages.
+///
+/// If --exchange, --market-type and --msg-type are all given, each input line
+/// is a raw message, otherwise each line is in the crypto-crawler format, i.e.,
+/// exchange, market_type, msg_type, received_at and the raw message separated
+/// by tabs. Input files can be plain text, gzip or xz.
+#[derive(Parser, Debug)]
+#[command(name = "crypto-msg-parser", version)]
+struct Args {
+    /// Input files, read from stdin if empty or `-`
+    files: Vec<PathBuf>,
+    /// Exchange name, e.g., binance
+    #[arg(long)]
+    exchange: Option<String>,
+    /// Market type, e.g., linear_swap
+    #[arg(long, value_parser = MarketType::from_str)]
+    market_type: Option<MarketType>,
+    /// Message type, e.g., trade
+    #[arg(long, value_parser = MessageType::from_str)]
+    msg_type: Option<MessageType>,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Jsonl)]
+    format: Format,
+    /// Output file, write to stdout if absent
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Leave the `json` field of JSON Lines output empty instead of echoing the raw message
+    #[arg(long)]
+    omit_json: bool,
+    /// Print the error of every failed line to stderr
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// JSON Lines, one normalized message per line
+    Jsonl,
+    /// CSV with a header row, lines of other message types than the first are skipped
+    Csv,
+}
+
+/// How to interpret an input line.
+enum Mode {
+    Raw {
+        exchange: String,
+        market_type: MarketType,
+        msg_type: MessageType,
+    },
+    Crawler,
+}
+
+thread_local! {
+    /// Set while the upstream parsers run, their panics are caught and the
+    /// lines counted as failed.
+    static PARSING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Run a parser with its panic messages suppressed unless `--verbose`.
+fn parse_quietly(
+    parse: impl FnOnce() -> Result<Vec<Message>, String>,
+) -> Result<Vec<Message>, String> {
+    PARSING.set(true);
+    let result = parse();
+    PARSING.set(false);
+    result
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Counters {
+    parsed: u64,
+    failed: u64,
+    skipped: u64,
+}
+
+/// Writes normalized messages in the output format.
+///
+/// A CSV output holds one message type only, which is decided by the first
+/// message, messages of other types are rejected.
+struct Output<W: Write> {
+    writer: W,
+    format: Format,
+    omit_json: bool,
+    csv_header: Option<&'static str>,
+}
+
+impl<W: Write> Output<W> {
+    fn new(writer: W, format: Format) -> Self {
+        Output {
+            writer,
+            format,
+            omit_json: false,
+            csv_header: None,
+        }
+    }
+
+    /// Returns false if the messages are rejected.
+    fn write(&mut self, msgs: &mut [Message]) -> io::Result<bool> {
+        match self.format {
+            Format::Jsonl => {
+                for msg in msgs {
+                    if self.omit_json {
+                        msg.clear_json();
+                    }
+                    serde_json::to_writer(&mut self.writer, msg)?;
+                    self.writer.write_all(b"\n")?;
+                }
+            }
+            Format::Csv => {
+                for msg in msgs.iter() {
+                    let header = csv_header(msg.msg_type());
+                    match self.csv_header {
+                        None => {
+                            self.csv_header = header;
+                            if let Some(header) = header {
+                                writeln!(self.writer, "{header}")?;
+                            }
+                        }
+                        Some(_) if self.csv_header != header => return Ok(false),
+                        Some(_) => (),
+                    }
+                }
+                self.writer.write_all(msgs.to_csv().as_bytes())?;
+            }
+        }
+        Ok(true)
+    }
+}
+
+fn convert<R: BufRead, W: Write>(
+    input: R,
+    output: &mut Output<W>,
+    mode: &Mode,
+    verbose: bool,
+    counters: &mut Counters,
+) -> io::Result<()> {
+    for (i, line) in input.split(b'\n').enumerate() {
+        let mut line = line?;
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        let line = match String::from_utf8(line) {
+            Ok(line) => line,
+            Err(err) => {
+                counters.failed += 1;
+                if verbose {
+                    eprintln!("line {}: {err}", i + 1);
+                }
+                continue;
+            }
+        };
+        if line.trim().is_empty() {
+            counters.skipped += 1;
+            continue;
+        }
+        let result = match mode {
+            Mode::Raw {
+                exchange,
+                market_type,
+                msg_type,
+            } => parse_quietly(|| parse_msg(exchange, *market_type, *msg_type, &line, 0)),
+            Mode::Crawler => match RawMsg::from_str(&line) {
+                Ok(raw_msg) if !is_supported(raw_msg.msg_type) => {
+                    counters.skipped += 1;
+                    continue;
+                }
+                Ok(raw_msg) => parse_quietly(|| raw_msg.parse()),
+                Err(err) => Err(err),
+            },
+        };
+        match result {
+            Ok(mut msgs) => {
+                if output.write(&mut msgs)? {
+                    counters.parsed += 1;
+                } else {
+                    counters.skipped += 1;
+                }
+            }
+            Err(err) => {
+                counters.failed += 1;
+                if verbose {
+                    eprintln!("line {}: {err}", i + 1);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let mode = match (args.exchange, args.market_type, args.msg_type) {
+        (Some(exchange), Some(market_type), Some(msg_type)) => {
+            if !is_supported(msg_type) {
+                eprintln!("Unsupported message type {msg_type}");
+                return ExitCode::FAILURE;
+            }
+            Mode::Raw {
+                exchange,
+                market_type,
+                msg_type,
+            }
+        }
+        (None, None, None) => Mode::Crawler,
+        _ => {
+            eprintln!("--exchange, --market-type and --msg-type must be specified together");
+            return ExitCode::FAILURE;
+        }
+    };
+    // Upstream panics are counted as failed lines, so don't let them flood
+    // stderr, other panics are bugs of this program and still reported
+    if !args.verbose {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if !PARSING.get() {
+                default_hook(info);
+            }
+        }));
+    }
+
+    let writer: BufWriter<Box<dyn Write>> = match &args.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => BufWriter::new(Box::new(file)),
+            Err(err) => {
+                eprintln!("{}, error: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => BufWriter::new(Box::new(io::stdout().lock())),
+    };
+    let mut output = Output::new(writer, args.format);
+    output.omit_json = args.omit_json;
+    let files = if args.files.is_empty() {
+        vec![PathBuf::from("-")]
+    } else {
+        args.files
+    };
+
+    let mut counters = Counters::default();
+    for path in files.iter() {
+        let input = if path.to_str() == Some("-") {
+            decompress(io::stdin())
+        } else {
+            File::open(path).and_then(decompress)
+        };
+        let result =
+            input.and_then(|input| convert(input, &mut output, &mode, args.verbose, &mut counters));
+        if let Err(err) = result {
+            eprintln!("{}, error: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+    if let Err(err) = output.writer.flush() {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+
+    eprintln!(
+        "parsed: {}, failed: {}, skipped: {}",
+        counters.parsed, counters.failed, counters.skipped
+    );
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert, Counters, Format, Mode, Output};
+    use crypto_market_type::MarketType;
+    use crypto_msg_parser_ffi::csv::TRADE_HEADER;
+    use crypto_msg_type::MessageType;
+
+    const TRADE_MSG: &str = r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#;
+
+    #[test]
+    fn test_convert_raw() {
+        let input = format!("{TRADE_MSG}\n\nnot json\n");
+        let mode = Mode::Raw {
+            exchange: "binance".to_string(),
+            market_type: MarketType::InverseSwap,
+            msg_type: MessageType::Trade,
+        };
+        let mut output = Output::new(Vec::new(), Format::Jsonl);
+        let mut counters = Counters::default();
+        convert(input.as_bytes(), &mut output, &mode, false, &mut counters).unwrap();
+
+        let expected = Counters {
+            parsed: 1,
+            failed: 1,
+            skipped: 1,
+        };
+        assert_eq!(expected, counters);
+        let text = String::from_utf8(output.writer).unwrap();
+        let trade = serde_json::from_str::<crypto_message::TradeMsg>(text.trim_end()).unwrap();
+        assert_eq!(58570.1, trade.price);
+    }
+
+    #[test]
+    fn test_convert_invalid_utf8() {
+        let mut input = b"binance\tinverse_swap\ttrade\t1616201883500\t\xff\n".to_vec();
+        input.extend_from_slice(
+            format!("binance\tinverse_swap\ttrade\t1616201883500\t{TRADE_MSG}\r\n").as_bytes(),
+        );
+        let mut output = Output::new(Vec::new(), Format::Jsonl);
+        let mut counters = Counters::default();
+        convert(
+            input.as_slice(),
+            &mut output,
+            &Mode::Crawler,
+            false,
+            &mut counters,
+        )
+        .unwrap();
+
+        let expected = Counters {
+            parsed: 1,
+            failed: 1,
+            skipped: 0,
+        };
+        assert_eq!(expected, counters);
+    }
+
+    #[test]
+    fn test_convert_crawler() {
+        let input = format!(
+            "binance\tinverse_swap\ttrade\t1616201883500\t{TRADE_MSG}\nbinance\tinverse_swap\tticker\t1616201883500\t{{}}\n"
+        );
+        let mut output = Output::new(Vec::new(), Format::Csv);
+        let mut counters = Counters::default();
+        convert(
+            input.as_bytes(),
+            &mut output,
+            &Mode::Crawler,
+            false,
+            &mut counters,
+        )
+        .unwrap();
+
+        let expected = Counters {
+            parsed: 1,
+            failed: 0,
+            skipped: 1,
+        };
+        assert_eq!(expected, counters);
+        let text = String::from_utf8(output.writer).unwrap();
+        assert_eq!(2, text.lines().count());
+        assert!(text.starts_with(TRADE_HEADER));
+    }
+}
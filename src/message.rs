@@ -0,0 +1,145 @@
+use crypto_market_type::MarketType;
+use crypto_message::{BboMsg, CandlestickMsg, FundingRateMsg, OrderBookMsg, TradeMsg};
+use crypto_msg_type::MessageType;
+use serde::Serialize;
+
+/// A normalized message produced by one of the `parse_*` functions.
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+pub enum Message {
+    Trade(TradeMsg),
+    OrderBook(OrderBookMsg),
+    Bbo(BboMsg),
+    FundingRate(FundingRateMsg),
+    Candlestick(CandlestickMsg),
+}
+
+impl Message {
+    pub fn msg_type(&self) -> MessageType {
+        match self {
+            Message::Trade(msg) => msg.msg_type,
+            Message::OrderBook(msg) => msg.msg_type,
+            Message::Bbo(msg) => msg.msg_type,
+            Message::FundingRate(msg) => msg.msg_type,
+            Message::Candlestick(msg) => msg.msg_type,
+        }
+    }
+
+    /// Drop the original message, leaving an empty `json` field.
+    pub fn clear_json(&mut self) {
+        let json = match self {
+            Message::Trade(msg) => &mut msg.json,
+            Message::OrderBook(msg) => &mut msg.json,
+            Message::Bbo(msg) => &mut msg.json,
+            Message::FundingRate(msg) => &mut msg.json,
+            Message::Candlestick(msg) => &mut msg.json,
+        };
+        *json = String::new();
+    }
+}
+
+/// Parse a raw message with the `parse_*` function that matches `msg_type`.
+///
+/// `received_at` is only used by message types whose raw messages may lack a
+/// timestamp, a non-positive value means it is unknown.
+pub fn parse_msg(
+    exchange: &str,
+    market_type: MarketType,
+    msg_type: MessageType,
+    msg: &str,
+    received_at: i64,
+) -> Result<Vec<Message>, String> {
+    let received_at = if received_at <= 0 {
+        None
+    } else {
+        Some(received_at)
+    };
+    if !is_supported(msg_type) {
+        return Err(format!("Unsupported message type {msg_type}"));
+    }
+    catch_panic(|| match msg_type {
+        MessageType::Trade => crypto_msg_parser::parse_trade(exchange, market_type, msg)
+            .map(|v| v.into_iter().map(Message::Trade).collect()),
+        MessageType::L2Event | MessageType::L2Snapshot => {
+            crypto_msg_parser::parse_l2(exchange, market_type, msg, received_at)
+                .map(|v| v.into_iter().map(Message::OrderBook).collect())
+        }
+        MessageType::L2TopK => {
+            crypto_msg_parser::parse_l2_topk(exchange, market_type, msg, received_at)
+                .map(|v| v.into_iter().map(Message::OrderBook).collect())
+        }
+        MessageType::BBO => crypto_msg_parser::parse_bbo(exchange, market_type, msg, received_at)
+            .map(|v| v.into_iter().map(Message::Bbo).collect()),
+        MessageType::FundingRate => {
+            crypto_msg_parser::parse_funding_rate(exchange, market_type, msg, received_at)
+                .map(|v| v.into_iter().map(Message::FundingRate).collect())
+        }
+        MessageType::Candlestick => {
+            crypto_msg_parser::parse_candlestick(exchange, market_type, msg, received_at)
+                .map(|v| v.into_iter().map(Message::Candlestick).collect())
+        }
+        _ => unreachable!(),
+    })
+}
+
+/// Call an upstream function, turning its error and panic into the error text.
+pub(crate) fn catch_panic<T, E: std::fmt::Display>(
+    f: impl FnOnce() -> Result<T, E> + std::panic::UnwindSafe,
+) -> Result<T, String> {
+    match std::panic::catch_unwind(f) {
+        Ok(Ok(x)) => Ok(x),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(err) => Err(err
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| err.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Panicked while parsing".to_string())),
+    }
+}
+
+/// Whether `parse_msg()` supports the message type.
+pub fn is_supported(msg_type: MessageType) -> bool {
+    matches!(
+        msg_type,
+        MessageType::Trade
+            | MessageType::L2Event
+            | MessageType::L2Snapshot
+            | MessageType::L2TopK
+            | MessageType::BBO
+            | MessageType::FundingRate
+            | MessageType::Candlestick
+    )
+}
+
+/// Encoding of a raw message.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PayloadEncoding {
+    /// UTF-8 JSON text, parsed by `crypto-msg-parser`
+    Json = 0,
+    /// Binance spot SBE market data streams, see the `sbe` module
+    BinanceSbe = 1,
+}
+
+/// Parse a raw message in the given encoding, see `parse_msg()`.
+pub fn parse_payload(
+    exchange: &str,
+    market_type: MarketType,
+    msg_type: MessageType,
+    payload: &[u8],
+    encoding: PayloadEncoding,
+    received_at: i64,
+) -> Result<Vec<Message>, String> {
+    match encoding {
+        PayloadEncoding::Json => {
+            let msg = std::str::from_utf8(payload).map_err(|err| err.to_string())?;
+            parse_msg(exchange, market_type, msg_type, msg, received_at)
+        }
+        PayloadEncoding::BinanceSbe if exchange == "binance" => {
+            crate::sbe::decode(market_type, msg_type, payload)
+        }
+        PayloadEncoding::BinanceSbe => {
+            Err(format!("{exchange} doesn't send {encoding:?} payloads"))
+        }
+    }
+}
@@ -0,0 +1,460 @@
+//! Node.js bindings built with napi-rs, see `node/`.
+//!
+//! Market types and message types are passed as their snake_case names, e.g.,
+//! `inverse_swap` and `l2_event`, fields of returned objects are camelCase.
+//!
+//! Sequence numbers and update IDs are `u64` and may exceed 2^53, they are
+//! returned as `bigint` so that no precision is lost.
+
+use crate::message::{catch_panic, parse_msg, Message};
+use crypto_market_type::MarketType;
+use crypto_msg_type::MessageType;
+use napi::bindgen_prelude::BigInt;
+use napi::{Error, Result};
+use napi_derive::napi;
+use std::str::FromStr;
+
+#[napi(object)]
+pub struct TradeMsg {
+    pub exchange: String,
+    pub market_type: String,
+    pub msg_type: String,
+    pub pair: String,
+    pub symbol: String,
+    /// Unix timestamp, in milliseconds
+    pub timestamp: i64,
+    /// `buy` or `sell`, which side is taker
+    pub side: String,
+    pub price: f64,
+    pub quantity_base: f64,
+    pub quantity_quote: f64,
+    /// Number of contracts, absent in spot market
+    pub quantity_contract: Option<f64>,
+    pub trade_id: String,
+    /// The original JSON message
+    pub json: String,
+}
+
+#[napi(object)]
+pub struct Order {
+    pub price: f64,
+    pub quantity_base: f64,
+    pub quantity_quote: f64,
+    pub quantity_contract: Option<f64>,
+}
+
+#[napi(object)]
+pub struct OrderBookMsg {
+    pub exchange: String,
+    pub market_type: String,
+    pub symbol: String,
+    pub pair: String,
+    pub msg_type: String,
+    /// Unix timestamp, in milliseconds
+    pub timestamp: i64,
+    pub snapshot: bool,
+    /// Sorted in ascending order by price
+    pub asks: Vec<Order>,
+    /// Sorted in descending order by price
+    pub bids: Vec<Order>,
+    pub seq_id: Option<BigInt>,
+    pub prev_seq_id: Option<BigInt>,
+    /// The original JSON message
+    pub json: String,
+}
+
+#[napi(object)]
+pub struct BboMsg {
+    pub exchange: String,
+    pub market_type: String,
+    pub symbol: String,
+    pub pair: String,
+    pub msg_type: String,
+    /// Unix timestamp, in milliseconds
+    pub timestamp: i64,
+    pub bid_price: f64,
+    pub bid_quantity_base: f64,
+    pub bid_quantity_quote: f64,
+    pub bid_quantity_contract: Option<f64>,
+    pub ask_price: f64,
+    pub ask_quantity_base: f64,
+    pub ask_quantity_quote: f64,
+    pub ask_quantity_contract: Option<f64>,
+    pub id: Option<BigInt>,
+    /// The original JSON message
+    pub json: String,
+}
+
+#[napi(object)]
+pub struct FundingRateMsg {
+    pub exchange: String,
+    pub market_type: String,
+    pub symbol: String,
+    pub pair: String,
+    pub msg_type: String,
+    /// Unix timestamp, in milliseconds
+    pub timestamp: i64,
+    pub funding_rate: f64,
+    /// Unix timestamp of the funding, in milliseconds
+    pub funding_time: i64,
+    pub estimated_rate: Option<f64>,
+    /// The original JSON message
+    pub json: String,
+}
+
+#[napi(object)]
+pub struct CandlestickMsg {
+    pub exchange: String,
+    pub market_type: String,
+    pub symbol: String,
+    pub pair: String,
+    pub msg_type: String,
+    /// Unix timestamp, in milliseconds
+    pub timestamp: i64,
+    /// Begin time of the candle cycle, in seconds
+    pub begin_time: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    /// Base volume
+    pub volume: f64,
+    /// m, minute; H, hour; D, day; W, week; M, month; Y, year
+    pub period: String,
+    /// Quote volume
+    pub quote_volume: Option<f64>,
+    /// The original JSON message
+    pub json: String,
+}
+
+impl From<crypto_message::TradeMsg> for TradeMsg {
+    fn from(msg: crypto_message::TradeMsg) -> Self {
+        TradeMsg {
+            exchange: msg.exchange,
+            market_type: msg.market_type.to_string(),
+            msg_type: msg.msg_type.to_string(),
+            pair: msg.pair,
+            symbol: msg.symbol,
+            timestamp: msg.timestamp,
+            side: msg.side.to_string(),
+            price: msg.price,
+            quantity_base: msg.quantity_base,
+            quantity_quote: msg.quantity_quote,
+            quantity_contract: msg.quantity_contract,
+            trade_id: msg.trade_id,
+            json: msg.json,
+        }
+    }
+}
+
+impl From<crypto_message::Order> for Order {
+    fn from(order: crypto_message::Order) -> Self {
+        Order {
+            price: order.price,
+            quantity_base: order.quantity_base,
+            quantity_quote: order.quantity_quote,
+            quantity_contract: order.quantity_contract,
+        }
+    }
+}
+
+impl From<crypto_message::OrderBookMsg> for OrderBookMsg {
+    fn from(msg: crypto_message::OrderBookMsg) -> Self {
+        OrderBookMsg {
+            exchange: msg.exchange,
+            market_type: msg.market_type.to_string(),
+            symbol: msg.symbol,
+            pair: msg.pair,
+            msg_type: msg.msg_type.to_string(),
+            timestamp: msg.timestamp,
+            snapshot: msg.snapshot,
+            asks: msg.asks.into_iter().map(Order::from).collect(),
+            bids: msg.bids.into_iter().map(Order::from).collect(),
+            seq_id: msg.seq_id.map(BigInt::from),
+            prev_seq_id: msg.prev_seq_id.map(BigInt::from),
+            json: msg.json,
+        }
+    }
+}
+
+impl From<crypto_message::BboMsg> for BboMsg {
+    fn from(msg: crypto_message::BboMsg) -> Self {
+        BboMsg {
+            exchange: msg.exchange,
+            market_type: msg.market_type.to_string(),
+            symbol: msg.symbol,
+            pair: msg.pair,
+            msg_type: msg.msg_type.to_string(),
+            timestamp: msg.timestamp,
+            bid_price: msg.bid_price,
+            bid_quantity_base: msg.bid_quantity_base,
+            bid_quantity_quote: msg.bid_quantity_quote,
+            bid_quantity_contract: msg.bid_quantity_contract,
+            ask_price: msg.ask_price,
+            ask_quantity_base: msg.ask_quantity_base,
+            ask_quantity_quote: msg.ask_quantity_quote,
+            ask_quantity_contract: msg.ask_quantity_contract,
+            id: msg.id.map(BigInt::from),
+            json: msg.json,
+        }
+    }
+}
+
+impl From<crypto_message::FundingRateMsg> for FundingRateMsg {
+    fn from(msg: crypto_message::FundingRateMsg) -> Self {
+        FundingRateMsg {
+            exchange: msg.exchange,
+            market_type: msg.market_type.to_string(),
+            symbol: msg.symbol,
+            pair: msg.pair,
+            msg_type: msg.msg_type.to_string(),
+            timestamp: msg.timestamp,
+            funding_rate: msg.funding_rate,
+            funding_time: msg.funding_time,
+            estimated_rate: msg.estimated_rate,
+            json: msg.json,
+        }
+    }
+}
+
+impl From<crypto_message::CandlestickMsg> for CandlestickMsg {
+    fn from(msg: crypto_message::CandlestickMsg) -> Self {
+        CandlestickMsg {
+            exchange: msg.exchange,
+            market_type: msg.market_type.to_string(),
+            symbol: msg.symbol,
+            pair: msg.pair,
+            msg_type: msg.msg_type.to_string(),
+            timestamp: msg.timestamp,
+            begin_time: msg.begin_time,
+            open: msg.open,
+            high: msg.high,
+            low: msg.low,
+            close: msg.close,
+            volume: msg.volume,
+            period: msg.period,
+            quote_volume: msg.quote_volume,
+            json: msg.json,
+        }
+    }
+}
+
+fn market_type_from_str(market_type: &str) -> Result<MarketType> {
+    MarketType::from_str(market_type)
+        .map_err(|_| Error::from_reason(format!("Unknown market type {market_type}")))
+}
+
+fn parse(
+    exchange: &str,
+    market_type: &str,
+    msg_type: MessageType,
+    msg: &str,
+    received_at: Option<i64>,
+) -> Result<Vec<Message>> {
+    let market_type = market_type_from_str(market_type)?;
+    parse_msg(
+        exchange,
+        market_type,
+        msg_type,
+        msg,
+        received_at.unwrap_or(0),
+    )
+    .map_err(Error::from_reason)
+}
+
+/// Keep messages of the given variant, converted into JS objects.
+macro_rules! convert {
+    ($msgs:expr, $variant:ident) => {
+        $msgs
+            .into_iter()
+            .filter_map(|msg| match msg {
+                Message::$variant(msg) => Some(msg.into()),
+                _ => None,
+            })
+            .collect()
+    };
+}
+
+/// Extract the symbol from the message, `ALL` for multiple symbols and `NONE`
+/// for no symbol.
+#[napi]
+pub fn extract_symbol(exchange: String, market_type: String, msg: String) -> Result<String> {
+    let market_type = market_type_from_str(&market_type)?;
+    catch_panic(|| crypto_msg_parser::extract_symbol(&exchange, market_type, &msg))
+        .map_err(Error::from_reason)
+}
+
+/// Extract the timestamp in milliseconds, `null` if the message has none.
+#[napi]
+pub fn extract_timestamp(
+    exchange: String,
+    market_type: String,
+    msg: String,
+) -> Result<Option<i64>> {
+    let market_type = market_type_from_str(&market_type)?;
+    catch_panic(|| crypto_msg_parser::extract_timestamp(&exchange, market_type, &msg))
+        .map_err(Error::from_reason)
+}
+
+/// Infer the message type from the message.
+#[napi]
+pub fn get_msg_type(exchange: String, msg: String) -> Result<String> {
+    catch_panic(|| Ok::<_, String>(crypto_msg_parser::get_msg_type(&exchange, &msg)))
+        .map(|msg_type| msg_type.to_string())
+        .map_err(Error::from_reason)
+}
+
+/// Parse a raw trade message.
+#[napi]
+pub fn parse_trade(exchange: String, market_type: String, msg: String) -> Result<Vec<TradeMsg>> {
+    let msgs = parse(&exchange, &market_type, MessageType::Trade, &msg, None)?;
+    Ok(convert!(msgs, Trade))
+}
+
+/// Parse a raw level2 orderbook message.
+#[napi]
+pub fn parse_l2(
+    exchange: String,
+    market_type: String,
+    msg: String,
+    received_at: Option<i64>,
+) -> Result<Vec<OrderBookMsg>> {
+    let msgs = parse(
+        &exchange,
+        &market_type,
+        MessageType::L2Event,
+        &msg,
+        received_at,
+    )?;
+    Ok(convert!(msgs, OrderBook))
+}
+
+/// Parse a level2 topk orderbook message.
+#[napi]
+pub fn parse_l2_topk(
+    exchange: String,
+    market_type: String,
+    msg: String,
+    received_at: Option<i64>,
+) -> Result<Vec<OrderBookMsg>> {
+    let msgs = parse(
+        &exchange,
+        &market_type,
+        MessageType::L2TopK,
+        &msg,
+        received_at,
+    )?;
+    Ok(convert!(msgs, OrderBook))
+}
+
+/// Parse a BBO(best bid&offer) message.
+#[napi]
+pub fn parse_bbo(
+    exchange: String,
+    market_type: String,
+    msg: String,
+    received_at: Option<i64>,
+) -> Result<Vec<BboMsg>> {
+    let msgs = parse(&exchange, &market_type, MessageType::BBO, &msg, received_at)?;
+    Ok(convert!(msgs, Bbo))
+}
+
+/// Parse a raw funding rate message.
+#[napi]
+pub fn parse_funding_rate(
+    exchange: String,
+    market_type: String,
+    msg: String,
+    received_at: Option<i64>,
+) -> Result<Vec<FundingRateMsg>> {
+    let msgs = parse(
+        &exchange,
+        &market_type,
+        MessageType::FundingRate,
+        &msg,
+        received_at,
+    )?;
+    Ok(convert!(msgs, FundingRate))
+}
+
+/// Parse a raw candlestick message.
+#[napi]
+pub fn parse_candlestick(
+    exchange: String,
+    market_type: String,
+    msg: String,
+    received_at: Option<i64>,
+) -> Result<Vec<CandlestickMsg>> {
+    let msgs = parse(
+        &exchange,
+        &market_type,
+        MessageType::Candlestick,
+        &msg,
+        received_at,
+    )?;
+    Ok(convert!(msgs, Candlestick))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_timestamp, get_msg_type, parse_l2, parse_trade};
+
+    #[test]
+    fn test_parse_trade() {
+        let raw_msg = r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#;
+        let trades = parse_trade(
+            "binance".to_string(),
+            "inverse_swap".to_string(),
+            raw_msg.to_string(),
+        )
+        .unwrap();
+        assert_eq!(1, trades.len());
+        let trade = &trades[0];
+        assert_eq!("inverse_swap", trade.market_type);
+        assert_eq!("trade", trade.msg_type);
+        assert_eq!("sell", trade.side);
+        assert_eq!(Some(58.0), trade.quantity_contract);
+        assert_eq!(
+            "trade",
+            get_msg_type("binance".to_string(), raw_msg.to_string()).unwrap()
+        );
+
+        assert!(parse_trade(
+            "binance".to_string(),
+            "spot_swap".to_string(),
+            raw_msg.to_string()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_l2() {
+        let raw_msg = r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}"#;
+        let orderbooks = parse_l2(
+            "binance".to_string(),
+            "inverse_swap".to_string(),
+            raw_msg.to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(1, orderbooks.len());
+        let orderbook = &orderbooks[0];
+        assert_eq!("l2_event", orderbook.msg_type);
+        assert_eq!(2, orderbook.asks.len());
+        assert_eq!(35365.9, orderbook.bids[0].price);
+        assert_eq!(Some(1400.0), orderbook.bids[0].quantity_contract);
+        assert_eq!(
+            Some(127559588177),
+            orderbook.seq_id.as_ref().map(|id| id.get_u64().1)
+        );
+        assert_eq!(
+            Some(1622370862564),
+            extract_timestamp(
+                "binance".to_string(),
+                "inverse_swap".to_string(),
+                raw_msg.to_string()
+            )
+            .unwrap()
+        );
+    }
+}
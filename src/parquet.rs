@@ -0,0 +1,268 @@
+//! Write normalized messages into Parquet files.
+
+use crate::{
+    arrow::{schema, to_record_batch},
+    message::{parse_msg, Message},
+};
+use crypto_market_type::MarketType;
+use crypto_msg_type::MessageType;
+use parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, ZstdLevel},
+    errors::ParquetError,
+    file::properties::WriterProperties,
+};
+use std::{ffi::CStr, fs::File, os::raw::c_char, path::Path};
+
+/// Number of messages converted into a record batch at a time.
+const BATCH_SIZE: usize = 8192;
+
+/// Maximum number of rows in a row group.
+const MAX_ROW_GROUP_SIZE: usize = 1024 * 1024;
+
+/// Accumulates normalized messages of one message type and writes them into
+/// a Parquet file.
+///
+/// The file has the same schema as `arrow::schema()`, uses ZSTD compression,
+/// and up to 1,048,576 rows per row group. It is incomplete until
+/// `close()` is called.
+pub struct ParquetWriter {
+    writer: ArrowWriter<File>,
+    msg_type: MessageType,
+    buffer: Vec<Message>,
+}
+
+impl ParquetWriter {
+    pub fn create<P: AsRef<Path>>(path: P, msg_type: MessageType) -> Result<Self, ParquetError> {
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .set_max_row_group_size(MAX_ROW_GROUP_SIZE)
+            .build();
+        Self::with_properties(path, msg_type, props)
+    }
+
+    pub fn with_properties<P: AsRef<Path>>(
+        path: P,
+        msg_type: MessageType,
+        props: WriterProperties,
+    ) -> Result<Self, ParquetError> {
+        let schema = schema(msg_type)
+            .ok_or_else(|| ParquetError::General(format!("Unsupported message type {msg_type}")))?;
+        let file = File::create(path)?;
+        Ok(ParquetWriter {
+            writer: ArrowWriter::try_new(file, schema, Some(props))?,
+            msg_type,
+            buffer: Vec::with_capacity(BATCH_SIZE),
+        })
+    }
+
+    pub fn msg_type(&self) -> MessageType {
+        self.msg_type
+    }
+
+    /// Whether a message has the columns of this writer.
+    fn accepts(&self, msg: &Message) -> bool {
+        matches!(
+            (self.msg_type, msg),
+            (MessageType::Trade, Message::Trade(_))
+                | (
+                    MessageType::L2Event | MessageType::L2Snapshot | MessageType::L2TopK,
+                    Message::OrderBook(_)
+                )
+                | (MessageType::BBO, Message::Bbo(_))
+                | (MessageType::FundingRate, Message::FundingRate(_))
+                | (MessageType::Candlestick, Message::Candlestick(_))
+        )
+    }
+
+    /// Buffer messages, they must be of the message type of this writer,
+    /// otherwise none of them is buffered.
+    pub fn write(&mut self, msgs: Vec<Message>) -> Result<(), ParquetError> {
+        if let Some(msg) = msgs.iter().find(|msg| !self.accepts(msg)) {
+            return Err(ParquetError::General(format!(
+                "Can't write {} messages into a {} file",
+                msg.msg_type(),
+                self.msg_type
+            )));
+        }
+        self.buffer.extend(msgs);
+        if self.buffer.len() >= BATCH_SIZE {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write buffered messages into the current row group.
+    ///
+    /// The buffer is emptied even if the write fails, after such an I/O error
+    /// the file is likely unusable.
+    pub fn flush(&mut self) -> Result<(), ParquetError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let msgs = std::mem::replace(&mut self.buffer, Vec::with_capacity(BATCH_SIZE));
+        let batch = to_record_batch(self.msg_type, &msgs)?;
+        self.writer.write(&batch)
+    }
+
+    /// Flush remaining messages and write the file footer.
+    pub fn close(mut self) -> Result<(), ParquetError> {
+        self.flush()?;
+        self.writer.close().map(|_| ())
+    }
+}
+
+/// Create a Parquet file for messages of `msg_type`, returns NULL if an error happens.
+#[no_mangle]
+pub extern "C" fn parquet_writer_open(
+    path: *const c_char,
+    msg_type: MessageType,
+) -> *mut ParquetWriter {
+    crate::error::clear_last_error();
+    let path_rust = unsafe {
+        debug_assert!(!path.is_null());
+        CStr::from_ptr(path).to_str().unwrap()
+    };
+    match ParquetWriter::create(path_rust, msg_type) {
+        Ok(writer) => Box::into_raw(Box::new(writer)),
+        Err(err) => {
+            crate::error::set_last_error(&err.to_string());
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Parse a raw message with the message type of the writer and write the
+/// normalized messages.
+///
+/// Returns the number of normalized messages, or -1 if an error happens. The
+/// messages of a failed call are not written, so it is safe to retry, unless
+/// writing a full batch failed, which drops the batch including messages of
+/// earlier calls and likely leaves the file unusable.
+#[no_mangle]
+pub extern "C" fn parquet_writer_write(
+    writer: *mut ParquetWriter,
+    exchange: *const c_char,
+    market_type: MarketType,
+    msg: *const c_char,
+    received_at: i64,
+) -> i64 {
+    crate::error::clear_last_error();
+    let writer_rust = unsafe {
+        debug_assert!(!writer.is_null());
+        &mut *writer
+    };
+    let exchange_rust = unsafe {
+        debug_assert!(!exchange.is_null());
+        CStr::from_ptr(exchange).to_str().unwrap()
+    };
+    let msg_rust = unsafe {
+        debug_assert!(!msg.is_null());
+        CStr::from_ptr(msg).to_str().unwrap()
+    };
+
+    let result = parse_msg(
+        exchange_rust,
+        market_type,
+        writer_rust.msg_type(),
+        msg_rust,
+        received_at,
+    )
+    .and_then(|msgs| {
+        let len = msgs.len() as i64;
+        writer_rust
+            .write(msgs)
+            .map(|_| len)
+            .map_err(|err| err.to_string())
+    });
+    match result {
+        Ok(len) => len,
+        Err(err) => {
+            crate::error::set_last_error(&err.to_string());
+            -1
+        }
+    }
+}
+
+/// Flush and close a writer returned by `parquet_writer_open()`.
+///
+/// Returns false if the file can't be completed, the writer is freed anyway.
+#[no_mangle]
+pub extern "C" fn parquet_writer_close(writer: *mut ParquetWriter) -> bool {
+    crate::error::clear_last_error();
+    if writer.is_null() {
+        return false;
+    }
+    let writer_rust = unsafe { Box::from_raw(writer) };
+    match writer_rust.close() {
+        Ok(()) => true,
+        Err(err) => {
+            crate::error::set_last_error(&err.to_string());
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParquetWriter;
+    use crate::message::parse_msg;
+    use crypto_market_type::MarketType;
+    use crypto_msg_type::MessageType;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::{
+        fs::File,
+        path::PathBuf,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    /// A path in the temporary directory unique to the process and the call.
+    fn temp_path(name: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        std::env::temp_dir().join(format!(
+            "crypto-msg-parser-ffi-test-{}-{}-{name}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    #[test]
+    fn test_write_trades() {
+        let raw_msg = r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#;
+        let path = temp_path("trades.parquet");
+
+        let mut writer = ParquetWriter::create(&path, MessageType::Trade).unwrap();
+        for _ in 0..3 {
+            let msgs = parse_msg(
+                "binance",
+                MarketType::InverseSwap,
+                MessageType::Trade,
+                raw_msg,
+                0,
+            )
+            .unwrap();
+            writer.write(msgs).unwrap();
+        }
+        // rejected without being buffered
+        let raw_rate = r#"{"stream":"btcusd_perp@markPrice","data":{"e":"markPriceUpdate","E":1617309477000,"s":"BTCUSD_PERP","p":"59012.56007222","P":"58896.00503145","r":"0.00073689","T":1617321600000}}"#;
+        let rates = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::FundingRate,
+            raw_rate,
+            0,
+        )
+        .unwrap();
+        assert!(writer.write(rates).is_err());
+        writer.close().unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let num_rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+        assert_eq!(3, num_rows);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,423 @@
+//! Protobuf messages of `proto/crypto_msg_parser.proto`.
+//!
+//! Generated by `cargo xtask codegen` with prost-build, don't modify it manually.
+
+// This file is @generated by prost-build.
+/// Realtime trade message.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trade {
+    #[prost(string, tag = "1")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(enumeration = "MarketType", tag = "2")]
+    pub market_type: i32,
+    #[prost(enumeration = "MessageType", tag = "3")]
+    pub msg_type: i32,
+    #[prost(string, tag = "4")]
+    pub pair: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    #[prost(enumeration = "TradeSide", tag = "7")]
+    pub side: i32,
+    #[prost(double, tag = "8")]
+    pub price: f64,
+    #[prost(double, tag = "9")]
+    pub quantity_base: f64,
+    #[prost(double, tag = "10")]
+    pub quantity_quote: f64,
+    /// Number of contracts, empty for spot markets
+    #[prost(double, optional, tag = "11")]
+    pub quantity_contract: ::core::option::Option<f64>,
+    #[prost(string, tag = "12")]
+    pub trade_id: ::prost::alloc::string::String,
+    /// The original JSON message
+    #[prost(string, tag = "13")]
+    pub json: ::prost::alloc::string::String,
+}
+/// A price level in the orderbook.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Order {
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    /// Number of base coins, 0 means the price level can be removed
+    #[prost(double, tag = "2")]
+    pub quantity_base: f64,
+    #[prost(double, tag = "3")]
+    pub quantity_quote: f64,
+    /// Number of contracts, empty for spot markets
+    #[prost(double, optional, tag = "4")]
+    pub quantity_contract: ::core::option::Option<f64>,
+}
+/// Level2 orderbook.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBook {
+    #[prost(string, tag = "1")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(enumeration = "MarketType", tag = "2")]
+    pub market_type: i32,
+    #[prost(enumeration = "MessageType", tag = "3")]
+    pub msg_type: i32,
+    #[prost(string, tag = "4")]
+    pub pair: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    /// snapshot or updates
+    #[prost(bool, tag = "7")]
+    pub snapshot: bool,
+    /// sorted in ascending order by price if snapshot=true, otherwise not sorted
+    #[prost(message, repeated, tag = "8")]
+    pub asks: ::prost::alloc::vec::Vec<Order>,
+    /// sorted in descending order by price if snapshot=true, otherwise not sorted
+    #[prost(message, repeated, tag = "9")]
+    pub bids: ::prost::alloc::vec::Vec<Order>,
+    #[prost(uint64, optional, tag = "10")]
+    pub seq_id: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "11")]
+    pub prev_seq_id: ::core::option::Option<u64>,
+    #[prost(string, tag = "12")]
+    pub json: ::prost::alloc::string::String,
+}
+/// Best bid and offer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Bbo {
+    #[prost(string, tag = "1")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(enumeration = "MarketType", tag = "2")]
+    pub market_type: i32,
+    #[prost(enumeration = "MessageType", tag = "3")]
+    pub msg_type: i32,
+    #[prost(string, tag = "4")]
+    pub pair: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    #[prost(double, tag = "7")]
+    pub bid_price: f64,
+    #[prost(double, tag = "8")]
+    pub bid_quantity_base: f64,
+    #[prost(double, tag = "9")]
+    pub bid_quantity_quote: f64,
+    #[prost(double, optional, tag = "10")]
+    pub bid_quantity_contract: ::core::option::Option<f64>,
+    #[prost(double, tag = "11")]
+    pub ask_price: f64,
+    #[prost(double, tag = "12")]
+    pub ask_quantity_base: f64,
+    #[prost(double, tag = "13")]
+    pub ask_quantity_quote: f64,
+    #[prost(double, optional, tag = "14")]
+    pub ask_quantity_contract: ::core::option::Option<f64>,
+    #[prost(uint64, optional, tag = "15")]
+    pub id: ::core::option::Option<u64>,
+    #[prost(string, tag = "16")]
+    pub json: ::prost::alloc::string::String,
+}
+/// Funding rate.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingRate {
+    #[prost(string, tag = "1")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(enumeration = "MarketType", tag = "2")]
+    pub market_type: i32,
+    #[prost(enumeration = "MessageType", tag = "3")]
+    pub msg_type: i32,
+    #[prost(string, tag = "4")]
+    pub pair: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    #[prost(double, tag = "7")]
+    pub funding_rate: f64,
+    #[prost(int64, tag = "8")]
+    pub funding_time: i64,
+    #[prost(double, optional, tag = "9")]
+    pub estimated_rate: ::core::option::Option<f64>,
+    #[prost(string, tag = "10")]
+    pub json: ::prost::alloc::string::String,
+}
+/// OHLCV candlestick.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Candlestick {
+    #[prost(string, tag = "1")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(enumeration = "MarketType", tag = "2")]
+    pub market_type: i32,
+    #[prost(enumeration = "MessageType", tag = "3")]
+    pub msg_type: i32,
+    #[prost(string, tag = "4")]
+    pub pair: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    /// Begin time of the candle cycle, in seconds
+    #[prost(int64, tag = "7")]
+    pub begin_time: i64,
+    #[prost(double, tag = "8")]
+    pub open: f64,
+    #[prost(double, tag = "9")]
+    pub high: f64,
+    #[prost(double, tag = "10")]
+    pub low: f64,
+    #[prost(double, tag = "11")]
+    pub close: f64,
+    /// base volume
+    #[prost(double, tag = "12")]
+    pub volume: f64,
+    /// m, minute; H, hour; D, day; W, week; M, month; Y, year
+    #[prost(string, tag = "13")]
+    pub period: ::prost::alloc::string::String,
+    #[prost(double, optional, tag = "14")]
+    pub quote_volume: ::core::option::Option<f64>,
+    #[prost(string, tag = "15")]
+    pub json: ::prost::alloc::string::String,
+}
+/// 24hr rolling window ticker.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ticker {
+    #[prost(string, tag = "1")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(enumeration = "MarketType", tag = "2")]
+    pub market_type: i32,
+    #[prost(enumeration = "MessageType", tag = "3")]
+    pub msg_type: i32,
+    #[prost(string, tag = "4")]
+    pub pair: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    #[prost(double, tag = "7")]
+    pub open: f64,
+    #[prost(double, tag = "8")]
+    pub high: f64,
+    #[prost(double, tag = "9")]
+    pub low: f64,
+    #[prost(double, tag = "10")]
+    pub close: f64,
+    #[prost(double, tag = "11")]
+    pub volume: f64,
+    #[prost(double, tag = "12")]
+    pub quote_volume: f64,
+    #[prost(double, optional, tag = "13")]
+    pub last_quantity: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "14")]
+    pub best_bid_price: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "15")]
+    pub best_bid_quantity: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "16")]
+    pub best_ask_price: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "17")]
+    pub best_ask_quantity: ::core::option::Option<f64>,
+    /// availale in Futures and Swap markets
+    #[prost(double, optional, tag = "18")]
+    pub open_interest: ::core::option::Option<f64>,
+    /// availale in Futures and Swap markets
+    #[prost(double, optional, tag = "19")]
+    pub open_interest_quote: ::core::option::Option<f64>,
+    #[prost(string, tag = "20")]
+    pub json: ::prost::alloc::string::String,
+}
+/// Open interest.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenInterest {
+    #[prost(string, tag = "1")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(enumeration = "MarketType", tag = "2")]
+    pub market_type: i32,
+    #[prost(enumeration = "MessageType", tag = "3")]
+    pub msg_type: i32,
+    #[prost(string, tag = "4")]
+    pub pair: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    /// Number of contracts
+    #[prost(double, optional, tag = "7")]
+    pub open_interest: ::core::option::Option<f64>,
+    /// Number of base coins
+    #[prost(double, tag = "8")]
+    pub open_interest_base: f64,
+    /// Number of quote coins
+    #[prost(double, tag = "9")]
+    pub open_interest_quote: f64,
+    #[prost(string, tag = "10")]
+    pub json: ::prost::alloc::string::String,
+}
+/// Results of parsing a raw message, one list per message type.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TradeList {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: ::prost::alloc::vec::Vec<Trade>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBookList {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: ::prost::alloc::vec::Vec<OrderBook>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BboList {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: ::prost::alloc::vec::Vec<Bbo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingRateList {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: ::prost::alloc::vec::Vec<FundingRate>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CandlestickList {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: ::prost::alloc::vec::Vec<Candlestick>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MarketType {
+    Unknown = 0,
+    Spot = 1,
+    LinearFuture = 2,
+    InverseFuture = 3,
+    LinearSwap = 4,
+    InverseSwap = 5,
+    AmericanOption = 6,
+    EuropeanOption = 7,
+    QuantoFuture = 8,
+    QuantoSwap = 9,
+    Move = 10,
+    Bvol = 11,
+}
+impl MarketType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "MARKET_TYPE_UNKNOWN",
+            Self::Spot => "MARKET_TYPE_SPOT",
+            Self::LinearFuture => "MARKET_TYPE_LINEAR_FUTURE",
+            Self::InverseFuture => "MARKET_TYPE_INVERSE_FUTURE",
+            Self::LinearSwap => "MARKET_TYPE_LINEAR_SWAP",
+            Self::InverseSwap => "MARKET_TYPE_INVERSE_SWAP",
+            Self::AmericanOption => "MARKET_TYPE_AMERICAN_OPTION",
+            Self::EuropeanOption => "MARKET_TYPE_EUROPEAN_OPTION",
+            Self::QuantoFuture => "MARKET_TYPE_QUANTO_FUTURE",
+            Self::QuantoSwap => "MARKET_TYPE_QUANTO_SWAP",
+            Self::Move => "MARKET_TYPE_MOVE",
+            Self::Bvol => "MARKET_TYPE_BVOL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MARKET_TYPE_UNKNOWN" => Some(Self::Unknown),
+            "MARKET_TYPE_SPOT" => Some(Self::Spot),
+            "MARKET_TYPE_LINEAR_FUTURE" => Some(Self::LinearFuture),
+            "MARKET_TYPE_INVERSE_FUTURE" => Some(Self::InverseFuture),
+            "MARKET_TYPE_LINEAR_SWAP" => Some(Self::LinearSwap),
+            "MARKET_TYPE_INVERSE_SWAP" => Some(Self::InverseSwap),
+            "MARKET_TYPE_AMERICAN_OPTION" => Some(Self::AmericanOption),
+            "MARKET_TYPE_EUROPEAN_OPTION" => Some(Self::EuropeanOption),
+            "MARKET_TYPE_QUANTO_FUTURE" => Some(Self::QuantoFuture),
+            "MARKET_TYPE_QUANTO_SWAP" => Some(Self::QuantoSwap),
+            "MARKET_TYPE_MOVE" => Some(Self::Move),
+            "MARKET_TYPE_BVOL" => Some(Self::Bvol),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MessageType {
+    Other = 0,
+    Trade = 1,
+    L2Event = 2,
+    L2Snapshot = 3,
+    L2Topk = 4,
+    L3Event = 5,
+    L3Snapshot = 6,
+    Bbo = 7,
+    Ticker = 8,
+    Candlestick = 9,
+    FundingRate = 10,
+    OpenInterest = 11,
+    LongShortRatio = 12,
+    TakerVolume = 13,
+}
+impl MessageType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Other => "MESSAGE_TYPE_OTHER",
+            Self::Trade => "MESSAGE_TYPE_TRADE",
+            Self::L2Event => "MESSAGE_TYPE_L2_EVENT",
+            Self::L2Snapshot => "MESSAGE_TYPE_L2_SNAPSHOT",
+            Self::L2Topk => "MESSAGE_TYPE_L2_TOPK",
+            Self::L3Event => "MESSAGE_TYPE_L3_EVENT",
+            Self::L3Snapshot => "MESSAGE_TYPE_L3_SNAPSHOT",
+            Self::Bbo => "MESSAGE_TYPE_BBO",
+            Self::Ticker => "MESSAGE_TYPE_TICKER",
+            Self::Candlestick => "MESSAGE_TYPE_CANDLESTICK",
+            Self::FundingRate => "MESSAGE_TYPE_FUNDING_RATE",
+            Self::OpenInterest => "MESSAGE_TYPE_OPEN_INTEREST",
+            Self::LongShortRatio => "MESSAGE_TYPE_LONG_SHORT_RATIO",
+            Self::TakerVolume => "MESSAGE_TYPE_TAKER_VOLUME",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MESSAGE_TYPE_OTHER" => Some(Self::Other),
+            "MESSAGE_TYPE_TRADE" => Some(Self::Trade),
+            "MESSAGE_TYPE_L2_EVENT" => Some(Self::L2Event),
+            "MESSAGE_TYPE_L2_SNAPSHOT" => Some(Self::L2Snapshot),
+            "MESSAGE_TYPE_L2_TOPK" => Some(Self::L2Topk),
+            "MESSAGE_TYPE_L3_EVENT" => Some(Self::L3Event),
+            "MESSAGE_TYPE_L3_SNAPSHOT" => Some(Self::L3Snapshot),
+            "MESSAGE_TYPE_BBO" => Some(Self::Bbo),
+            "MESSAGE_TYPE_TICKER" => Some(Self::Ticker),
+            "MESSAGE_TYPE_CANDLESTICK" => Some(Self::Candlestick),
+            "MESSAGE_TYPE_FUNDING_RATE" => Some(Self::FundingRate),
+            "MESSAGE_TYPE_OPEN_INTEREST" => Some(Self::OpenInterest),
+            "MESSAGE_TYPE_LONG_SHORT_RATIO" => Some(Self::LongShortRatio),
+            "MESSAGE_TYPE_TAKER_VOLUME" => Some(Self::TakerVolume),
+            _ => None,
+        }
+    }
+}
+/// Which side is taker
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TradeSide {
+    Buy = 0,
+    Sell = 1,
+}
+impl TradeSide {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Buy => "TRADE_SIDE_BUY",
+            Self::Sell => "TRADE_SIDE_SELL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TRADE_SIDE_BUY" => Some(Self::Buy),
+            "TRADE_SIDE_SELL" => Some(Self::Sell),
+            _ => None,
+        }
+    }
+}
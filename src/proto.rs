@@ -0,0 +1,239 @@
+//! Encode normalized messages as the protobuf messages defined in
+//! `proto/crypto_msg_parser.proto`, whose Rust code is generated in the `pb`
+//! module.
+//!
+//! `Ticker` and `OpenInterest` are defined for downstream use only,
+//! `crypto-msg-parser` has no parser for them, so `parse_msg()` rejects their
+//! message types.
+
+use crate::{
+    message::Message as NormalizedMsg,
+    pb::{
+        Bbo, BboList, Candlestick, CandlestickList, FundingRate, FundingRateList, Order, OrderBook,
+        OrderBookList, Trade, TradeList, TradeSide,
+    },
+};
+use crypto_message::{BboMsg, CandlestickMsg, FundingRateMsg, OrderBookMsg, TradeMsg};
+use prost::Message;
+
+impl From<&TradeMsg> for Trade {
+    fn from(msg: &TradeMsg) -> Self {
+        Trade {
+            exchange: msg.exchange.clone(),
+            market_type: msg.market_type as i32,
+            msg_type: msg.msg_type as i32,
+            pair: msg.pair.clone(),
+            symbol: msg.symbol.clone(),
+            timestamp: msg.timestamp,
+            side: match msg.side {
+                crypto_message::TradeSide::Buy => TradeSide::Buy as i32,
+                crypto_message::TradeSide::Sell => TradeSide::Sell as i32,
+            },
+            price: msg.price,
+            quantity_base: msg.quantity_base,
+            quantity_quote: msg.quantity_quote,
+            quantity_contract: msg.quantity_contract,
+            trade_id: msg.trade_id.clone(),
+            json: msg.json.clone(),
+        }
+    }
+}
+
+impl From<&crypto_message::Order> for Order {
+    fn from(order: &crypto_message::Order) -> Self {
+        Order {
+            price: order.price,
+            quantity_base: order.quantity_base,
+            quantity_quote: order.quantity_quote,
+            quantity_contract: order.quantity_contract,
+        }
+    }
+}
+
+impl From<&OrderBookMsg> for OrderBook {
+    fn from(msg: &OrderBookMsg) -> Self {
+        OrderBook {
+            exchange: msg.exchange.clone(),
+            market_type: msg.market_type as i32,
+            msg_type: msg.msg_type as i32,
+            pair: msg.pair.clone(),
+            symbol: msg.symbol.clone(),
+            timestamp: msg.timestamp,
+            snapshot: msg.snapshot,
+            asks: msg.asks.iter().map(Order::from).collect(),
+            bids: msg.bids.iter().map(Order::from).collect(),
+            seq_id: msg.seq_id,
+            prev_seq_id: msg.prev_seq_id,
+            json: msg.json.clone(),
+        }
+    }
+}
+
+impl From<&BboMsg> for Bbo {
+    fn from(msg: &BboMsg) -> Self {
+        Bbo {
+            exchange: msg.exchange.clone(),
+            market_type: msg.market_type as i32,
+            msg_type: msg.msg_type as i32,
+            pair: msg.pair.clone(),
+            symbol: msg.symbol.clone(),
+            timestamp: msg.timestamp,
+            bid_price: msg.bid_price,
+            bid_quantity_base: msg.bid_quantity_base,
+            bid_quantity_quote: msg.bid_quantity_quote,
+            bid_quantity_contract: msg.bid_quantity_contract,
+            ask_price: msg.ask_price,
+            ask_quantity_base: msg.ask_quantity_base,
+            ask_quantity_quote: msg.ask_quantity_quote,
+            ask_quantity_contract: msg.ask_quantity_contract,
+            id: msg.id,
+            json: msg.json.clone(),
+        }
+    }
+}
+
+impl From<&FundingRateMsg> for FundingRate {
+    fn from(msg: &FundingRateMsg) -> Self {
+        FundingRate {
+            exchange: msg.exchange.clone(),
+            market_type: msg.market_type as i32,
+            msg_type: msg.msg_type as i32,
+            pair: msg.pair.clone(),
+            symbol: msg.symbol.clone(),
+            timestamp: msg.timestamp,
+            funding_rate: msg.funding_rate,
+            funding_time: msg.funding_time,
+            estimated_rate: msg.estimated_rate,
+            json: msg.json.clone(),
+        }
+    }
+}
+
+impl From<&CandlestickMsg> for Candlestick {
+    fn from(msg: &CandlestickMsg) -> Self {
+        Candlestick {
+            exchange: msg.exchange.clone(),
+            market_type: msg.market_type as i32,
+            msg_type: msg.msg_type as i32,
+            pair: msg.pair.clone(),
+            symbol: msg.symbol.clone(),
+            timestamp: msg.timestamp,
+            begin_time: msg.begin_time,
+            open: msg.open,
+            high: msg.high,
+            low: msg.low,
+            close: msg.close,
+            volume: msg.volume,
+            period: msg.period.clone(),
+            quote_volume: msg.quote_volume,
+            json: msg.json.clone(),
+        }
+    }
+}
+
+/// Collect the inner messages of one variant, fails if any message is of another variant.
+macro_rules! collect_variant {
+    ($msgs:expr, $variant:path, $proto:ty) => {
+        $msgs
+            .iter()
+            .map(|msg| match msg {
+                $variant(m) => Ok(<$proto>::from(m)),
+                _ => Err(format!("Unexpected message type {}", msg.msg_type())),
+            })
+            .collect::<Result<Vec<_>, String>>()?
+    };
+}
+
+/// Encode normalized messages of the same message type as a list message,
+/// e.g., `TradeList`.
+pub fn encode(msgs: &[NormalizedMsg]) -> Result<Vec<u8>, String> {
+    let bytes = match msgs.first() {
+        None => Vec::new(),
+        Some(NormalizedMsg::Trade(_)) => TradeList {
+            messages: collect_variant!(msgs, NormalizedMsg::Trade, Trade),
+        }
+        .encode_to_vec(),
+        Some(NormalizedMsg::OrderBook(_)) => OrderBookList {
+            messages: collect_variant!(msgs, NormalizedMsg::OrderBook, OrderBook),
+        }
+        .encode_to_vec(),
+        Some(NormalizedMsg::Bbo(_)) => BboList {
+            messages: collect_variant!(msgs, NormalizedMsg::Bbo, Bbo),
+        }
+        .encode_to_vec(),
+        Some(NormalizedMsg::FundingRate(_)) => FundingRateList {
+            messages: collect_variant!(msgs, NormalizedMsg::FundingRate, FundingRate),
+        }
+        .encode_to_vec(),
+        Some(NormalizedMsg::Candlestick(_)) => CandlestickList {
+            messages: collect_variant!(msgs, NormalizedMsg::Candlestick, Candlestick),
+        }
+        .encode_to_vec(),
+    };
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode;
+    use crate::{
+        message::parse_msg,
+        pb::{self, OrderBookList, TradeList},
+    };
+    use crypto_market_type::MarketType;
+    use crypto_msg_type::MessageType;
+    use prost::Message;
+
+    #[test]
+    fn test_encode_trade() {
+        let raw_msg = r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::Trade,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+
+        let trades = TradeList::decode(encode(&msgs).unwrap().as_slice())
+            .unwrap()
+            .messages;
+        assert_eq!(1, trades.len());
+        let trade = &trades[0];
+        assert_eq!("binance", trade.exchange);
+        assert_eq!(
+            Ok(pb::MarketType::InverseSwap),
+            trade.market_type.try_into()
+        );
+        assert_eq!(Ok(pb::MessageType::Trade), trade.msg_type.try_into());
+        assert_eq!(Ok(pb::TradeSide::Sell), trade.side.try_into());
+        assert_eq!(58570.1, trade.price);
+        assert_eq!(Some(58.0), trade.quantity_contract);
+        assert_eq!(raw_msg, trade.json);
+    }
+
+    #[test]
+    fn test_encode_l2() {
+        let raw_msg = r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}"#;
+        let msgs = parse_msg(
+            "binance",
+            MarketType::InverseSwap,
+            MessageType::L2Event,
+            raw_msg,
+            0,
+        )
+        .unwrap();
+
+        let orderbooks = OrderBookList::decode(encode(&msgs).unwrap().as_slice())
+            .unwrap()
+            .messages;
+        assert_eq!(1, orderbooks.len());
+        let orderbook = &orderbooks[0];
+        assert_eq!(Ok(pb::MessageType::L2Event), orderbook.msg_type.try_into());
+        assert_eq!(2, orderbook.asks.len());
+        assert_eq!(35365.9, orderbook.bids[0].price);
+        assert_eq!(Some(1400.0), orderbook.bids[0].quantity_contract);
+        assert_eq!(Some(127559588177), orderbook.seq_id);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::message::{parse_msg, Message};
+use crypto_market_type::MarketType;
+use crypto_msg_type::MessageType;
+use flate2::read::MultiGzDecoder;
+use serde::Serialize;
+use std::{
+    ffi::{CStr, CString},
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    os::raw::c_char,
+    path::Path,
+    str::FromStr,
+};
+use xz2::read::XzDecoder;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const XZ_MAGIC: &[u8] = &[0xfd, b'7', b'z', b'X', b'Z', 0x00];
+
+/// A line of a crypto-crawler archive.
+///
+/// Each line has five tab-separated columns: exchange, market_type,
+/// msg_type, received_at and the raw JSON message.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RawMsg {
+    pub exchange: String,
+    pub market_type: MarketType,
+    pub msg_type: MessageType,
+    /// Unix timestamp in milliseconds
+    pub received_at: i64,
+    pub json: String,
+}
+
+impl FromStr for RawMsg {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let columns: Vec<&str> = line.splitn(5, '\t').collect();
+        if columns.len() != 5 {
+            return Err(format!("Expected 5 columns, got {}", columns.len()));
+        }
+        let market_type = MarketType::from_str(columns[1])
+            .map_err(|_| format!("Unknown market type {}", columns[1]))?;
+        let msg_type = MessageType::from_str(columns[2])
+            .map_err(|_| format!("Unknown message type {}", columns[2]))?;
+        let received_at = columns[3]
+            .parse::<i64>()
+            .map_err(|_| format!("Invalid received_at {}", columns[3]))?;
+        Ok(RawMsg {
+            exchange: columns[0].to_string(),
+            market_type,
+            msg_type,
+            received_at,
+            json: columns[4].to_string(),
+        })
+    }
+}
+
+impl RawMsg {
+    /// Parse the raw JSON message with the `parse_*` function matching `msg_type`.
+    pub fn parse(&self) -> Result<Vec<Message>, String> {
+        parse_msg(
+            &self.exchange,
+            self.market_type,
+            self.msg_type,
+            &self.json,
+            self.received_at,
+        )
+    }
+}
+
+/// The result of a line, messages on success or the error text on failure.
+#[derive(Serialize, Debug)]
+pub struct ParsedLine {
+    /// 1-based line number
+    pub line_number: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Open a stream, decompressing it if it starts with a gzip or xz header.
+pub fn decompress<R: Read + 'static>(reader: R) -> io::Result<Box<dyn BufRead>> {
+    let mut buf_reader = BufReader::new(reader);
+    let header = buf_reader.fill_buf()?;
+    let reader: Box<dyn BufRead> = if header.starts_with(GZIP_MAGIC) {
+        Box::new(BufReader::new(MultiGzDecoder::new(buf_reader)))
+    } else if header.starts_with(XZ_MAGIC) {
+        Box::new(BufReader::new(XzDecoder::new_multi_decoder(buf_reader)))
+    } else {
+        Box::new(buf_reader)
+    };
+    Ok(reader)
+}
+
+/// Reads a crypto-crawler archive line by line and parses each line.
+///
+/// Plain text, gzip and xz files are supported, the compression is detected
+/// from the file content. An I/O error, e.g., of a corrupt compressed stream,
+/// is yielded once and ends the iteration, lines with invalid UTF-8 are
+/// yielded as errors.
+pub struct RawFileReader {
+    lines: io::Split<Box<dyn BufRead>>,
+    line_number: usize,
+    failed: bool,
+}
+
+impl RawFileReader {
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_reader(File::open(path)?)
+    }
+
+    pub fn from_reader<R: Read + 'static>(reader: R) -> io::Result<Self> {
+        Ok(RawFileReader {
+            lines: decompress(reader)?.split(b'\n'),
+            line_number: 0,
+            failed: false,
+        })
+    }
+}
+
+impl Iterator for RawFileReader {
+    type Item = ParsedLine;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        loop {
+            let line = self.lines.next()?;
+            self.line_number += 1;
+            let result = match line {
+                Ok(mut line) => {
+                    if line.last() == Some(&b'\r') {
+                        line.pop();
+                    }
+                    match String::from_utf8(line) {
+                        Ok(line) if line.trim().is_empty() => continue,
+                        Ok(line) => RawMsg::from_str(&line).and_then(|raw_msg| raw_msg.parse()),
+                        Err(err) => Err(err.to_string()),
+                    }
+                }
+                Err(err) => {
+                    // decoders may return the same error forever
+                    self.failed = true;
+                    Err(err.to_string())
+                }
+            };
+            let line_number = self.line_number;
+            return Some(match result {
+                Ok(messages) => ParsedLine {
+                    line_number,
+                    messages: Some(messages),
+                    error: None,
+                },
+                Err(error) => ParsedLine {
+                    line_number,
+                    messages: None,
+                    error: Some(error),
+                },
+            });
+        }
+    }
+}
+
+/// Open a crypto-crawler archive, returns NULL if the file can't be opened.
+#[no_mangle]
+pub extern "C" fn raw_file_reader_open(path: *const c_char) -> *mut RawFileReader {
+    crate::error::clear_last_error();
+    let path_rust = unsafe {
+        debug_assert!(!path.is_null());
+        CStr::from_ptr(path).to_str().unwrap()
+    };
+    match RawFileReader::open(path_rust) {
+        Ok(reader) => Box::into_raw(Box::new(reader)),
+        Err(err) => {
+            crate::error::set_last_error(&err.to_string());
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Parse the next line and convert the result to a JSON string.
+///
+/// The JSON object has a `line_number` field, and either a `messages` array
+/// or an `error` string. Returns NULL at the end of the file, and after an
+/// I/O error, which is returned as the `error` of the last line.
+#[no_mangle]
+pub extern "C" fn raw_file_reader_next(reader: *mut RawFileReader) -> *const c_char {
+    let reader_rust = unsafe {
+        debug_assert!(!reader.is_null());
+        &mut *reader
+    };
+    match reader_rust.next() {
+        Some(parsed_line) => {
+            let text = serde_json::to_string(&parsed_line).unwrap();
+            let raw = CString::new(text).unwrap();
+            raw.into_raw() as *const c_char
+        }
+        None => std::ptr::null(),
+    }
+}
+
+/// Close a reader returned by `raw_file_reader_open()`.
+#[no_mangle]
+pub extern "C" fn raw_file_reader_close(reader: *mut RawFileReader) {
+    if reader.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Box::from_raw(reader));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RawFileReader, RawMsg};
+    use crate::message::Message;
+    use crypto_market_type::MarketType;
+    use crypto_msg_type::MessageType;
+    use flate2::{write::GzEncoder, Compression};
+    use std::{io::Write, str::FromStr};
+
+    const LINES: &str = concat!(
+        "binance\tinverse_swap\ttrade\t1616201883500\t",
+        r#"{"stream":"btcusd_perp@aggTrade","data":{"e":"aggTrade","E":1616201883458,"a":41045788,"s":"BTCUSD_PERP","p":"58570.1","q":"58","f":91864326,"l":91864327,"T":1616201883304,"m":true}}"#,
+        "\n",
+        "binance\tinverse_swap\tl2_event\t1622370862600\t",
+        r#"{"stream":"btcusd_perp@depth@100ms","data":{"e":"depthUpdate","E":1622370862564,"T":1622370862553,"s":"BTCUSD_PERP","ps":"BTCUSD","U":127559587191,"u":127559588177,"pu":127559587113,"b":[["35365.9","1400"],["35425.8","561"]],"a":[["35817.8","7885"],["35818.7","307"]]}}"#,
+        "\n",
+        "binance\tinverse_swap\ttrade\t1616201883500\tnot json\n",
+    );
+
+    #[test]
+    fn test_raw_msg_from_str() {
+        let raw_msg = RawMsg::from_str(LINES.lines().next().unwrap()).unwrap();
+        assert_eq!("binance", raw_msg.exchange);
+        assert_eq!(MarketType::InverseSwap, raw_msg.market_type);
+        assert_eq!(MessageType::Trade, raw_msg.msg_type);
+        assert_eq!(1616201883500, raw_msg.received_at);
+
+        assert!(RawMsg::from_str("binance\tinverse_swap\ttrade").is_err());
+        assert!(RawMsg::from_str("binance\tinverse_swap\ttrade\tabc\t{}").is_err());
+    }
+
+    #[test]
+    fn test_read_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(LINES.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let lines: Vec<_> = RawFileReader::from_reader(std::io::Cursor::new(compressed))
+            .unwrap()
+            .collect();
+        assert_eq!(3, lines.len());
+
+        let trades = lines[0].messages.as_ref().unwrap();
+        assert_eq!(1, trades.len());
+        assert!(matches!(&trades[0], Message::Trade(trade) if trade.price == 58570.1));
+
+        let orderbooks = lines[1].messages.as_ref().unwrap();
+        assert!(
+            matches!(&orderbooks[0], Message::OrderBook(orderbook) if orderbook.bids.len() == 2)
+        );
+
+        assert_eq!(3, lines[2].line_number);
+        assert!(lines[2].messages.is_none());
+        assert!(lines[2].error.is_some());
+    }
+
+    #[test]
+    fn test_read_corrupt_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(LINES.as_bytes()).unwrap();
+        let mut compressed = encoder.finish().unwrap();
+        compressed.truncate(compressed.len() / 2);
+        compressed.extend_from_slice(&[0xff; 64]);
+
+        let lines: Vec<_> = RawFileReader::from_reader(std::io::Cursor::new(compressed))
+            .unwrap()
+            .take(10)
+            .collect();
+        assert!(lines.len() < 10);
+        assert!(lines.last().unwrap().error.is_some());
+    }
+
+    #[test]
+    fn test_read_invalid_utf8() {
+        let mut input = b"binance\tinverse_swap\ttrade\t1616201883500\t\xff\r\n".to_vec();
+        input.extend_from_slice(LINES.as_bytes());
+        let lines: Vec<_> = RawFileReader::from_reader(std::io::Cursor::new(input))
+            .unwrap()
+            .collect();
+        assert_eq!(4, lines.len());
+        assert!(lines[0].error.is_some());
+        assert!(lines[1].messages.is_some());
+    }
+}
@@ -0,0 +1,353 @@
+//! Decoder of Binance spot SBE market data streams.
+//!
+//! Frames follow the `spot_stream` schema (id 1) published by Binance, all
+//! integers are little-endian. Every frame starts with the standard SBE
+//! message header, i.e., blockLength, templateId, schemaId and version, each
+//! a `u16`. Fields unknown to this decoder, appended by newer schema
+//! versions, are skipped by `blockLength`.
+
+use crate::{decimal::FixedPoint, message::Message};
+use crypto_market_type::MarketType;
+use crypto_message::{BboMsg, Order, OrderBookMsg, TradeMsg, TradeSide};
+use crypto_msg_type::MessageType;
+
+const EXCHANGE_NAME: &str = "binance";
+
+const SCHEMA_ID: u16 = 1;
+
+const TRADES_STREAM_EVENT: u16 = 10000;
+const BEST_BID_ASK_STREAM_EVENT: u16 = 10001;
+const DEPTH_SNAPSHOT_STREAM_EVENT: u16 = 10002;
+const DEPTH_DIFF_STREAM_EVENT: u16 = 10003;
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let end = self.pos + n;
+        if end > self.bytes.len() {
+            return Err(format!(
+                "Truncated SBE frame, expected at least {end} bytes, got {}",
+                self.bytes.len()
+            ));
+        }
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn i8(&mut self) -> Result<i8, String> {
+        Ok(self.take(1)?[0] as i8)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, String> {
+        Ok(i64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    /// Skip to the end of a block starting at `start`.
+    fn skip_block(&mut self, start: usize, block_length: u16) -> Result<(), String> {
+        let end = start + block_length as usize;
+        if end < self.pos {
+            return Err(format!("SBE block length {block_length} is too small"));
+        }
+        self.take(end - self.pos).map(|_| ())
+    }
+
+    /// Read a group with a `u16` or `u32` count, entries are read by `f`.
+    fn group<T>(
+        &mut self,
+        wide: bool,
+        mut f: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let block_length = self.u16()?;
+        let num_in_group = if wide {
+            self.u32()? as usize
+        } else {
+            self.u16()? as usize
+        };
+        let mut entries = Vec::with_capacity(num_in_group.min(self.bytes.len()));
+        for _ in 0..num_in_group {
+            let start = self.pos;
+            entries.push(f(self)?);
+            self.skip_block(start, block_length)?;
+        }
+        Ok(entries)
+    }
+
+    fn var_string8(&mut self) -> Result<String, String> {
+        let len = self.u8()? as usize;
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|err| err.to_string())
+    }
+}
+
+fn to_f64(mantissa: i64, exponent: i8) -> f64 {
+    FixedPoint {
+        mantissa,
+        exponent: exponent.into(),
+    }
+    .to_f64()
+}
+
+fn normalize_pair(symbol: &str) -> Result<String, String> {
+    crypto_pair::normalize_pair(symbol, EXCHANGE_NAME)
+        .ok_or_else(|| format!("Failed to normalize {symbol}"))
+}
+
+/// Decode a frame of the Binance spot SBE market data streams.
+///
+/// Supports trades, best bid/ask, depth snapshots and depth diffs, the
+/// template of the frame must match `msg_type`. Timestamps in microseconds
+/// are converted to milliseconds, the `json` field is empty since there is no
+/// JSON message.
+pub fn decode(
+    market_type: MarketType,
+    msg_type: MessageType,
+    bytes: &[u8],
+) -> Result<Vec<Message>, String> {
+    if market_type != MarketType::Spot {
+        return Err(format!("Binance SBE streams don't support {market_type}"));
+    }
+    let mut reader = Reader { bytes, pos: 0 };
+    let block_length = reader.u16()?;
+    let template_id = reader.u16()?;
+    let schema_id = reader.u16()?;
+    let _version = reader.u16()?;
+    if schema_id != SCHEMA_ID {
+        return Err(format!("Unsupported SBE schema {schema_id}"));
+    }
+    let expected = match template_id {
+        TRADES_STREAM_EVENT => MessageType::Trade,
+        BEST_BID_ASK_STREAM_EVENT => MessageType::BBO,
+        DEPTH_SNAPSHOT_STREAM_EVENT => MessageType::L2Snapshot,
+        DEPTH_DIFF_STREAM_EVENT => MessageType::L2Event,
+        _ => return Err(format!("Unsupported SBE template {template_id}")),
+    };
+    if expected != msg_type {
+        return Err(format!("SBE template {template_id} is not {msg_type}"));
+    }
+
+    let start = reader.pos;
+    match template_id {
+        TRADES_STREAM_EVENT => {
+            let event_time = reader.i64()?;
+            let _transact_time = reader.i64()?;
+            let price_exponent = reader.i8()?;
+            let qty_exponent = reader.i8()?;
+            reader.skip_block(start, block_length)?;
+            let trades = reader.group(true, |r| {
+                let id = r.i64()?;
+                let price = r.i64()?;
+                let qty = r.i64()?;
+                let is_buyer_maker = r.u8()? == 1;
+                Ok((id, price, qty, is_buyer_maker))
+            })?;
+            let symbol = reader.var_string8()?;
+            let pair = normalize_pair(&symbol)?;
+            Ok(trades
+                .into_iter()
+                .map(|(id, price, qty, is_buyer_maker)| {
+                    let price = to_f64(price, price_exponent);
+                    let quantity = to_f64(qty, qty_exponent);
+                    Message::Trade(TradeMsg {
+                        exchange: EXCHANGE_NAME.to_string(),
+                        market_type,
+                        symbol: symbol.clone(),
+                        pair: pair.clone(),
+                        msg_type,
+                        timestamp: event_time / 1000,
+                        price,
+                        quantity_base: quantity,
+                        quantity_quote: price * quantity,
+                        quantity_contract: None,
+                        side: if is_buyer_maker {
+                            TradeSide::Sell
+                        } else {
+                            TradeSide::Buy
+                        },
+                        trade_id: id.to_string(),
+                        json: String::new(),
+                    })
+                })
+                .collect())
+        }
+        BEST_BID_ASK_STREAM_EVENT => {
+            let event_time = reader.i64()?;
+            let book_update_id = reader.i64()?;
+            let price_exponent = reader.i8()?;
+            let qty_exponent = reader.i8()?;
+            let bid_price = to_f64(reader.i64()?, price_exponent);
+            let bid_qty = to_f64(reader.i64()?, qty_exponent);
+            let ask_price = to_f64(reader.i64()?, price_exponent);
+            let ask_qty = to_f64(reader.i64()?, qty_exponent);
+            reader.skip_block(start, block_length)?;
+            let symbol = reader.var_string8()?;
+            let pair = normalize_pair(&symbol)?;
+            Ok(vec![Message::Bbo(BboMsg {
+                exchange: EXCHANGE_NAME.to_string(),
+                market_type,
+                symbol,
+                pair,
+                msg_type,
+                timestamp: event_time / 1000,
+                bid_price,
+                bid_quantity_base: bid_qty,
+                bid_quantity_quote: bid_price * bid_qty,
+                bid_quantity_contract: None,
+                ask_price,
+                ask_quantity_base: ask_qty,
+                ask_quantity_quote: ask_price * ask_qty,
+                ask_quantity_contract: None,
+                id: Some(book_update_id as u64),
+                json: String::new(),
+            })])
+        }
+        _ => {
+            let event_time = reader.i64()?;
+            let (seq_id, prev_seq_id) = if template_id == DEPTH_DIFF_STREAM_EVENT {
+                let first_book_update_id = reader.i64()?;
+                let last_book_update_id = reader.i64()?;
+                (
+                    last_book_update_id,
+                    (first_book_update_id as u64).checked_sub(1),
+                )
+            } else {
+                (reader.i64()?, None)
+            };
+            let price_exponent = reader.i8()?;
+            let qty_exponent = reader.i8()?;
+            reader.skip_block(start, block_length)?;
+            let mut read_levels = || {
+                reader.group(false, |r| {
+                    let price = to_f64(r.i64()?, price_exponent);
+                    let quantity = to_f64(r.i64()?, qty_exponent);
+                    Ok(Order {
+                        price,
+                        quantity_base: quantity,
+                        quantity_quote: price * quantity,
+                        quantity_contract: None,
+                    })
+                })
+            };
+            let bids = read_levels()?;
+            let asks = read_levels()?;
+            let symbol = reader.var_string8()?;
+            let pair = normalize_pair(&symbol)?;
+            Ok(vec![Message::OrderBook(OrderBookMsg {
+                exchange: EXCHANGE_NAME.to_string(),
+                market_type,
+                symbol,
+                pair,
+                msg_type,
+                timestamp: event_time / 1000,
+                snapshot: template_id == DEPTH_SNAPSHOT_STREAM_EVENT,
+                asks,
+                bids,
+                seq_id: Some(seq_id as u64),
+                prev_seq_id,
+                json: String::new(),
+            })])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+    use crate::message::Message;
+    use crypto_market_type::MarketType;
+    use crypto_message::TradeSide;
+    use crypto_msg_type::MessageType;
+
+    fn header(block_length: u16, template_id: u16) -> Vec<u8> {
+        [block_length, template_id, 1, 0]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect()
+    }
+
+    fn symbol(bytes: &mut Vec<u8>, symbol: &str) {
+        bytes.push(symbol.len() as u8);
+        bytes.extend(symbol.as_bytes());
+    }
+
+    #[test]
+    fn test_decode_trades() {
+        let mut bytes = header(18, 10000);
+        bytes.extend(1616201883458000i64.to_le_bytes());
+        bytes.extend(1616201883457000i64.to_le_bytes());
+        bytes.extend([-2i8 as u8, -5i8 as u8]);
+        // 2 trades with one more byte per entry from a newer schema version
+        bytes.extend(27u16.to_le_bytes());
+        bytes.extend(2u32.to_le_bytes());
+        for (id, price, qty, is_buyer_maker) in
+            [(1i64, 5857010i64, 58i64, 1u8), (2, 5857020, 100000, 0)]
+        {
+            bytes.extend(id.to_le_bytes());
+            bytes.extend(price.to_le_bytes());
+            bytes.extend(qty.to_le_bytes());
+            bytes.extend([is_buyer_maker, 1, 0]);
+        }
+        symbol(&mut bytes, "BTCUSDT");
+
+        let msgs = decode(MarketType::Spot, MessageType::Trade, &bytes).unwrap();
+        assert_eq!(2, msgs.len());
+        let Message::Trade(trade) = &msgs[0] else {
+            panic!("not a trade");
+        };
+        assert_eq!("BTC/USDT", trade.pair);
+        assert_eq!(1616201883458, trade.timestamp);
+        assert_eq!(58570.1, trade.price);
+        assert_eq!(0.00058, trade.quantity_base);
+        assert_eq!(TradeSide::Sell, trade.side);
+        assert_eq!("1", trade.trade_id);
+
+        assert!(decode(MarketType::Spot, MessageType::BBO, &bytes).is_err());
+        assert!(decode(MarketType::Spot, MessageType::Trade, &bytes[..30]).is_err());
+    }
+
+    #[test]
+    fn test_decode_depth_diff() {
+        let mut bytes = header(26, 10003);
+        bytes.extend(1616201883458000i64.to_le_bytes());
+        bytes.extend(101i64.to_le_bytes());
+        bytes.extend(105i64.to_le_bytes());
+        bytes.extend([-1i8 as u8, -3i8 as u8]);
+        for levels in [vec![(585701i64, 1500i64)], vec![(585702, 0), (585710, 20)]] {
+            bytes.extend(16u16.to_le_bytes());
+            bytes.extend((levels.len() as u16).to_le_bytes());
+            for (price, qty) in levels {
+                bytes.extend(price.to_le_bytes());
+                bytes.extend(qty.to_le_bytes());
+            }
+        }
+        symbol(&mut bytes, "BTCUSDT");
+
+        let msgs = decode(MarketType::Spot, MessageType::L2Event, &bytes).unwrap();
+        let Message::OrderBook(orderbook) = &msgs[0] else {
+            panic!("not an orderbook");
+        };
+        assert!(!orderbook.snapshot);
+        assert_eq!(Some(105), orderbook.seq_id);
+        assert_eq!(Some(100), orderbook.prev_seq_id);
+        assert_eq!(1, orderbook.bids.len());
+        assert_eq!(58570.1, orderbook.bids[0].price);
+        assert_eq!(1.5, orderbook.bids[0].quantity_base);
+        assert_eq!(2, orderbook.asks.len());
+        assert_eq!(0.0, orderbook.asks[0].quantity_base);
+    }
+}
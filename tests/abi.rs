@@ -0,0 +1,201 @@
+//! Compares the exported symbols of the shared library and the declarations
+//! in the C headers with the baseline in tests/abi, so that changes of the ABI
+//! are never silent. Comments and blank lines of the headers are ignored.
+//!
+//! Symbols are not versioned: rustc links a cdylib with its own anonymous
+//! version script, GNU ld rejects a second script with version tags and lld
+//! leaves the symbols unversioned, so the baseline is the only check.
+//!
+//! After an intended change, bump `CRYPTO_MSG_PARSER_FFI_ABI_VERSION` if it
+//! breaks compatibility, then update the baseline with:
+//!
+//!   UPDATE_ABI_BASELINE=1 cargo test --all-features --test abi
+
+#![cfg(target_os = "linux")]
+
+use std::{
+    collections::BTreeSet,
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const HEADERS: &[&str] = &[
+    "crypto_market_type.h",
+    "crypto_msg_type.h",
+    "crypto_msg_parser_ffi.h",
+];
+
+/// Symbols exported by the Java and Node.js bindings, which are not part of
+/// the C API.
+const BINDING_PREFIXES: &[&str] = &["Java_", "napi_"];
+
+fn baseline_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/abi")
+}
+
+fn update_baseline() -> bool {
+    env::var_os("UPDATE_ABI_BASELINE").is_some()
+}
+
+fn enabled(feature: &str) -> bool {
+    match feature {
+        "arrow" => cfg!(feature = "arrow"),
+        "parquet" => cfg!(feature = "parquet"),
+        _ => panic!("Unknown feature {feature}"),
+    }
+}
+
+/// Lines of a header without comments, trailing whitespace and blank lines.
+fn declarations(text: &str) -> Vec<String> {
+    let mut code = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("/*") {
+        code.push_str(&rest[..start]);
+        rest = rest[start..]
+            .split_once("*/")
+            .map_or("", |(_, after)| after);
+    }
+    code.push_str(rest);
+    code.lines()
+        .map(|line| line.split_once("//").map_or(line, |(code, _)| code))
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Symbols exported by the shared library built with the features of this test.
+fn exported_symbols() -> BTreeSet<String> {
+    // the test executable is target/<profile>/deps/abi-<hash>
+    let library = env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("libcrypto_msg_parser_ffi.so");
+    let output = Command::new(env::var("NM").unwrap_or_else(|_| "nm".to_string()))
+        .args(["--dynamic", "--defined-only", "--format=posix"])
+        .arg(library)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|name| {
+            !BINDING_PREFIXES
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn test_declarations() {
+    let header = "/* Licensed under Apache-2.0 */\n\n/**\n * A buffer.\n */\ntypedef struct {\n  uint8_t *data; // owned\n  size_t len;  \n} ByteBuffer;\n";
+    assert_eq!(
+        vec![
+            "typedef struct {",
+            "  uint8_t *data;",
+            "  size_t len;",
+            "} ByteBuffer;"
+        ],
+        declarations(header)
+    );
+}
+
+#[test]
+fn test_headers() {
+    let include_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("include");
+    for header in HEADERS {
+        let actual = fs::read_to_string(include_dir.join(header)).unwrap();
+        let baseline_path = baseline_dir().join(header);
+        if update_baseline() {
+            fs::write(&baseline_path, &actual).unwrap();
+            continue;
+        }
+        let expected = fs::read_to_string(&baseline_path).unwrap();
+        let (actual, expected) = (declarations(&actual), declarations(&expected));
+        let first_difference = actual
+            .iter()
+            .zip(expected.iter())
+            .position(|(a, e)| a != e)
+            .unwrap_or_else(|| actual.len().min(expected.len()));
+        assert!(
+            actual == expected,
+            "{header} differs from the ABI baseline:\n  baseline: {:?}\n  actual:   {:?}",
+            expected.get(first_difference),
+            actual.get(first_difference),
+        );
+    }
+}
+
+/// tests/abi/symbols.txt lists the symbols of the C API, each optionally
+/// followed by the feature exporting it. Updating the baseline keeps the
+/// features of existing symbols, those of new symbols are added by hand.
+#[test]
+fn test_exported_symbols() {
+    let baseline_path = baseline_dir().join("symbols.txt");
+    let baseline = fs::read_to_string(&baseline_path).unwrap();
+    let actual = exported_symbols();
+
+    if update_baseline() {
+        if !cfg!(feature = "parquet") {
+            panic!("Update the baseline with --all-features");
+        }
+        // keep the features of known symbols
+        let features = baseline
+            .lines()
+            .filter_map(|line| line.split_once(' '))
+            .collect::<Vec<_>>();
+        let mut text = String::new();
+        for symbol in &actual {
+            match features.iter().find(|(name, _)| name == symbol) {
+                Some((_, feature)) => text.push_str(&format!("{symbol} {feature}\n")),
+                None => text.push_str(&format!("{symbol}\n")),
+            }
+        }
+        fs::write(&baseline_path, text).unwrap();
+        return;
+    }
+
+    let expected = baseline
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let symbol = fields.next()?;
+            fields
+                .next()
+                .is_none_or(enabled)
+                .then(|| symbol.to_string())
+        })
+        .collect::<BTreeSet<_>>();
+    let removed = expected.difference(&actual).collect::<Vec<_>>();
+    let added = actual.difference(&expected).collect::<Vec<_>>();
+    assert!(
+        removed.is_empty() && added.is_empty(),
+        "Exported symbols differ from the ABI baseline, removed: {removed:?}, added: {added:?}"
+    );
+}
+
+#[test]
+fn test_abi_version() {
+    let header = fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("include/crypto_msg_parser_ffi.h"),
+    )
+    .unwrap();
+    let define = format!(
+        "#define CRYPTO_MSG_PARSER_FFI_ABI_VERSION {}",
+        crypto_msg_parser_ffi::abi::abi_version()
+    );
+    assert!(
+        header.lines().any(|line| line == define),
+        "abi_version() differs from CRYPTO_MSG_PARSER_FFI_ABI_VERSION in the header"
+    );
+}
@@ -0,0 +1,558 @@
+/* Licensed under Apache-2.0 */
+
+#ifndef CRYPTO_MSG_PARSER_FFI_H_
+#define CRYPTO_MSG_PARSER_FFI_H_
+
+/* Generated with cbindgen:0.24.5 */
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "crypto_market_type.h"
+#include "crypto_msg_type.h"
+#ifndef ARROW_C_DATA_INTERFACE
+#define ARROW_C_DATA_INTERFACE
+
+#define ARROW_FLAG_DICTIONARY_ORDERED 1
+#define ARROW_FLAG_NULLABLE 2
+#define ARROW_FLAG_MAP_KEYS_SORTED 4
+
+struct ArrowSchema {
+  // Array type description
+  const char* format;
+  const char* name;
+  const char* metadata;
+  int64_t flags;
+  int64_t n_children;
+  struct ArrowSchema** children;
+  struct ArrowSchema* dictionary;
+
+  // Release callback
+  void (*release)(struct ArrowSchema*);
+  // Opaque producer-specific data
+  void* private_data;
+};
+
+struct ArrowArray {
+  // Array data description
+  int64_t length;
+  int64_t null_count;
+  int64_t offset;
+  int64_t n_buffers;
+  int64_t n_children;
+  const void** buffers;
+  struct ArrowArray** children;
+  struct ArrowArray* dictionary;
+
+  // Release callback
+  void (*release)(struct ArrowArray*);
+  // Opaque producer-specific data
+  void* private_data;
+};
+
+#endif  // ARROW_C_DATA_INTERFACE
+
+/**
+ * ABI version of the header a program is compiled against.
+ */
+#define CRYPTO_MSG_PARSER_FFI_ABI_VERSION 1
+
+/**
+ * Compression of a raw message.
+ */
+typedef enum {
+  /**
+   * Uncompressed text
+   */
+  Compression_Plain = 0,
+  Compression_Gzip = 1,
+  /**
+   * Raw deflate stream without header, RFC 1951
+   */
+  Compression_Deflate = 2,
+  /**
+   * Deflate stream with a zlib header, RFC 1950
+   */
+  Compression_Zlib = 3,
+  /**
+   * Detect gzip and zlib by their headers, otherwise the frame is plain
+   * text if it starts with `{` or `[`, or a raw deflate stream
+   */
+  Compression_Auto = 4,
+} Compression;
+
+/**
+ * How prices and quantities are represented in the output.
+ */
+typedef enum {
+  /**
+   * Floating point numbers, same as the `parse_*` functions
+   */
+  NumberFormat_Float = 0,
+  /**
+   * The decimal strings of the exchange, e.g., `"0.00073689"`, null for
+   * values not in the raw message
+   */
+  NumberFormat_DecimalString = 1,
+  /**
+   * Integer mantissas of the decimal strings of the exchange, each message
+   * has a decimal exponent per column, e.g., `"price": 585701` with
+   * `"price_exponent": -1`. Values not in the raw message are null, a
+   * mantissa overflowing `i64` is an error
+   */
+  NumberFormat_FixedPoint = 2,
+} NumberFormat;
+
+/**
+ * Serialization format of normalized messages.
+ *
+ * All formats keep the field names of the JSON output.
+ */
+typedef enum {
+  OutputFormat_Json = 0,
+  OutputFormat_MessagePack = 1,
+  OutputFormat_Cbor = 2,
+  /**
+   * A list message defined in `proto/crypto_msg_parser.proto`, e.g., `TradeList`
+   */
+  OutputFormat_Protobuf = 3,
+  /**
+   * A `Messages` root table defined in `fbs/crypto_msg_parser.fbs`, only
+   * trade, orderbook and BBO messages are supported
+   */
+  OutputFormat_FlatBuffers = 4,
+  /**
+   * CSV rows without the header row, see `csv_header()` and the `csv` module
+   */
+  OutputFormat_Csv = 5,
+} OutputFormat;
+
+/**
+ * Encoding of a raw message.
+ */
+typedef enum {
+  /**
+   * UTF-8 JSON text, parsed by `crypto-msg-parser`
+   */
+  PayloadEncoding_Json = 0,
+  /**
+   * Binance spot SBE market data streams, see the `sbe` module
+   */
+  PayloadEncoding_BinanceSbe = 1,
+} PayloadEncoding;
+
+#if defined(CRYPTO_MSG_PARSER_FFI_PARQUET)
+/**
+ * Accumulates normalized messages of one message type and writes them into
+ * a Parquet file.
+ *
+ * The file has the same schema as `arrow::schema()`, uses ZSTD compression,
+ * and up to 1,048,576 rows per row group. It is incomplete until
+ * `close()` is called.
+ */
+typedef struct ParquetWriter ParquetWriter;
+#endif
+
+/**
+ * Reads a crypto-crawler archive line by line and parses each line.
+ *
+ * Plain text, gzip and xz files are supported, the compression is detected
+ * from the file content. An I/O error, e.g., of a corrupt compressed stream,
+ * is yielded once and ends the iteration, lines with invalid UTF-8 are
+ * yielded as errors.
+ */
+typedef struct RawFileReader RawFileReader;
+
+/**
+ * A byte buffer allocated by this library.
+ *
+ * `data` is NULL if an error happens, call `deallocate_bytes()` to free it.
+ */
+typedef struct {
+  uint8_t *data;
+  size_t len;
+} ByteBuffer;
+
+/**
+ * Options of the `parse_*_with_options()` functions.
+ */
+typedef struct {
+  OutputFormat format;
+  /**
+   * Only JSON, MessagePack and CBOR support number formats other than `Float`
+   */
+  NumberFormat number_format;
+  /**
+   * Comma separated names of fields to keep, e.g., `"timestamp,price,quantity_base,side"`,
+   * NULL means all fields.
+   *
+   * Only JSON, MessagePack and CBOR support it. Exponent fields of the
+   * `FixedPoint` number format are always kept.
+   */
+  const char *fields;
+  /**
+   * Replace the original message in the `json` field with an empty string,
+   * which roughly halves the output size.
+   */
+  bool omit_json;
+} ParseOptions;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Extract the symbol from the message.
+ *
+ * * If the message contains multiple symbols, `ALL` is returned;
+ * * If the message has no symbol, `NONE` is returned.
+ */
+const char *extract_symbol(const char *exchange,
+                           MarketType market_type,
+                           const char *msg);
+
+/**
+ * Extract the timestamp from the message.
+ *
+ * Returns 0 if the message doesn't have a timestamp, -1 if an error happens.
+ */
+int64_t extract_timestamp(const char *exchange,
+                          MarketType market_type,
+                          const char *msg);
+
+/**
+ * Infer the message type from the message.
+ */
+MessageType get_msg_type(const char *exchange, const char *msg);
+
+/**
+ * Parse a raw trade message into a Vec<TradeMsg> and then convert to a JSON string.
+ */
+const char *parse_trade(const char *exchange,
+                        MarketType market_type,
+                        const char *msg);
+
+/**
+ * Parse a raw level2 orderbook message into a Vec<OrderBookMsg> and then convert to a JSON string.
+ */
+const char *parse_l2(const char *exchange,
+                     MarketType market_type,
+                     const char *msg,
+                     int64_t received_at);
+
+/**
+ * Parse a level2 topk orderbook message into a Vec<OrderBookMsg> and then convert to a JSON string.
+ */
+const char *parse_l2_topk(const char *exchange,
+                          MarketType market_type,
+                          const char *msg,
+                          int64_t received_at);
+
+/**
+ * Parse a BBO(best bid&offer) message into a Vec<BboMsg> and then convert to a JSON string.
+ */
+const char *parse_bbo(const char *exchange,
+                      MarketType market_type,
+                      const char *msg,
+                      int64_t received_at);
+
+/**
+ * Parse a raw funding rate message into a Vec<FundingRateMsg> and then convert to a JSON string.
+ */
+const char *parse_funding_rate(const char *exchange,
+                               MarketType market_type,
+                               const char *msg,
+                               int64_t received_at);
+
+/**
+ * Parse a raw candlestick message into a Vec<CandlestickMsg> and then convert to a JSON string.
+ */
+const char *parse_candlestick(const char *exchange,
+                              MarketType market_type,
+                              const char *msg,
+                              int64_t received_at);
+
+/**
+ * Get the CSV header row of a message type, returns NULL if the message
+ * type has no CSV representation.
+ *
+ * See the `csv` module for the columns of each message type.
+ */
+const char *csv_header(MessageType msg_type);
+
+/**
+ * Parse a raw trade message into a Vec<TradeMsg> and then serialize it in the given format.
+ */
+ByteBuffer parse_trade_with_format(const char *exchange,
+                                   MarketType market_type,
+                                   const char *msg,
+                                   OutputFormat format);
+
+/**
+ * Parse a raw level2 orderbook message into a Vec<OrderBookMsg> and then serialize it in the given format.
+ */
+ByteBuffer parse_l2_with_format(const char *exchange,
+                                MarketType market_type,
+                                const char *msg,
+                                int64_t received_at,
+                                OutputFormat format);
+
+/**
+ * Parse a level2 topk orderbook message into a Vec<OrderBookMsg> and then serialize it in the given format.
+ */
+ByteBuffer parse_l2_topk_with_format(const char *exchange,
+                                     MarketType market_type,
+                                     const char *msg,
+                                     int64_t received_at,
+                                     OutputFormat format);
+
+/**
+ * Parse a BBO(best bid&offer) message into a Vec<BboMsg> and then serialize it in the given format.
+ */
+ByteBuffer parse_bbo_with_format(const char *exchange,
+                                 MarketType market_type,
+                                 const char *msg,
+                                 int64_t received_at,
+                                 OutputFormat format);
+
+/**
+ * Parse a raw funding rate message into a Vec<FundingRateMsg> and then serialize it in the given format.
+ */
+ByteBuffer parse_funding_rate_with_format(const char *exchange,
+                                          MarketType market_type,
+                                          const char *msg,
+                                          int64_t received_at,
+                                          OutputFormat format);
+
+/**
+ * Parse a raw candlestick message into a Vec<CandlestickMsg> and then serialize it in the given format.
+ */
+ByteBuffer parse_candlestick_with_format(const char *exchange,
+                                         MarketType market_type,
+                                         const char *msg,
+                                         int64_t received_at,
+                                         OutputFormat format);
+
+/**
+ * Parse a raw trade message into a Vec<TradeMsg> and then serialize it with the given options.
+ */
+ByteBuffer parse_trade_with_options(const char *exchange,
+                                    MarketType market_type,
+                                    const char *msg,
+                                    ParseOptions options);
+
+/**
+ * Parse a raw level2 orderbook message into a Vec<OrderBookMsg> and then serialize it with the given options.
+ */
+ByteBuffer parse_l2_with_options(const char *exchange,
+                                 MarketType market_type,
+                                 const char *msg,
+                                 int64_t received_at,
+                                 ParseOptions options);
+
+/**
+ * Parse a level2 topk orderbook message into a Vec<OrderBookMsg> and then serialize it with the given options.
+ */
+ByteBuffer parse_l2_topk_with_options(const char *exchange,
+                                      MarketType market_type,
+                                      const char *msg,
+                                      int64_t received_at,
+                                      ParseOptions options);
+
+/**
+ * Parse a BBO(best bid&offer) message into a Vec<BboMsg> and then serialize it with the given options.
+ */
+ByteBuffer parse_bbo_with_options(const char *exchange,
+                                  MarketType market_type,
+                                  const char *msg,
+                                  int64_t received_at,
+                                  ParseOptions options);
+
+/**
+ * Parse a raw funding rate message into a Vec<FundingRateMsg> and then serialize it with the given options.
+ */
+ByteBuffer parse_funding_rate_with_options(const char *exchange,
+                                           MarketType market_type,
+                                           const char *msg,
+                                           int64_t received_at,
+                                           ParseOptions options);
+
+/**
+ * Parse a raw candlestick message into a Vec<CandlestickMsg> and then serialize it with the given options.
+ */
+ByteBuffer parse_candlestick_with_options(const char *exchange,
+                                          MarketType market_type,
+                                          const char *msg,
+                                          int64_t received_at,
+                                          ParseOptions options);
+
+/**
+ * Parse a possibly compressed raw message of `len` bytes with the `parse_*`
+ * function matching `msg_type`, then serialize the messages with the given options.
+ *
+ * The message is decompressed according to `compression` before parsing,
+ * `received_at` is ignored by trade messages.
+ */
+ByteBuffer parse_compressed(const char *exchange,
+                            MarketType market_type,
+                            MessageType msg_type,
+                            const uint8_t *data,
+                            size_t len,
+                            Compression compression,
+                            int64_t received_at,
+                            ParseOptions options);
+
+/**
+ * Parse a raw message of `len` bytes in the given encoding, then serialize
+ * the messages with the given options.
+ *
+ * Binary payloads such as `BinanceSbe` produce the same normalized messages
+ * as JSON ones, with an empty `json` field.
+ */
+ByteBuffer parse_payload_bytes(const char *exchange,
+                               MarketType market_type,
+                               MessageType msg_type,
+                               const uint8_t *data,
+                               size_t len,
+                               PayloadEncoding encoding,
+                               int64_t received_at,
+                               ParseOptions options);
+
+/**
+ * Deallocate a string.
+ */
+void deallocate_string(const char *pointer);
+
+/**
+ * Get the ABI version of the loaded library.
+ *
+ * A program should check at startup that it equals the
+ * `CRYPTO_MSG_PARSER_FFI_ABI_VERSION` it was compiled with.
+ */
+uint32_t abi_version(void);
+
+#if defined(CRYPTO_MSG_PARSER_FFI_ARROW)
+/**
+ * Export the Arrow schema of a message type via the Arrow C Data Interface.
+ *
+ * Returns false if the message type is not supported. The caller owns
+ * `out_schema` and must call its `release` callback.
+ */
+bool export_arrow_schema(MessageType msg_type, struct ArrowSchema *out_schema);
+#endif
+
+#if defined(CRYPTO_MSG_PARSER_FFI_ARROW)
+/**
+ * Parse a batch of raw messages of the same message type into an Arrow
+ * record batch, exported via the Arrow C Data Interface.
+ *
+ * * `msgs` is an array of `len` raw messages.
+ * * `received_at` is either NULL or an array of `len` timestamps.
+ *
+ * The record batch is exported as a struct array, e.g., in Python, use
+ * `pyarrow.RecordBatch._import_from_c(array_ptr, schema_ptr)` to import it.
+ * The caller owns `out_array` and `out_schema` and must call their `release`
+ * callbacks.
+ *
+ * Returns the number of messages that failed to parse, or -1 if an error happens.
+ */
+int64_t parse_batch_arrow(const char *exchange,
+                          MarketType market_type,
+                          MessageType msg_type,
+                          const char *const *msgs,
+                          const int64_t *received_at,
+                          size_t len,
+                          struct ArrowArray *out_array,
+                          struct ArrowSchema *out_schema);
+#endif
+
+/**
+ * Get the error message of the last call on the current thread, returns
+ * NULL if it succeeded.
+ *
+ * Errors are recorded by the functions returning a `ByteBuffer`
+ * (`parse_*_with_format()`, `parse_*_with_options()`, `parse_compressed()`
+ * and `parse_payload_bytes()`), `raw_file_reader_open()`,
+ * `export_arrow_schema()`, `parse_batch_arrow()` and the `parquet_writer_*`
+ * functions. Each of them clears the error when called, so the result
+ * always belongs to the last of them. Functions returning a string, such as
+ * `parse_trade()` or `extract_symbol()`, only return NULL on failure and
+ * leave the error untouched.
+ *
+ * The string is owned by this library and valid until the next call of a
+ * function that records errors on the same thread, don't free it.
+ */
+const char *last_error(void);
+
+/**
+ * Get the default options, i.e., JSON output with floating point numbers.
+ */
+ParseOptions default_parse_options(void);
+
+/**
+ * Deallocate a byte buffer.
+ */
+void deallocate_bytes(ByteBuffer buffer);
+
+#if defined(CRYPTO_MSG_PARSER_FFI_PARQUET)
+/**
+ * Create a Parquet file for messages of `msg_type`, returns NULL if an error happens.
+ */
+ParquetWriter *parquet_writer_open(const char *path,
+                                   MessageType msg_type);
+#endif
+
+#if defined(CRYPTO_MSG_PARSER_FFI_PARQUET)
+/**
+ * Parse a raw message with the message type of the writer and write the
+ * normalized messages.
+ *
+ * Returns the number of normalized messages, or -1 if an error happens. The
+ * messages of a failed call are not written, so it is safe to retry, unless
+ * writing a full batch failed, which drops the batch including messages of
+ * earlier calls and likely leaves the file unusable.
+ */
+int64_t parquet_writer_write(ParquetWriter *writer,
+                             const char *exchange,
+                             MarketType market_type,
+                             const char *msg,
+                             int64_t received_at);
+#endif
+
+#if defined(CRYPTO_MSG_PARSER_FFI_PARQUET)
+/**
+ * Flush and close a writer returned by `parquet_writer_open()`.
+ *
+ * Returns false if the file can't be completed, the writer is freed anyway.
+ */
+bool parquet_writer_close(ParquetWriter *writer);
+#endif
+
+/**
+ * Open a crypto-crawler archive, returns NULL if the file can't be opened.
+ */
+RawFileReader *raw_file_reader_open(const char *path);
+
+/**
+ * Parse the next line and convert the result to a JSON string.
+ *
+ * The JSON object has a `line_number` field, and either a `messages` array
+ * or an `error` string. Returns NULL at the end of the file, and after an
+ * I/O error, which is returned as the `error` of the last line.
+ */
+const char *raw_file_reader_next(RawFileReader *reader);
+
+/**
+ * Close a reader returned by `raw_file_reader_open()`.
+ */
+void raw_file_reader_close(RawFileReader *reader);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* CRYPTO_MSG_PARSER_FFI_H_ */
@@ -0,0 +1,72 @@
+/* Licensed under Apache-2.0 */
+#ifndef CRYPTO_MSG_TYPE_H_
+#define CRYPTO_MSG_TYPE_H_
+
+/* Generated from crypto-msg-type by `cargo xtask codegen`, don't modify it manually. */
+
+/**
+ * Crypto message types.
+ *
+ * L2Snapshot and L2TopK are very similar, the former is from RESTful API,
+ * the latter is from websocket.
+ */
+typedef enum {
+  /**
+   * All other messages
+   */
+  Other = 0,
+  /**
+   * tick-by-tick trade messages
+   */
+  Trade = 1,
+  /**
+   * Incremental level2 orderbook updates
+   */
+  L2Event = 2,
+  /**
+   * Level2 snapshot from RESTful API
+   */
+  L2Snapshot = 3,
+  /**
+   * Level2 top K snapshots from websocket
+   */
+  L2TopK = 4,
+  /**
+   * Incremental level3 orderbook updates
+   */
+  L3Event = 5,
+  /**
+   * Level3 snapshot from RESTful API
+   */
+  L3Snapshot = 6,
+  /**
+   * Best bid and ask
+   */
+  BBO = 7,
+  /**
+   * 24hr rolling window ticker
+   */
+  Ticker = 8,
+  /**
+   * OHLCV candlestick
+   */
+  Candlestick = 9,
+  /**
+   * Funding rate
+   */
+  FundingRate = 10,
+  /**
+   * Open interest
+   */
+  OpenInterest = 11,
+  /**
+   * Long/short ratio
+   */
+  LongShortRatio = 12,
+  /**
+   * Taker buy/sell volume
+   */
+  TakerVolume = 13,
+} MessageType;
+
+#endif /* CRYPTO_MSG_TYPE_H_ */
@@ -0,0 +1,388 @@
+/* Licensed under Apache-2.0 */
+
+/*
+ * Calls every function of crypto_msg_parser_ffi.h through the header, so
+ * that mismatches between the headers and the library, e.g., a wrong
+ * enumerator in crypto_market_type.h, are caught.
+ *
+ * Built and run by tests/c_api.rs against the static and the shared library.
+ */
+
+#define _POSIX_C_SOURCE 200809L
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <unistd.h>
+
+#include "crypto_msg_parser_ffi.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                               \
+  do {                                                                 \
+    if (!(condition)) {                                                \
+      fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #condition);  \
+      failures++;                                                      \
+    }                                                                  \
+  } while (0)
+
+static const char TRADE_MSG[] =
+    "{\"stream\":\"btcusd_perp@aggTrade\",\"data\":{\"e\":\"aggTrade\",\"E\":1616201883458,\"a\":41045788,\"s\":\"BTCUSD_PERP\",\"p\":\"58570.1\",\"q\":\"58\",\"f\":91864326,\"l\":91864327,\"T\":1616201883304,\"m\":true}}";
+static const char L2_MSG[] =
+    "{\"stream\":\"btcusd_perp@depth@100ms\",\"data\":{\"e\":\"depthUpdate\",\"E\":1622370862564,\"T\":1622370862553,\"s\":\"BTCUSD_PERP\",\"ps\":\"BTCUSD\",\"U\":127559587191,\"u\":127559588177,\"pu\":127559587113,\"b\":[[\"35365.9\",\"1400\"],[\"35425.8\",\"561\"]],\"a\":[[\"35817.8\",\"7885\"],[\"35818.7\",\"307\"]]}}";
+static const char SPOT_L2_TOPK_MSG[] =
+    "{\"stream\":\"ethusdt@depth20\",\"data\":{\"lastUpdateId\":17044571457,\"bids\":[[\"1782.00000000\",\"6.48300000\"],[\"1781.95000000\",\"0.03000000\"]],\"asks\":[[\"1782.01000000\",\"15.46080000\"],[\"1782.02000000\",\"0.00780000\"]]}}";
+static const char INVERSE_FUTURE_L2_TOPK_MSG[] =
+    "{\"stream\":\"btcusd_220624@depth20\",\"data\":{\"e\":\"depthUpdate\",\"E\":1653863834495,\"T\":1653863834489,\"s\":\"BTCUSD_220624\",\"ps\":\"BTCUSD\",\"U\":462549791905,\"u\":462549794973,\"pu\":462549791429,\"b\":[[\"29538.2\",\"195\"],[\"29536.3\",\"491\"],[\"29534.6\",\"44\"]],\"a\":[[\"29538.3\",\"144\"],[\"29540.5\",\"46\"],[\"29540.6\",\"180\"]]}}";
+static const char LINEAR_FUTURE_L2_TOPK_MSG[] =
+    "{\"stream\":\"ethusdt_220624@depth20\",\"data\":{\"e\":\"depthUpdate\",\"E\":1653864801536,\"T\":1653864801453,\"s\":\"ETHUSDT_220624\",\"U\":1555027694421,\"u\":1555027698095,\"pu\":1555027693966,\"b\":[[\"1817.59\",\"0.246\"],[\"1817.57\",\"0.254\"],[\"1817.55\",\"0.254\"]],\"a\":[[\"1817.98\",\"0.704\"],[\"1817.99\",\"0.820\"],[\"1818.10\",\"0.246\"]]}}";
+static const char BBO_MSG[] =
+    "{\"stream\":\"ethusdt@bookTicker\",\"data\":{\"e\":\"bookTicker\",\"u\":1553413152520,\"s\":\"ETHUSDT\",\"b\":\"1778.54\",\"B\":\"15.164\",\"a\":\"1778.55\",\"A\":\"7.289\",\"T\":1653817855284,\"E\":1653817855289}}";
+static const char FUNDING_RATE_MSG[] =
+    "{\"stream\":\"btcusd_perp@markPrice\",\"data\":{\"e\":\"markPriceUpdate\",\"E\":1617309477000,\"s\":\"BTCUSD_PERP\",\"p\":\"59012.56007222\",\"P\":\"58896.00503145\",\"r\":\"0.00073689\",\"T\":1617321600000}}";
+static const char CANDLESTICK_MSG[] =
+    "{\"stream\":\"btcusdt@kline_1M\",\"data\":{\"e\":\"kline\",\"E\":1653819041520,\"s\":\"BTCUSDT\",\"k\":{\"t\":1651363200000,\"T\":1654041599999,\"s\":\"BTCUSDT\",\"i\":\"1M\",\"f\":2172726276,\"L\":2301806561,\"o\":\"37614.40\",\"c\":\"29075.50\",\"h\":\"40071.70\",\"l\":\"26631.00\",\"v\":\"13431981.671\",\"n\":129025447,\"x\":false,\"q\":\"423075730671.12853\",\"V\":\"6700065.176\",\"Q\":\"211000435586.65000\",\"B\":\"0\"}}}";
+
+static const int64_t RECEIVED_AT = 1653819041520;
+
+typedef const char *(*StringParser)(const char *, MarketType, const char *, int64_t);
+typedef ByteBuffer (*FormatParser)(const char *, MarketType, const char *, int64_t, OutputFormat);
+typedef ByteBuffer (*OptionsParser)(const char *, MarketType, const char *, int64_t, ParseOptions);
+
+/* Trade parsers take no received_at */
+static const char *parse_trade_at(const char *exchange, MarketType market_type,
+                                  const char *msg, int64_t received_at) {
+  (void)received_at;
+  return parse_trade(exchange, market_type, msg);
+}
+
+static ByteBuffer parse_trade_with_format_at(const char *exchange,
+                                             MarketType market_type,
+                                             const char *msg,
+                                             int64_t received_at,
+                                             OutputFormat format) {
+  (void)received_at;
+  return parse_trade_with_format(exchange, market_type, msg, format);
+}
+
+static ByteBuffer parse_trade_with_options_at(const char *exchange,
+                                              MarketType market_type,
+                                              const char *msg,
+                                              int64_t received_at,
+                                              ParseOptions options) {
+  (void)received_at;
+  return parse_trade_with_options(exchange, market_type, msg, options);
+}
+
+typedef struct {
+  MarketType market_type;
+  MessageType msg_type;
+  /* names in the JSON output */
+  const char *market_type_name;
+  const char *msg_type_name;
+  const char *msg;
+  StringParser parse;
+  FormatParser parse_with_format;
+  OptionsParser parse_with_options;
+} Case;
+
+static const Case CASES[] = {
+    {InverseSwap, Trade, "inverse_swap", "trade", TRADE_MSG, parse_trade_at,
+     parse_trade_with_format_at, parse_trade_with_options_at},
+    {InverseSwap, L2Event, "inverse_swap", "l2_event", L2_MSG, parse_l2,
+     parse_l2_with_format, parse_l2_with_options},
+    {Spot, L2TopK, "spot", "l2_topk", SPOT_L2_TOPK_MSG, parse_l2_topk,
+     parse_l2_topk_with_format, parse_l2_topk_with_options},
+    {InverseFuture, L2TopK, "inverse_future", "l2_topk",
+     INVERSE_FUTURE_L2_TOPK_MSG, parse_l2_topk, parse_l2_topk_with_format,
+     parse_l2_topk_with_options},
+    {LinearFuture, L2TopK, "linear_future", "l2_topk",
+     LINEAR_FUTURE_L2_TOPK_MSG, parse_l2_topk, parse_l2_topk_with_format,
+     parse_l2_topk_with_options},
+    {LinearSwap, BBO, "linear_swap", "bbo", BBO_MSG, parse_bbo,
+     parse_bbo_with_format, parse_bbo_with_options},
+    {InverseSwap, FundingRate, "inverse_swap", "funding_rate",
+     FUNDING_RATE_MSG, parse_funding_rate, parse_funding_rate_with_format,
+     parse_funding_rate_with_options},
+    {LinearSwap, Candlestick, "linear_swap", "candlestick", CANDLESTICK_MSG,
+     parse_candlestick, parse_candlestick_with_format,
+     parse_candlestick_with_options},
+};
+
+/* Copies a byte buffer into a NUL-terminated string and frees the buffer */
+static char *take_bytes(ByteBuffer buffer) {
+  if (buffer.data == NULL) {
+    return NULL;
+  }
+  char *text = malloc(buffer.len + 1);
+  memcpy(text, buffer.data, buffer.len);
+  text[buffer.len] = '\0';
+  deallocate_bytes(buffer);
+  return text;
+}
+
+static size_t count_char(const char *text, char c) {
+  size_t count = 0;
+  for (; *text != '\0' && *text != '\n'; text++) {
+    count += *text == c;
+  }
+  return count;
+}
+
+/* Checks the market type and message type names in the JSON output */
+static void check_json(const char *json, const Case *c, int line) {
+  char expected[64];
+  if (json == NULL) {
+    fprintf(stderr, "%s:%d: NULL output of %s\n", __FILE__, line,
+            c->msg_type_name);
+    failures++;
+    return;
+  }
+  snprintf(expected, sizeof(expected), "\"market_type\":\"%s\"",
+           c->market_type_name);
+  if (strstr(json, expected) == NULL) {
+    fprintf(stderr, "%s:%d: %s not found in %s\n", __FILE__, line, expected,
+            json);
+    failures++;
+  }
+  snprintf(expected, sizeof(expected), "\"msg_type\":\"%s\"",
+           c->msg_type_name);
+  if (strstr(json, expected) == NULL) {
+    fprintf(stderr, "%s:%d: %s not found in %s\n", __FILE__, line, expected,
+            json);
+    failures++;
+  }
+}
+
+static void test_case(const Case *c) {
+  const char *msg = c->msg;
+  const char *json = c->parse("binance", c->market_type, msg, RECEIVED_AT);
+  check_json(json, c, __LINE__);
+  deallocate_string(json);
+
+  /* the CSV columns match the header */
+  const char *header = csv_header(c->msg_type);
+  char *csv = take_bytes(c->parse_with_format("binance", c->market_type, msg,
+                                              RECEIVED_AT, OutputFormat_Csv));
+  CHECK(header != NULL);
+  CHECK(csv != NULL && *csv != '\0');
+  if (header != NULL && csv != NULL) {
+    CHECK(count_char(header, ',') == count_char(csv, ','));
+  }
+  deallocate_string(header);
+  free(csv);
+
+  char *text = take_bytes(c->parse_with_format("binance", c->market_type, msg,
+                                               RECEIVED_AT, OutputFormat_Json));
+  check_json(text, c, __LINE__);
+  free(text);
+
+  ByteBuffer cbor = c->parse_with_format("binance", c->market_type, msg,
+                                         RECEIVED_AT, OutputFormat_Cbor);
+  CHECK(cbor.data != NULL && cbor.len > 0);
+  deallocate_bytes(cbor);
+
+  text = take_bytes(c->parse_with_options("binance", c->market_type, msg,
+                                          RECEIVED_AT,
+                                          default_parse_options()));
+  check_json(text, c, __LINE__);
+  free(text);
+
+  ParseOptions options = default_parse_options();
+  options.fields = "msg_type,json";
+  options.omit_json = true;
+  text = take_bytes(c->parse_with_options("binance", c->market_type, msg,
+                                          RECEIVED_AT, options));
+  CHECK(text != NULL && strstr(text, "\"json\":\"\"") != NULL);
+  CHECK(text != NULL && strstr(text, "market_type") == NULL);
+  free(text);
+
+  /* parse_compressed() and parse_payload_bytes() dispatch on msg_type */
+  text = take_bytes(parse_compressed(
+      "binance", c->market_type, c->msg_type, (const uint8_t *)msg,
+      strlen(msg), Compression_Plain, RECEIVED_AT, default_parse_options()));
+  check_json(text, c, __LINE__);
+  free(text);
+
+  text = take_bytes(parse_payload_bytes(
+      "binance", c->market_type, c->msg_type, (const uint8_t *)msg,
+      strlen(msg), PayloadEncoding_Json, RECEIVED_AT,
+      default_parse_options()));
+  check_json(text, c, __LINE__);
+  free(text);
+
+  const char *symbol = extract_symbol("binance", c->market_type, msg);
+  CHECK(symbol != NULL && symbol[0] == '"');
+  deallocate_string(symbol);
+  CHECK(extract_timestamp("binance", c->market_type, msg) >= 0);
+}
+
+static void test_cases(void) {
+  for (size_t i = 0; i < sizeof(CASES) / sizeof(CASES[0]); i++) {
+    test_case(&CASES[i]);
+  }
+}
+
+static void test_extract(void) {
+  const char *symbol = extract_symbol("binance", InverseSwap, L2_MSG);
+  CHECK(symbol != NULL && strcmp(symbol, "\"BTCUSD_PERP\"") == 0);
+  deallocate_string(symbol);
+
+  CHECK(extract_timestamp("binance", InverseSwap, L2_MSG) == 1622370862564);
+  CHECK(extract_timestamp("binance", Spot, SPOT_L2_TOPK_MSG) == 0);
+  CHECK(extract_timestamp("binance", InverseSwap, "not json") == -1);
+}
+
+static void test_get_msg_type(void) {
+  CHECK(get_msg_type("binance", TRADE_MSG) == Trade);
+  CHECK(get_msg_type("binance", L2_MSG) == L2Event);
+  CHECK(get_msg_type("binance", INVERSE_FUTURE_L2_TOPK_MSG) == L2TopK);
+  CHECK(get_msg_type("binance", BBO_MSG) == BBO);
+  CHECK(get_msg_type("binance", FUNDING_RATE_MSG) == FundingRate);
+  CHECK(get_msg_type("binance", CANDLESTICK_MSG) == Candlestick);
+}
+
+static void test_abi(void) {
+  CHECK(abi_version() == CRYPTO_MSG_PARSER_FFI_ABI_VERSION);
+  /* values of enumerators are part of the ABI */
+  CHECK(InverseSwap == 5 && BVOL == 11);
+  CHECK(L2TopK == 4 && TakerVolume == 13);
+  CHECK(Compression_Auto == 4 && OutputFormat_FlatBuffers == 4);
+}
+
+static void test_errors(void) {
+  CHECK(parse_trade("binance", InverseSwap, "not json") == NULL);
+  CHECK(csv_header(Other) == NULL);
+
+  ByteBuffer buffer =
+      parse_trade_with_options("binance", InverseSwap, "not json",
+                               default_parse_options());
+  CHECK(buffer.data == NULL);
+  CHECK(last_error() != NULL && *last_error() != '\0');
+
+  /* plain text isn't a gzip stream */
+  buffer = parse_compressed("binance", InverseSwap, Trade,
+                            (const uint8_t *)TRADE_MSG, strlen(TRADE_MSG),
+                            Compression_Gzip, 0, default_parse_options());
+  CHECK(buffer.data == NULL);
+  CHECK(last_error() != NULL);
+
+  /* freeing NULL is a no-op */
+  deallocate_string(NULL);
+  deallocate_bytes(buffer);
+
+  /* a successful call clears the error */
+  buffer = parse_trade_with_options("binance", InverseSwap, TRADE_MSG,
+                                    default_parse_options());
+  CHECK(buffer.data != NULL);
+  CHECK(last_error() == NULL);
+  deallocate_bytes(buffer);
+}
+
+static void test_raw_file_reader(void) {
+  char path[] = "/tmp/crypto_msg_parser_ffi_XXXXXX";
+  int fd = mkstemp(path);
+  CHECK(fd >= 0);
+  FILE *file = fdopen(fd, "w");
+  fprintf(file, "binance\tinverse_swap\ttrade\t1616201883500\t%s\n", TRADE_MSG);
+  fprintf(file, "binance\tinverse_swap\ttrade\t1616201883500\tnot json\n");
+  fclose(file);
+
+  RawFileReader *reader = raw_file_reader_open(path);
+  CHECK(reader != NULL);
+  if (reader != NULL) {
+    const char *line = raw_file_reader_next(reader);
+    CHECK(line != NULL && strstr(line, "\"line_number\":1") != NULL);
+    CHECK(line != NULL && strstr(line, "\"messages\":[") != NULL);
+    deallocate_string(line);
+
+    line = raw_file_reader_next(reader);
+    CHECK(line != NULL && strstr(line, "\"error\":") != NULL);
+    deallocate_string(line);
+
+    CHECK(raw_file_reader_next(reader) == NULL);
+    raw_file_reader_close(reader);
+  }
+  unlink(path);
+
+  CHECK(raw_file_reader_open("/nonexistent/file.txt") == NULL);
+  CHECK(last_error() != NULL);
+  raw_file_reader_close(NULL);
+}
+
+#if defined(CRYPTO_MSG_PARSER_FFI_ARROW)
+static void test_arrow(void) {
+  struct ArrowSchema schema;
+  CHECK(export_arrow_schema(Trade, &schema));
+  CHECK(strcmp(schema.format, "+s") == 0);
+  CHECK(schema.n_children > 0);
+  schema.release(&schema);
+  CHECK(!export_arrow_schema(Other, &schema));
+
+  const char *msgs[] = {TRADE_MSG, "not json"};
+  struct ArrowArray array;
+  CHECK(parse_batch_arrow("binance", InverseSwap, Trade, msgs, NULL, 2, &array,
+                          &schema) == 1);
+  CHECK(array.length == 1);
+  CHECK(array.n_children == schema.n_children);
+  array.release(&array);
+  schema.release(&schema);
+}
+#endif
+
+#if defined(CRYPTO_MSG_PARSER_FFI_PARQUET)
+static void test_parquet(void) {
+  char path[] = "/tmp/crypto_msg_parser_ffi_XXXXXX";
+  int fd = mkstemp(path);
+  CHECK(fd >= 0);
+  close(fd);
+
+  ParquetWriter *writer = parquet_writer_open(path, Trade);
+  CHECK(writer != NULL);
+  if (writer != NULL) {
+    CHECK(parquet_writer_write(writer, "binance", InverseSwap, TRADE_MSG, 0) ==
+          1);
+    CHECK(parquet_writer_write(writer, "binance", InverseSwap, "not json", 0) ==
+          -1);
+    CHECK(parquet_writer_close(writer));
+
+    FILE *file = fopen(path, "rb");
+    char magic[4] = {0};
+    CHECK(file != NULL && fread(magic, 1, 4, file) == 4);
+    CHECK(memcmp(magic, "PAR1", 4) == 0);
+    if (file != NULL) {
+      fclose(file);
+    }
+  }
+  unlink(path);
+
+  CHECK(parquet_writer_open(path, Other) == NULL);
+  CHECK(!parquet_writer_close(NULL));
+}
+#endif
+
+int main(void) {
+  /* no call has failed yet */
+  CHECK(last_error() == NULL);
+
+  test_abi();
+  test_cases();
+  test_extract();
+  test_get_msg_type();
+  test_errors();
+  test_raw_file_reader();
+#if defined(CRYPTO_MSG_PARSER_FFI_ARROW)
+  test_arrow();
+#endif
+#if defined(CRYPTO_MSG_PARSER_FFI_PARQUET)
+  test_parquet();
+#endif
+
+  if (failures > 0) {
+    fprintf(stderr, "%d checks failed\n", failures);
+    return EXIT_FAILURE;
+  }
+  printf("All tests passed\n");
+  return EXIT_SUCCESS;
+}
@@ -0,0 +1,119 @@
+//! Compiles tests/c/test_ffi.c with the C compiler, i.e., `$CC` or `cc`, and
+//! runs it against the static and the shared library of this crate. The
+//! dynamic section of the shared library is read with `readelf`.
+
+#![cfg(target_os = "linux")]
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The directory of the libraries built with the features of this test, i.e.,
+/// target/<profile>/deps, they are only copied to target/<profile> by `cargo build`.
+fn library_dir() -> PathBuf {
+    // the test executable is target/<profile>/deps/c_api-<hash>
+    env::current_exe().unwrap().parent().unwrap().to_path_buf()
+}
+
+/// Output of `readelf --dynamic` for the shared library.
+fn dynamic_section() -> String {
+    let output = Command::new("readelf")
+        .arg("--dynamic")
+        .arg(library_dir().join("libcrypto_msg_parser_ffi.so"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "Failed to run readelf");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Values of the entries of a tag in the dynamic section, e.g., `NEEDED`.
+fn dynamic_entries(dynamic: &str, tag: &str) -> Vec<String> {
+    let tag = format!("({tag})");
+    dynamic
+        .lines()
+        .filter(|line| line.split_whitespace().nth(1) == Some(tag.as_str()))
+        .filter_map(|line| Some(line.split_once('[')?.1.strip_suffix(']')?.to_string()))
+        .collect()
+}
+
+/// The native libraries of the static library, as `-l` arguments.
+///
+/// The shared library is linked from the same code with the libraries of
+/// `rustc --print native-static-libs`, its `NEEDED` entries are those of them
+/// that are actually used.
+fn native_static_libs() -> Vec<String> {
+    dynamic_entries(&dynamic_section(), "NEEDED")
+        .iter()
+        .filter_map(|lib| lib.strip_prefix("lib")?.split_once(".so"))
+        .map(|(name, _)| format!("-l{name}"))
+        .collect()
+}
+
+fn compile_and_run(name: &str, link_args: &[String]) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let exe = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+
+    let mut cc = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_string()));
+    cc.args(["-std=c11", "-Wall", "-Wextra", "-Werror"])
+        .arg("-I")
+        .arg(root.join("include"));
+    if cfg!(feature = "arrow") {
+        cc.arg("-DCRYPTO_MSG_PARSER_FFI_ARROW");
+    }
+    if cfg!(feature = "parquet") {
+        cc.arg("-DCRYPTO_MSG_PARSER_FFI_PARQUET");
+    }
+    let status = cc
+        .arg(root.join("tests/c/test_ffi.c"))
+        .args(link_args)
+        .arg("-o")
+        .arg(&exe)
+        .status()
+        .unwrap();
+    assert!(status.success(), "Failed to compile the C tests");
+
+    // cargo adds target/<profile> to LD_LIBRARY_PATH, which takes precedence
+    // over the rpath
+    let output = Command::new(&exe)
+        .env_remove("LD_LIBRARY_PATH")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_c_api_static() {
+    let mut link_args = vec![library_dir()
+        .join("libcrypto_msg_parser_ffi.a")
+        .display()
+        .to_string()];
+    link_args.extend(native_static_libs());
+    compile_and_run("test_ffi_static", &link_args);
+}
+
+#[test]
+fn test_c_api_shared() {
+    // the program needs a file named after the SONAME, which `make` creates
+    // in target/release
+    let soname = dynamic_entries(&dynamic_section(), "SONAME").remove(0);
+    let link = library_dir().join(soname);
+    if link.symlink_metadata().is_err() {
+        std::os::unix::fs::symlink("libcrypto_msg_parser_ffi.so", link).unwrap();
+    }
+
+    let dir = library_dir().display().to_string();
+    compile_and_run(
+        "test_ffi_shared",
+        &[
+            format!("-L{dir}"),
+            "-lcrypto_msg_parser_ffi".to_string(),
+            format!("-Wl,-rpath,{dir}"),
+        ],
+    );
+}
@@ -0,0 +1,296 @@
+//! Development tasks of crypto-msg-parser-ffi, run with `cargo xtask <task>`.
+//!
+//! * `codegen` generates the C headers of the `MarketType` and `MessageType`
+//!   enums of the crypto-market-type and crypto-msg-type crates with explicit
+//!   values, i.e., `include/crypto_market_type.h` and
+//!   `include/crypto_msg_type.h`, as well as `src/enum_assertions.rs`, which
+//!   fails the build if the library is compiled with other values, and the
+//!   prost messages of `proto/crypto_msg_parser.proto` in `src/pb.rs`. The
+//!   generated files are committed, run it after upgrading these crates or
+//!   changing the `.proto` file.
+
+use serde_json::Value;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+/// C enums generated from the dependency crates, i.e., (crate, enum, header).
+const ENUM_HEADERS: &[(&str, &str, &str)] = &[
+    ("crypto-market-type", "MarketType", "crypto_market_type.h"),
+    ("crypto-msg-type", "MessageType", "crypto_msg_type.h"),
+];
+
+/// Package of `proto/crypto_msg_parser.proto`, also the name of the file.
+const PROTO_PACKAGE: &str = "crypto_msg_parser";
+
+struct Variant {
+    name: String,
+    docs: Vec<String>,
+    value: i64,
+}
+
+/// Find the source file of a dependency of the library in `cargo metadata`.
+fn dependency(metadata: &Value, crate_dir: &Path, name: &str) -> PathBuf {
+    let packages = metadata["packages"].as_array().unwrap();
+    let manifest_path = crate_dir.join("Cargo.toml");
+    let this = packages
+        .iter()
+        .find(|package| Path::new(package["manifest_path"].as_str().unwrap()) == manifest_path)
+        .unwrap();
+    let node = metadata["resolve"]["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|node| node["id"] == this["id"])
+        .unwrap();
+    let dep_id = &node["deps"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|dep| dep["name"] == name.replace('-', "_"))
+        .unwrap_or_else(|| panic!("{name} is not a dependency"))["pkg"];
+    let package = packages
+        .iter()
+        .find(|package| &package["id"] == dep_id)
+        .unwrap();
+    let src_path = package["targets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|target| {
+            target["kind"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|kind| kind == "lib")
+        })
+        .unwrap()["src_path"]
+        .as_str()
+        .unwrap();
+    PathBuf::from(src_path)
+}
+
+fn docs(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(text),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("doc") => Some(text.value()),
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+/// Parse a `#[repr(C)]` enum, returns its doc comment and variants.
+fn parse_enum(src_path: &Path, name: &str) -> (Vec<String>, Vec<Variant>) {
+    let file = syn::parse_file(&fs::read_to_string(src_path).unwrap()).unwrap();
+    let item = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            syn::Item::Enum(item) if item.ident == name => Some(item),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("enum {name} not found in {}", src_path.display()));
+    assert!(
+        item.attrs.iter().any(|attr| attr.path().is_ident("repr")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|repr| repr == "C")),
+        "{name} is not #[repr(C)]"
+    );
+
+    let mut next = 0;
+    let variants = item
+        .variants
+        .iter()
+        .map(|variant| {
+            let value = match &variant.discriminant {
+                Some((
+                    _,
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(value),
+                        ..
+                    }),
+                )) => value.base10_parse().unwrap(),
+                Some(_) => panic!("Unsupported discriminant of {name}::{}", variant.ident),
+                None => next,
+            };
+            next = value + 1;
+            Variant {
+                name: variant.ident.to_string(),
+                docs: docs(&variant.attrs),
+                value,
+            }
+        })
+        .collect();
+    (docs(&item.attrs), variants)
+}
+
+fn write_docs(out: &mut String, docs: &[String], indent: &str) {
+    if docs.is_empty() {
+        return;
+    }
+    out.push_str(&format!("{indent}/**\n"));
+    for line in docs {
+        if line.is_empty() {
+            out.push_str(&format!("{indent} *\n"));
+        } else {
+            out.push_str(&format!("{indent} * {line}\n"));
+        }
+    }
+    out.push_str(&format!("{indent} */\n"));
+}
+
+/// The root directory of crypto-msg-parser-ffi.
+fn crate_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Generate the prost messages of the `.proto` file, parsed by protox, so
+/// protoc isn't needed.
+fn protobuf_messages(crate_dir: &Path) -> String {
+    let proto_dir = crate_dir.join("proto");
+    let descriptors = protox::compile(
+        [proto_dir.join(format!("{PROTO_PACKAGE}.proto"))],
+        [&proto_dir],
+    )
+    .unwrap();
+    let out_dir = env::temp_dir().join(format!("crypto-msg-parser-ffi-xtask-{}", process::id()));
+    fs::create_dir_all(&out_dir).unwrap();
+    prost_build::Config::new()
+        .out_dir(&out_dir)
+        .compile_fds(descriptors)
+        .unwrap();
+    let messages = fs::read_to_string(out_dir.join(format!("{PROTO_PACKAGE}.rs"))).unwrap();
+    fs::remove_dir_all(&out_dir).unwrap();
+    format!(
+        "//! Protobuf messages of `proto/{PROTO_PACKAGE}.proto`.\n\
+         //!\n\
+         //! Generated by `cargo xtask codegen` with prost-build, don't modify it manually.\n\n\
+         {messages}"
+    )
+}
+
+/// Generate the C headers of the enums with explicit discriminants,
+/// compile-time assertions that the library uses the same discriminants, and
+/// the Rust code of the protobuf messages.
+///
+/// Returns the paths and contents of the generated files.
+fn codegen(crate_dir: &Path) -> Vec<(PathBuf, String)> {
+    // the sources of the dependencies are read from the local registry, so
+    // they must have been downloaded anyway
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["metadata", "--format-version", "1", "--offline"])
+        .arg("--manifest-path")
+        .arg(crate_dir.join("Cargo.toml"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let metadata: Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let mut files = Vec::new();
+    let mut assertions = String::from(
+        "//! Checks that the values in crypto_market_type.h and crypto_msg_type.h equal\n\
+         //! the discriminants of the enums the library is compiled with.\n\
+         //!\n\
+         //! Generated by `cargo xtask codegen`, don't modify it manually.\n\n",
+    );
+    for (crate_name, enum_name, _) in ENUM_HEADERS {
+        assertions.push_str(&format!(
+            "use {}::{enum_name};\n",
+            crate_name.replace('-', "_")
+        ));
+    }
+    assertions.push_str("use std::{mem::size_of, os::raw::c_int};\n");
+
+    for (crate_name, enum_name, header) in ENUM_HEADERS {
+        let src_path = dependency(&metadata, crate_dir, crate_name);
+        let (enum_docs, variants) = parse_enum(&src_path, enum_name);
+        let guard = header.to_uppercase().replace('.', "_") + "_";
+
+        let mut out = String::from("/* Licensed under Apache-2.0 */\n");
+        out.push_str(&format!("#ifndef {guard}\n#define {guard}\n\n"));
+        out.push_str(&format!(
+            "/* Generated from {crate_name} by `cargo xtask codegen`, don't modify it manually. */\n\n"
+        ));
+        write_docs(&mut out, &enum_docs, "");
+        out.push_str("typedef enum {\n");
+        for variant in &variants {
+            write_docs(&mut out, &variant.docs, "  ");
+            out.push_str(&format!("  {} = {},\n", variant.name, variant.value));
+        }
+        out.push_str(&format!("}} {enum_name};\n\n#endif /* {guard} */\n"));
+        files.push((crate_dir.join("include").join(header), out));
+
+        assertions.push_str(&format!(
+            "\nconst _: () = assert!(size_of::<{enum_name}>() == size_of::<c_int>());\n"
+        ));
+        for variant in &variants {
+            assertions.push_str(&format!(
+                "const _: () = assert!({enum_name}::{} as i64 == {});\n",
+                variant.name, variant.value
+            ));
+        }
+    }
+    files.push((crate_dir.join("src/enum_assertions.rs"), assertions));
+    files.push((crate_dir.join("src/pb.rs"), protobuf_messages(crate_dir)));
+    files
+}
+
+fn main() {
+    match env::args().nth(1).as_deref() {
+        Some("codegen") => {
+            for (path, contents) in codegen(&crate_dir()) {
+                // leave unchanged files untouched
+                if fs::read_to_string(&path).ok() != Some(contents.clone()) {
+                    fs::write(&path, contents).unwrap();
+                    println!("Generated {}", path.display());
+                }
+            }
+        }
+        _ => {
+            eprintln!("Usage: cargo xtask codegen");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{codegen, crate_dir};
+    use std::fs;
+
+    #[test]
+    fn test_codegen_up_to_date() {
+        for (path, contents) in codegen(&crate_dir()) {
+            assert!(
+                fs::read_to_string(&path).ok() == Some(contents),
+                "{} is out of date, run `cargo xtask codegen`",
+                path.display()
+            );
+        }
+    }
+}